@@ -0,0 +1,307 @@
+//! Derive macros for [asky](https://docs.rs/asky).
+//!
+//! Enable them with the `derive` feature of `asky`.
+
+use proc_macro::TokenStream;
+use proc_macro2::TokenStream as TokenStream2;
+use quote::quote;
+use syn::{
+    parse_macro_input, Attribute, Data, DeriveInput, Expr, Field, Fields, Lit, LitStr, Meta, Type,
+};
+
+/// Derive a `select` constructor for enums with unit variants.
+///
+/// The generated `fn select(message: &str) -> asky::Select<Self>` has an option for each variant:
+///
+/// - The title is the variant name, or the one set with `#[asky(title = "...")]`.
+/// - The description is the doc comment of the variant, if any.
+///
+/// # Examples
+///
+/// ```ignore
+/// use asky::AskySelect;
+///
+/// #[derive(AskySelect)]
+/// enum Shell {
+///     /// The default shell in most Linux distributions
+///     Bash,
+///     #[asky(title = "Z shell")]
+///     Zsh,
+/// }
+///
+/// let shell = Shell::select("Choose a shell").prompt()?;
+/// ```
+#[proc_macro_derive(AskySelect, attributes(asky))]
+pub fn derive_select(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+
+    match expand_select(input) {
+        Ok(tokens) => tokens.into(),
+        Err(err) => err.to_compile_error().into(),
+    }
+}
+
+fn expand_select(input: DeriveInput) -> syn::Result<TokenStream2> {
+    let name = &input.ident;
+    let (impl_generics, ty_generics, where_clause) = input.generics.split_for_impl();
+
+    let Data::Enum(data) = &input.data else {
+        return Err(syn::Error::new_spanned(
+            &input.ident,
+            "AskySelect can only be derived for enums",
+        ));
+    };
+
+    let mut options = Vec::with_capacity(data.variants.len());
+
+    for variant in &data.variants {
+        if !matches!(variant.fields, Fields::Unit) {
+            return Err(syn::Error::new_spanned(
+                variant,
+                "AskySelect only supports unit variants",
+            ));
+        }
+
+        let ident = &variant.ident;
+        let title = match parse_title(&variant.attrs)? {
+            Some(title) => title.value(),
+            None => ident.to_string(),
+        };
+        let description = parse_doc(&variant.attrs).map(|doc| quote! { .description(#doc) });
+
+        options.push(quote! {
+            ::asky::SelectOption::new_titled(Self::#ident, #title) #description
+        });
+    }
+
+    Ok(quote! {
+        impl #impl_generics #name #ty_generics #where_clause {
+            /// Create a select prompt with an option for each variant.
+            pub fn select(message: &str) -> ::asky::Select<'_, Self> {
+                ::asky::Select::new_complex(message, ::std::vec![#(#options),*])
+            }
+        }
+    })
+}
+
+/// Derive a `prompt` constructor for structs with named fields.
+///
+/// The generated `fn prompt() -> Result<Self, asky::Error>` asks each field in order,
+/// with a prompt based on its type:
+///
+/// - `String` fields use a `Text` prompt.
+/// - `bool` fields use a `Confirm` prompt.
+/// - Number fields, like `u8` or `f64`, use a `Number` prompt.
+///
+/// The message is the field name in sentence case, and the prompt name is the field name.
+/// Each field accepts these attributes:
+///
+/// - `#[asky(message = "...")]` to set the message.
+/// - `#[asky(default = ...)]` to set the default value, or the initial one for `Confirm`.
+/// - `#[asky(validate = ...)]` to set a validator, like `asky::validators::email("...")`.
+///
+/// # Examples
+///
+/// ```ignore
+/// use asky::Prompt;
+///
+/// #[derive(Prompt)]
+/// struct Signup {
+///     name: String,
+///     #[asky(message = "How old are you?", default = 18)]
+///     age: u8,
+///     newsletter: bool,
+/// }
+///
+/// let signup = Signup::prompt()?;
+/// ```
+#[proc_macro_derive(Prompt, attributes(asky))]
+pub fn derive_prompt(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+
+    match expand_prompt(input) {
+        Ok(tokens) => tokens.into(),
+        Err(err) => err.to_compile_error().into(),
+    }
+}
+
+fn expand_prompt(input: DeriveInput) -> syn::Result<TokenStream2> {
+    let name = &input.ident;
+    let (impl_generics, ty_generics, where_clause) = input.generics.split_for_impl();
+
+    let fields = match &input.data {
+        Data::Struct(data) => match &data.fields {
+            Fields::Named(fields) => &fields.named,
+            _ => {
+                return Err(syn::Error::new_spanned(
+                    &input.ident,
+                    "Prompt only supports structs with named fields",
+                ))
+            }
+        },
+        _ => {
+            return Err(syn::Error::new_spanned(
+                &input.ident,
+                "Prompt can only be derived for structs",
+            ))
+        }
+    };
+
+    let values = fields
+        .iter()
+        .map(|field| {
+            let ident = field.ident.as_ref().expect("named field");
+            let prompt = field_prompt(field)?;
+
+            Ok(quote! { #ident: #prompt.prompt()? })
+        })
+        .collect::<syn::Result<Vec<_>>>()?;
+
+    Ok(quote! {
+        impl #impl_generics #name #ty_generics #where_clause {
+            /// Ask a prompt for each field, in order.
+            pub fn prompt() -> ::std::result::Result<Self, ::asky::Error> {
+                ::std::result::Result::Ok(Self {
+                    #(#values),*
+                })
+            }
+        }
+    })
+}
+
+/// Returns the prompt constructor of a field, with the builder calls of its attributes.
+fn field_prompt(field: &Field) -> syn::Result<TokenStream2> {
+    let ident = field.ident.as_ref().expect("named field");
+    let key = ident.to_string();
+    let attrs = parse_field_attrs(&field.attrs)?;
+    let message = match attrs.message {
+        Some(message) => message.value(),
+        None => sentence_case(&key),
+    };
+
+    let ty = &field.ty;
+    let type_name = match ty {
+        Type::Path(path) if path.qself.is_none() => path
+            .path
+            .segments
+            .last()
+            .filter(|segment| segment.arguments.is_empty())
+            .map(|segment| segment.ident.to_string()),
+        _ => None,
+    };
+
+    let (constructor, default_method) = match type_name.as_deref() {
+        Some("String") => (quote! { ::asky::Text::new(#message) }, quote! { default }),
+        Some("bool") => (
+            quote! { ::asky::Confirm::new(#message) },
+            quote! { initial },
+        ),
+        Some(
+            "u8" | "u16" | "u32" | "u64" | "u128" | "usize" | "i8" | "i16" | "i32" | "i64" | "i128"
+            | "isize" | "f32" | "f64",
+        ) => (
+            quote! { ::asky::Number::<#ty>::new(#message) },
+            quote! { default },
+        ),
+        _ => {
+            return Err(syn::Error::new_spanned(
+                ty,
+                "unsupported field type, expected `String`, `bool` or a number",
+            ))
+        }
+    };
+
+    let default = attrs
+        .default
+        .map(|default| quote! { .#default_method(#default) });
+    let validate = attrs
+        .validate
+        .map(|validate| quote! { .validate(#validate) });
+
+    Ok(quote! {
+        #constructor.name(#key) #default #validate
+    })
+}
+
+#[derive(Default)]
+struct FieldAttrs {
+    message: Option<LitStr>,
+    default: Option<Expr>,
+    validate: Option<Expr>,
+}
+
+/// Returns the values of `#[asky(message = "...", default = ..., validate = ...)]`.
+fn parse_field_attrs(attrs: &[Attribute]) -> syn::Result<FieldAttrs> {
+    let mut field_attrs = FieldAttrs::default();
+
+    for attr in attrs.iter().filter(|attr| attr.path().is_ident("asky")) {
+        attr.parse_nested_meta(|meta| {
+            if meta.path.is_ident("message") {
+                field_attrs.message = Some(meta.value()?.parse()?);
+            } else if meta.path.is_ident("default") {
+                field_attrs.default = Some(meta.value()?.parse()?);
+            } else if meta.path.is_ident("validate") {
+                field_attrs.validate = Some(meta.value()?.parse()?);
+            } else {
+                return Err(meta
+                    .error("unknown asky attribute, expected `message`, `default` or `validate`"));
+            }
+
+            Ok(())
+        })?;
+    }
+
+    Ok(field_attrs)
+}
+
+/// Convert a field name like `first_name` into `First name`.
+fn sentence_case(name: &str) -> String {
+    let name = name.trim_start_matches("r#").replace('_', " ");
+    let mut chars = name.trim().chars();
+
+    match chars.next() {
+        Some(first) => first.to_uppercase().chain(chars).collect(),
+        None => String::new(),
+    }
+}
+
+/// Returns the value of `#[asky(title = "...")]`.
+fn parse_title(attrs: &[Attribute]) -> syn::Result<Option<LitStr>> {
+    let mut title = None;
+
+    for attr in attrs.iter().filter(|attr| attr.path().is_ident("asky")) {
+        attr.parse_nested_meta(|meta| {
+            if meta.path.is_ident("title") {
+                title = Some(meta.value()?.parse()?);
+                Ok(())
+            } else {
+                Err(meta.error("unknown asky attribute, expected `title`"))
+            }
+        })?;
+    }
+
+    Ok(title)
+}
+
+/// Returns the doc comment lines, trimmed and joined with spaces.
+fn parse_doc(attrs: &[Attribute]) -> Option<String> {
+    let lines: Vec<String> = attrs
+        .iter()
+        .filter_map(|attr| match &attr.meta {
+            Meta::NameValue(meta) if meta.path.is_ident("doc") => match &meta.value {
+                Expr::Lit(expr) => match &expr.lit {
+                    Lit::Str(lit) => Some(lit.value().trim().to_string()),
+                    _ => None,
+                },
+                _ => None,
+            },
+            _ => None,
+        })
+        .filter(|line| !line.is_empty())
+        .collect();
+
+    match lines.is_empty() {
+        true => None,
+        false => Some(lines.join(" ")),
+    }
+}
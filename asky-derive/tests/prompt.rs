@@ -0,0 +1,42 @@
+use std::collections::HashMap;
+
+use asky_derive::Prompt;
+
+#[derive(Debug, PartialEq, Prompt)]
+struct Signup {
+    #[asky(message = "What is your name?", validate = asky::validators::min_len(1, "required"))]
+    name: String,
+    #[asky(default = 18)]
+    age: u8,
+    newsletter_opt_in: bool,
+}
+
+fn answers(name: &str) -> HashMap<String, String> {
+    HashMap::from([
+        ("name".to_string(), name.to_string()),
+        ("age".to_string(), String::new()),
+        ("newsletter_opt_in".to_string(), "yes".to_string()),
+    ])
+}
+
+#[test]
+fn derive_prompt_constructor() {
+    // the answers are pre-seeded by the field names, so the prompts don't need a terminal
+    asky::set_answer_source(answers("Ann"));
+    let signup = Signup::prompt();
+
+    asky::set_answer_source(answers(""));
+    let invalid = Signup::prompt();
+
+    asky::clear_answer_source();
+
+    assert_eq!(
+        signup.unwrap(),
+        Signup {
+            name: String::from("Ann"),
+            age: 18,
+            newsletter_opt_in: true,
+        }
+    );
+    assert!(matches!(invalid, Err(asky::Error::InvalidInput(value)) if value.is_empty()));
+}
@@ -0,0 +1,38 @@
+use asky_derive::AskySelect;
+
+#[derive(Debug, PartialEq, AskySelect)]
+enum Shell {
+    /// The default shell in most Linux distributions
+    Bash,
+    /// Extended Bourne shell
+    /// with a lot of improvements
+    #[asky(title = "Z shell")]
+    Zsh,
+    Fish,
+}
+
+#[test]
+fn derive_options() {
+    let prompt = Shell::select("Choose a shell");
+    let options = &prompt.options;
+
+    assert_eq!(prompt.message, "Choose a shell");
+    assert_eq!(options.len(), 3);
+
+    assert_eq!(options[0].value, Shell::Bash);
+    assert_eq!(options[0].title, "Bash");
+    assert_eq!(
+        options[0].description,
+        Some("The default shell in most Linux distributions")
+    );
+
+    assert_eq!(options[1].value, Shell::Zsh);
+    assert_eq!(options[1].title, "Z shell");
+    assert_eq!(
+        options[1].description,
+        Some("Extended Bourne shell with a lot of improvements")
+    );
+
+    assert_eq!(options[2].value, Shell::Fish);
+    assert_eq!(options[2].description, None);
+}
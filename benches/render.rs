@@ -0,0 +1,41 @@
+use asky::{
+    __bench::{format_select, format_text, Typeable},
+    Select, Text,
+};
+use criterion::{black_box, criterion_group, criterion_main, Criterion};
+use crossterm::event::{KeyCode, KeyEvent};
+
+fn select_draw(c: &mut Criterion) {
+    let prompt = Select::new("Choose an option", 0..10_000);
+
+    c.bench_function("select 10k: full draw", |b| {
+        b.iter(|| format_select(black_box(&prompt)))
+    });
+}
+
+fn select_keystroke(c: &mut Criterion) {
+    let mut prompt = Select::new("Choose an option", 0..10_000);
+
+    c.bench_function("select 10k: keystroke and redraw", |b| {
+        b.iter(|| {
+            prompt.handle_key(KeyEvent::from(KeyCode::Down));
+            format_select(black_box(&prompt))
+        })
+    });
+}
+
+fn text_keystroke(c: &mut Criterion) {
+    c.bench_function("text: type 100 chars and redraw", |b| {
+        b.iter(|| {
+            let mut prompt = Text::new("What is your name?");
+
+            for _ in 0..100 {
+                prompt.handle_key(KeyEvent::from(KeyCode::Char('a')));
+                black_box(format_text(&prompt));
+            }
+        })
+    });
+}
+
+criterion_group!(benches, select_draw, select_keystroke, text_keystroke);
+criterion_main!(benches);
@@ -2,10 +2,10 @@ use asky::Number;
 
 fn main() -> std::io::Result<()> {
 
-    if let Ok(age) = Number::<u8>::new("How old are you?").prompt()? {
-        if age <= 60 {
-            println!("Pretty young");
-        }
+    let age = Number::<u8>::new("How old are you?").prompt_validated()?;
+
+    if age <= 60 {
+        println!("Pretty young");
     }
 
     // ...
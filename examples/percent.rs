@@ -0,0 +1,10 @@
+use asky::Percent;
+
+fn main() -> std::io::Result<()> {
+    let volume = Percent::new("Set the volume").initial(50).prompt()?;
+    println!("Volume at {volume}%");
+
+    // ...
+
+    Ok(())
+}
@@ -0,0 +1,12 @@
+use asky::TypeToConfirm;
+
+fn main() -> std::io::Result<()> {
+    let confirmed = TypeToConfirm::new("Type 'prod-db' to delete it", "prod-db").prompt()?;
+
+    match confirmed {
+        true => println!("Deleting prod-db..."),
+        false => println!("Cancelled"),
+    }
+
+    Ok(())
+}
@@ -0,0 +1,63 @@
+//! Run the prompts with arbitrary key events, checking that handling and drawing them never panics.
+//!
+//! Run it with `cargo +nightly fuzz run handle_key`, see <https://github.com/rust-fuzz/cargo-fuzz>.
+
+#![no_main]
+
+use asky::{test::Session, MultiSelect, Number, Password, Select, Text};
+use crossterm::event::{KeyCode, KeyEvent, KeyModifiers};
+use libfuzzer_sys::fuzz_target;
+
+/// Decode a key event from two bytes, the keys handled by the prompts are the most likely.
+fn decode_key(code: u8, modifiers: u8) -> KeyEvent {
+    let code = match code {
+        0 => KeyCode::Enter,
+        1 => KeyCode::Backspace,
+        2 => KeyCode::Delete,
+        3 => KeyCode::Left,
+        4 => KeyCode::Right,
+        5 => KeyCode::Up,
+        6 => KeyCode::Down,
+        7 => KeyCode::Home,
+        8 => KeyCode::End,
+        9 => KeyCode::Tab,
+        10 => KeyCode::BackTab,
+        11 => KeyCode::PageUp,
+        12 => KeyCode::PageDown,
+        // wide and combining characters, to check the cursor and the line wrapping
+        13 => KeyCode::Char('界'),
+        14 => KeyCode::Char('👍'),
+        15 => KeyCode::Char('\u{301}'),
+        // `Esc` cancels, so it ends the run early
+        27 => KeyCode::Esc,
+        _ => KeyCode::Char(char::from(code)),
+    };
+
+    KeyEvent::new(code, KeyModifiers::from_bits_truncate(modifiers))
+}
+
+fuzz_target!(|data: &[u8]| {
+    let keys: Vec<KeyEvent> = data
+        .chunks_exact(2)
+        .map(|pair| decode_key(pair[0], pair[1]))
+        .collect();
+
+    // a small terminal, so long inputs wrap and lists are paginated
+    let mut session = Session::new(12, 6);
+
+    let mut text = Text::new("Name");
+    let _ = session.keys(keys.clone()).run(&mut text);
+
+    let mut password = Password::new("Password");
+    let _ = session.keys(keys.clone()).run(&mut password);
+
+    let mut number = Number::<f64>::new("Number");
+    let _ = session.keys(keys.clone()).run(&mut number);
+
+    let mut select = Select::new("Select", ["a", "bb", "ccc", "dddd", "eeeee", "ffffff"]);
+    select.filterable(true).multi_pick = true;
+    let _ = session.keys(keys.clone()).run(&mut select);
+
+    let mut multi_select = MultiSelect::new("MultiSelect", ["a", "bb", "ccc", "dddd", "eeeee"]);
+    let _ = session.keys(keys).run(&mut multi_select);
+});
@@ -7,8 +7,35 @@
 //! - [`Text`] - One-line user input.
 //! - [`Number`] - One-line user input of numbers.
 //! - [`Password`] - One-line user input as password.
+//! - [`Percent`] - Percentage between 0 and 100.
+//! - [`TypeToConfirm`] - Type an expected text to confirm a dangerous operation.
 //! - [`Select`] - Select an item from a list.
 //! - [`MultiSelect`] - Select multiple items from a list.
+//! - [`Pair`] - Two one-line input prompts on the same screen.
+//! - [`Form`] - One-line input prompts asked one after another, as a single unit.
+//!
+//! With the `derive` feature, `#[derive(AskySelect)]` creates a [`Select`] prompt from an enum,
+//! using the doc comments of the variants as descriptions.
+//! And `#[derive(Prompt)]` asks the fields of a struct in order, with a [`Text`], [`Number`]
+//! or [`Confirm`] prompt by their type.
+//!
+//! The [`validators`] module has reusable validators for [`Text`], [`Password`] and [`Number`],
+//! like [`validators::email`]. The `regex` feature adds `validators::regex`.
+//!
+//! With [`set_answer_source`], the prompts are answered from command line flags, environment
+//! variables or a map, without waiting for keys, e.g. when stdin is not a terminal.
+//!
+//! With the `secrecy` feature, `Password::prompt_secret()` returns the secret as a `SecretString`,
+//! and the input of [`Password`] is zeroized after the prompt.
+//!
+//! The [`test`] module runs prompts with a script of key events in a virtual terminal,
+//! to test them without a real one.
+//!
+//! With the `survey` feature, a `Survey` of questions can be deserialized from a configuration
+//! file, like JSON or TOML, and its answers are returned as a map of `serde_json::Value`.
+//!
+//! With the `autosave` feature, the answers of a [`Flow`] are saved to a file after each step,
+//! and an interrupted flow can continue from that file with `Flow::resume_from()`.
 //!
 //! # Simple Example
 //!
@@ -30,8 +57,24 @@
 //! }
 //! ```
 //!
+//! # Async
+//!
+//! With the `async` feature, all the prompts have a `prompt_async()` method, which reads the keys
+//! from a terminal event stream instead of blocking the thread, e.g. in a tokio application.
+//!
+//! # Cancellation
+//!
+//! When the user presses `Esc`, `Ctrl+C` or `Ctrl+D`, the prompt is removed and returns
+//! [`Error::Cancel`], so the application can clean up or show its own message.
+//! To exit the process instead, set [`AskySettings::exit_on_cancel`],
+//! and to keep the prompt with a cancelled mark, set [`AskySettings::keep_cancelled`].
+//!
 //! # Customization
 //!
+//! To change the symbols and colors of all the prompts at once, set a [`Theme`] preset
+//! (like [`Theme::PLAIN`] or [`Theme::EMOJI`]) in [`AskySettings::theme`].
+//! The symbols can be swapped without the colors with a [`GlyphSet`], like [`GlyphSet::NERD_FONT`].
+//!
 //! If you'd like to use this crate but don't want the default styles or just want to customize as you like,
 //! all the prompts allow setting a custom formatter using `format()` method.
 //!
@@ -74,7 +117,7 @@
 //! # fn main() -> std::io::Result<()> {
 //! Text::new("What is your name")
 //!     .format(|prompt, _draw_time| {
-//!         let cursor_col = prompt.input.col;
+//!         let cursor_col = prompt.input.cursor_width();
 //!         let prefix = "> ";
 //!
 //!         let x = (prefix.len() + cursor_col);
@@ -99,20 +142,77 @@
 //! ```
 //!
 //! Where `|` is the cursor position.
+//!
+//! ## Layout
+//!
+//! Long lines are wrapped by the terminal, so they take more than one row. The renderer counts
+//! the wrapped rows to restore the cursor, use [`LayoutContext`] in custom formatters to get
+//! the terminal width and the same rows and cursor positions.
 #![deny(missing_docs)]
 
 mod prompts;
+pub mod test;
 mod utils;
+pub mod validators;
 
 pub use prompts::confirm::Confirm;
+pub use prompts::form::Form;
 pub use prompts::multi_select::MultiSelect;
 pub use prompts::number::Number;
+pub use prompts::pair::Pair;
 pub use prompts::password::Password;
+pub use prompts::percent::Percent;
 pub use prompts::select::Select;
 pub use prompts::text::Text;
 pub use prompts::toggle::Toggle;
+pub use prompts::type_to_confirm::TypeToConfirm;
 
+pub use prompts::confirm::{ConfirmAnswer, StickyConfirm};
+pub use prompts::multi_select::ResultOrder;
+pub use prompts::number::{NumberLocale, NumberMode};
+pub use prompts::pair::Composable;
+pub use prompts::password::{PasswordVisibility, Strength};
 pub use prompts::select::{SelectInput, SelectOption};
 pub use prompts::text::LineInput;
+pub use utils::answer::{Answer, Answers, Recordable};
+pub use utils::answer_source::{
+    clear_answer_source, set_answer_source, AnswerSource, ArgsSource, EnvSource,
+};
+pub use utils::error::Error;
+pub use utils::flow::{Flow, FlowAnswers};
+pub use utils::history::History;
+pub use utils::idle::{clear_idle_callback, on_idle};
+pub use utils::last_choices::LastChoices;
+pub use utils::layout::LayoutContext;
 pub use utils::num_like::NumLike;
-pub use utils::renderer::DrawTime;
+pub use utils::recording::{record_keys, replay_keys, stop_recording};
+pub use utils::renderer::{clear_header, set_header, suspend, CursorShape, CursorStyle, DrawTime};
+pub use utils::settings::{Action, AskySettings, GlyphSet, KeyLayout, Keybindings, Layout, Theme};
+pub use utils::state::{clear_state_observer, set_state_observer, OptionState, PromptState};
+pub use utils::summary::{Summary, SummaryEntry};
+pub use utils::terminal_lock::{
+    lock_terminal, lock_terminal_async, try_lock_terminal, with_terminal_lock, TerminalGuard,
+    TerminalLockFuture, TerminalScope,
+};
+
+#[cfg(feature = "survey")]
+pub use utils::survey::{Question, QuestionKind, Survey, Validation};
+
+#[cfg(feature = "derive")]
+pub use asky_derive::{AskySelect, Prompt};
+
+#[doc(hidden)]
+pub mod __bench {
+    //! Internals used by the benchmarks, not part of the public API.
+
+    pub use crate::utils::key_listener::Typeable;
+    use crate::{utils::theme, DrawTime, Select, Text};
+
+    pub fn format_select<T>(prompt: &Select<T>) -> String {
+        theme::fmt_select(prompt, DrawTime::Update)
+    }
+
+    pub fn format_text(prompt: &Text) -> (String, [usize; 2]) {
+        theme::fmt_text(prompt, DrawTime::Update)
+    }
+}
@@ -3,8 +3,12 @@ use std::io;
 use crossterm::event::{KeyCode, KeyEvent};
 
 use crate::utils::{
+    answer::{Answer, Recordable},
+    answer_source,
+    error::Error,
     key_listener::{self, Typeable},
     renderer::{DrawTime, Printable, Renderer},
+    state::PromptState,
     theme,
 };
 
@@ -19,8 +23,17 @@ type Formatter<'a> = dyn Fn(&Confirm, DrawTime) -> String + 'a;
 /// | `Enter`, `Backspace` | Submit current/initial value |
 /// | `y`, `Y`             | Submit `true`                |
 /// | `n`, `N`             | Submit `false`               |
-/// | `Left`, `h`, `H`     | Focus `false`                |
-/// | `Right`, `l`, `L`    | Focus `true`                 |
+/// | `a`, `A`             | Submit `Always` [^policy]    |
+/// | `v`, `V`             | Submit `Never` [^policy]     |
+/// | `Left`, `h`, `H`     | Focus previous option        |
+/// | `Right`, `l`, `L`    | Focus next option            |
+/// | `Up`, `k`, `K`       | Scroll preview up [^preview] |
+/// | `Down`, `j`, `J`     | Scroll preview down          |
+/// | `PageUp`, `PageDown` | Scroll preview by a page     |
+///
+/// [^policy]: Only when using [`Confirm::prompt_policy`].
+///
+/// [^preview]: Only when using [`Confirm::preview`] with more lines than the preview height.
 ///
 /// # Examples
 ///
@@ -39,8 +52,30 @@ type Formatter<'a> = dyn Fn(&Confirm, DrawTime) -> String + 'a;
 pub struct Confirm<'a> {
     /// Message used to display in the prompt.
     pub message: &'a str,
+    /// Stable identifier of the prompt.
+    pub name: Option<&'a str>,
+    /// Key of the answer, when it's recorded in [`Answers`](crate::Answers).
+    pub key: Option<&'a str>,
     /// Current state of the prompt.
     pub active: bool,
+    /// Initial state of the prompt, to indicate which option is the default one.
+    pub initial_active: bool,
+    /// Indicate if the `Always` and `Never` options are displayed.
+    ///
+    /// See [`Confirm::prompt_policy`].
+    pub with_policy: bool,
+    /// Indicate if the focused option is `Always` or `Never`, instead of `Yes` or `No`.
+    pub apply_to_rest: bool,
+    /// Correct answer, to display if the user was right after submitting.
+    pub correct: Option<bool>,
+    /// Explanation to display after submitting, when the correct answer is set.
+    pub explanation: Option<&'a str>,
+    /// Pre-formatted content to display above the options, like a diff.
+    pub preview: Option<&'a str>,
+    /// Index of the first visible line of the preview.
+    pub preview_offset: usize,
+    /// Maximum number of lines of the preview to display at once.
+    pub preview_height: usize,
     formatter: Box<Formatter<'a>>,
 }
 
@@ -49,7 +84,17 @@ impl<'a> Confirm<'a> {
     pub fn new(message: &'a str) -> Self {
         Confirm {
             message,
+            name: None,
+            key: None,
             active: false,
+            initial_active: false,
+            with_policy: false,
+            apply_to_rest: false,
+            correct: None,
+            explanation: None,
+            preview: None,
+            preview_offset: 0,
+            preview_height: 10,
             formatter: Box::new(theme::fmt_confirm),
         }
     }
@@ -57,6 +102,48 @@ impl<'a> Confirm<'a> {
     /// Set whether the prompt should be active at start.
     pub fn initial(&mut self, active: bool) -> &mut Self {
         self.active = active;
+        self.initial_active = active;
+        self
+    }
+
+    /// Set the correct answer, to display if the user was right after submitting.
+    pub fn correct(&mut self, active: bool) -> &mut Self {
+        self.correct = Some(active);
+        self
+    }
+
+    /// Set an explanation to display after submitting, when the correct answer is set.
+    pub fn explanation(&mut self, explanation: &'a str) -> &mut Self {
+        self.explanation = Some(explanation);
+        self
+    }
+
+    /// Set pre-formatted content to display above the options, like a diff of the changes to apply.
+    ///
+    /// When it's longer than the preview height, it can be scrolled with the arrow keys.
+    pub fn preview(&mut self, content: &'a str) -> &mut Self {
+        self.preview = Some(content);
+        self.preview_offset = 0;
+        self
+    }
+
+    /// Set the maximum number of lines of the preview to display at once.
+    pub fn preview_height(&mut self, height: usize) -> &mut Self {
+        self.preview_height = height.max(1);
+        self
+    }
+
+    /// Set a stable identifier for the prompt, like `"db.host"`.
+    ///
+    /// It is available to custom formatters, which can use it to render per-field markers.
+    pub fn name(&mut self, name: &'a str) -> &mut Self {
+        self.name = Some(name);
+        self
+    }
+
+    /// Set the key of the answer, when it's recorded in [`Answers`](crate::Answers).
+    pub fn key(&mut self, key: &'a str) -> &mut Self {
+        self.key = Some(key);
         self
     }
 
@@ -72,17 +159,95 @@ impl<'a> Confirm<'a> {
     }
 
     /// Display the prompt and return the user answer.
-    pub fn prompt(&mut self) -> io::Result<bool> {
+    pub fn prompt(&mut self) -> Result<bool, Error> {
         key_listener::listen(self, true)?;
         Ok(self.active)
     }
+
+    /// Async version of [`Confirm::prompt`].
+    #[cfg(feature = "async")]
+    pub async fn prompt_async(&mut self) -> Result<bool, Error> {
+        key_listener::listen_async(self, true).await?;
+        Ok(self.active)
+    }
+
+    /// Display the prompt with the extra `Always` and `Never` options, and return the user answer.
+    ///
+    /// Useful when the same question is asked for many items, like overwriting files,
+    /// see [`StickyConfirm`] to stop asking after `Always` or `Never`.
+    pub fn prompt_policy(&mut self) -> Result<ConfirmAnswer, Error> {
+        self.with_policy = true;
+        key_listener::listen(self, true)?;
+        Ok(self.answer())
+    }
 }
 
 impl Confirm<'_> {
+    /// Returns whether the current answer is the correct one, if it was set.
+    pub fn is_correct(&self) -> Option<bool> {
+        self.correct.map(|correct| correct == self.active)
+    }
+
+    /// Returns the current answer.
+    pub fn answer(&self) -> ConfirmAnswer {
+        match (self.active, self.apply_to_rest) {
+            (false, false) => ConfirmAnswer::No,
+            (true, false) => ConfirmAnswer::Yes,
+            (false, true) => ConfirmAnswer::Never,
+            (true, true) => ConfirmAnswer::Always,
+        }
+    }
+
     fn update_and_submit(&mut self, active: bool) -> bool {
         self.active = active;
+        self.apply_to_rest = false;
+        true
+    }
+
+    fn update_policy_and_submit(&mut self, active: bool) -> bool {
+        self.active = active;
+        self.apply_to_rest = true;
         true
     }
+
+    /// Move the focus through `No`, `Yes`, `Never` and `Always`.
+    fn move_focus(&mut self, forward: bool) {
+        let max = if self.with_policy { 3 } else { 1 };
+        let index = self.active as usize + 2 * self.apply_to_rest as usize;
+        let index = match forward {
+            true => (index + 1).min(max),
+            false => index.saturating_sub(1),
+        };
+
+        self.active = index % 2 == 1;
+        self.apply_to_rest = index >= 2;
+    }
+
+    fn scroll_preview(&mut self, delta: isize) {
+        let lines = self.preview.map_or(0, |preview| preview.lines().count());
+        let max_offset = lines.saturating_sub(self.preview_height);
+
+        self.preview_offset = self
+            .preview_offset
+            .saturating_add_signed(delta)
+            .min(max_offset);
+    }
+}
+
+impl Recordable for Confirm<'_> {
+    type Output = bool;
+
+    fn record_key(&self) -> Option<&str> {
+        self.key
+    }
+
+    fn ask(&mut self) -> Result<bool, Error> {
+        self.prompt()
+    }
+
+    fn submitted_answer(&self) -> Answer {
+        self.active.into()
+    }
 }
 
 impl Typeable for Confirm<'_> {
@@ -90,19 +255,57 @@ impl Typeable for Confirm<'_> {
         let mut submit = false;
 
         match key.code {
-            // update value
-            KeyCode::Left | KeyCode::Char('h' | 'H') => self.active = false,
-            KeyCode::Right | KeyCode::Char('l' | 'L') => self.active = true,
             // update value and submit
             KeyCode::Char('y' | 'Y') => submit = self.update_and_submit(true),
             KeyCode::Char('n' | 'N') => submit = self.update_and_submit(false),
+            KeyCode::Char('a' | 'A') if self.with_policy => {
+                submit = self.update_policy_and_submit(true)
+            }
+            KeyCode::Char('v' | 'V') if self.with_policy => {
+                submit = self.update_policy_and_submit(false)
+            }
             // submit current/initial value
             KeyCode::Enter | KeyCode::Backspace => submit = true,
-            _ => (),
+            // update value
+            code => match key_listener::navigation_key(code) {
+                KeyCode::Left | KeyCode::Char('h' | 'H') => self.move_focus(false),
+                KeyCode::Right | KeyCode::Char('l' | 'L') => self.move_focus(true),
+                // scroll preview
+                KeyCode::Up | KeyCode::Char('k' | 'K') => self.scroll_preview(-1),
+                KeyCode::Down | KeyCode::Char('j' | 'J') => self.scroll_preview(1),
+                KeyCode::PageUp => self.scroll_preview(-(self.preview_height as isize)),
+                KeyCode::PageDown => self.scroll_preview(self.preview_height as isize),
+                _ => (),
+            },
         }
 
         submit
     }
+
+    fn answer_key(&self) -> Option<&str> {
+        self.key.or(self.name)
+    }
+
+    fn seed(&mut self, value: &str) -> bool {
+        match answer_source::parse_bool(value) {
+            Some(active) => {
+                self.active = active;
+                true
+            }
+            None => false,
+        }
+    }
+}
+
+impl Confirm<'_> {
+    /// Returns a snapshot of the prompt state, see [`set_state_observer`](crate::set_state_observer).
+    pub fn state(&self) -> PromptState {
+        let titles = ["No", "Yes", "Never", "Always"];
+        let titles = &titles[..if self.with_policy { 4 } else { 2 }];
+        let focused = self.active as usize + 2 * self.apply_to_rest as usize;
+
+        PromptState::new(self.message, self.name, titles[focused]).with_titles(titles, focused)
+    }
 }
 
 impl Printable for Confirm<'_> {
@@ -110,6 +313,92 @@ impl Printable for Confirm<'_> {
         let text = (self.formatter)(self, renderer.draw_time);
         renderer.print(text)
     }
+
+    fn snapshot(&self) -> Option<PromptState> {
+        Some(self.state())
+    }
+}
+
+/// Answer of [`Confirm::prompt_policy`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ConfirmAnswer {
+    /// `false` for this time.
+    No,
+    /// `true` for this time.
+    Yes,
+    /// `false` for this and the following times.
+    Never,
+    /// `true` for this and the following times.
+    Always,
+}
+
+impl ConfirmAnswer {
+    /// Returns the boolean value of the answer.
+    pub fn value(self) -> bool {
+        matches!(self, ConfirmAnswer::Yes | ConfirmAnswer::Always)
+    }
+
+    /// Returns whether the answer applies to the following times.
+    pub fn is_sticky(self) -> bool {
+        matches!(self, ConfirmAnswer::Never | ConfirmAnswer::Always)
+    }
+}
+
+/// Cache for a confirmation asked repeatedly, which stops asking after `Always` or `Never`.
+///
+/// # Examples
+///
+/// ```no_run
+/// use asky::{Confirm, StickyConfirm};
+///
+/// # fn main() -> std::io::Result<()> {
+/// let mut overwrite = StickyConfirm::new();
+///
+/// for file in ["a.txt", "b.txt", "c.txt"] {
+///     let message = format!("Overwrite {file}?");
+///
+///     if overwrite.ask(&mut Confirm::new(&message))? {
+///         // ...
+///     }
+/// }
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct StickyConfirm {
+    choice: Option<bool>,
+}
+
+impl StickyConfirm {
+    /// Create a new cache, without a sticky choice.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Returns the sticky choice, or display the prompt with [`Confirm::prompt_policy`].
+    pub fn ask(&mut self, confirm: &mut Confirm) -> Result<bool, Error> {
+        if let Some(choice) = self.choice {
+            return Ok(choice);
+        }
+
+        let answer = confirm.prompt_policy()?;
+
+        if answer.is_sticky() {
+            self.choice = Some(answer.value());
+        }
+
+        Ok(answer.value())
+    }
+
+    /// Returns the sticky choice, if the user answered `Always` or `Never`.
+    pub fn choice(&self) -> Option<bool> {
+        self.choice
+    }
+
+    /// Forget the sticky choice, to ask again.
+    pub fn reset(&mut self) {
+        self.choice = None;
+    }
 }
 
 #[cfg(test)]
@@ -126,6 +415,80 @@ mod tests {
         assert!(prompt.active);
     }
 
+    #[test]
+    fn keep_initial_value() {
+        let mut prompt = Confirm::new("");
+
+        prompt.initial(true);
+        prompt.handle_key(KeyEvent::from(KeyCode::Left));
+
+        assert!(!prompt.active);
+        assert!(prompt.initial_active);
+    }
+
+    #[test]
+    fn set_name() {
+        let mut prompt = Confirm::new("");
+
+        assert_eq!(prompt.name, None);
+        prompt.name("foo");
+        assert_eq!(prompt.name, Some("foo"));
+    }
+
+    #[test]
+    fn check_correct_answer() {
+        let mut prompt = Confirm::new("");
+
+        assert_eq!(prompt.is_correct(), None);
+        prompt.correct(true).explanation("foo");
+        assert_eq!(prompt.is_correct(), Some(false));
+        prompt.handle_key(KeyEvent::from(KeyCode::Char('y')));
+        assert_eq!(prompt.is_correct(), Some(true));
+        assert_eq!(prompt.explanation, Some("foo"));
+    }
+
+    #[test]
+    fn answer_with_policy() {
+        let mut prompt = Confirm::new("");
+
+        // policy keys are ignored without policy
+        assert!(!prompt.handle_key(KeyEvent::from(KeyCode::Char('a'))));
+        prompt.handle_key(KeyEvent::from(KeyCode::Right));
+        prompt.handle_key(KeyEvent::from(KeyCode::Right));
+        assert_eq!(prompt.answer(), ConfirmAnswer::Yes);
+
+        prompt.with_policy = true;
+        prompt.handle_key(KeyEvent::from(KeyCode::Right));
+        assert_eq!(prompt.answer(), ConfirmAnswer::Never);
+        prompt.handle_key(KeyEvent::from(KeyCode::Right));
+        prompt.handle_key(KeyEvent::from(KeyCode::Right));
+        assert_eq!(prompt.answer(), ConfirmAnswer::Always);
+
+        assert!(prompt.handle_key(KeyEvent::from(KeyCode::Char('v'))));
+        assert_eq!(prompt.answer(), ConfirmAnswer::Never);
+        assert!(prompt.handle_key(KeyEvent::from(KeyCode::Char('y'))));
+        assert_eq!(prompt.answer(), ConfirmAnswer::Yes);
+    }
+
+    #[test]
+    fn scroll_preview() {
+        let mut prompt = Confirm::new("");
+        let scroll = |prompt: &mut Confirm, key| {
+            prompt.handle_key(KeyEvent::from(key));
+            prompt.preview_offset
+        };
+
+        assert_eq!(scroll(&mut prompt, KeyCode::Down), 0);
+
+        prompt.preview("1\n2\n3\n4\n5").preview_height(2);
+
+        assert_eq!(scroll(&mut prompt, KeyCode::Up), 0);
+        assert_eq!(scroll(&mut prompt, KeyCode::Char('j')), 1);
+        assert_eq!(scroll(&mut prompt, KeyCode::PageDown), 3);
+        assert_eq!(scroll(&mut prompt, KeyCode::Down), 3);
+        assert_eq!(scroll(&mut prompt, KeyCode::PageUp), 1);
+    }
+
     #[test]
     fn set_custom_formatter() {
         let mut prompt: Confirm = Confirm::new("");
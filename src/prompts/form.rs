@@ -0,0 +1,481 @@
+use std::io;
+
+use crossterm::event::{KeyCode, KeyEvent, KeyModifiers};
+
+use crate::utils::{
+    answer::{Answer, Answers},
+    error::Error,
+    key_listener::{self, Typeable},
+    layout::LayoutContext,
+    renderer::{DrawTime, Printable, Renderer},
+    state::PromptState,
+    theme,
+};
+
+use super::pair::Composable;
+
+type Condition<'a> = dyn Fn(&Answers) -> bool + 'a;
+
+/// Object-safe view of a [`Composable`] prompt, to keep prompts of different types together.
+trait Field: Typeable + Printable {
+    fn frame(&self, draw_time: DrawTime) -> (String, [usize; 2]);
+    fn answer(&mut self) -> Answer;
+
+    fn message(&self) -> String {
+        self.snapshot()
+            .map(|state| state.message)
+            .unwrap_or_default()
+    }
+}
+
+impl<C: Composable> Field for C
+where
+    C::Output: Into<Answer>,
+{
+    fn frame(&self, draw_time: DrawTime) -> (String, [usize; 2]) {
+        Composable::frame(self, draw_time)
+    }
+
+    fn answer(&mut self) -> Answer {
+        self.output().into()
+    }
+}
+
+/// Run several one-line input prompts one after another, as a single form.
+///
+/// The answered prompts are kept above the focused one, below the title of the form,
+/// and they can be edited again before submitting the last prompt.
+///
+/// Prompts added with a [`Form::when`] condition are only asked when it's `true`,
+/// otherwise they are hidden and left out of the answers.
+///
+/// # Key Events
+///
+/// | Key                 | Action                                        |
+/// | ------------------- | --------------------------------------------- |
+/// | `Enter`             | Submit the focused prompt, if valid           |
+/// | `Ctrl+S`            | Skip the focused prompt, if it's skippable    |
+/// | `BackTab`, `Ctrl+P` | Go back to the previous prompt                |
+/// | Other keys          | Handled by the focused prompt                 |
+///
+/// # Examples
+///
+/// ```no_run
+/// use asky::{Form, Number, Password, Text};
+///
+/// # fn main() -> std::io::Result<()> {
+/// let answers = Form::new()
+///     .title("Create account")
+///     .add("username", Text::new("Username").placeholder("admin"))
+///     .add("age", Number::<u8>::new("Age").min(18))
+///     .add("password", &mut Password::new("Password"))
+///     .prompt()?;
+///
+/// let username = answers[0].1.as_text();
+/// # Ok(())
+/// # }
+/// ```
+pub struct Form<'a, 'p> {
+    /// Title displayed above the prompts.
+    pub title: Option<&'a str>,
+    fields: Vec<Entry<'a, 'p>>,
+    focused: usize,
+}
+
+struct Entry<'a, 'p> {
+    key: &'a str,
+    field: &'p mut dyn Field,
+    condition: Option<Box<Condition<'a>>>,
+    visible: bool,
+    skippable: bool,
+    skipped: bool,
+}
+
+impl<'a, 'p> Form<'a, 'p> {
+    /// Create an empty form.
+    pub fn new() -> Self {
+        Form {
+            title: None,
+            fields: Vec::new(),
+            focused: 0,
+        }
+    }
+
+    /// Set the title displayed above the prompts.
+    pub fn title(&mut self, title: &'a str) -> &mut Self {
+        self.title = Some(title);
+        self
+    }
+
+    /// Add a prompt, with the key of its answer.
+    pub fn add<C>(&mut self, key: &'a str, prompt: &'p mut C) -> &mut Self
+    where
+        C: Composable + 'p,
+        C::Output: Into<Answer>,
+    {
+        self.fields.push(Entry {
+            key,
+            field: prompt,
+            condition: None,
+            visible: true,
+            skippable: false,
+            skipped: false,
+        });
+        self
+    }
+
+    /// Allow skipping the last added prompt with `Ctrl+S`, its answer is [`Answer::Unit`].
+    pub fn skippable(&mut self) -> &mut Self {
+        if let Some(entry) = self.fields.last_mut() {
+            entry.skippable = true;
+        }
+
+        self
+    }
+
+    /// Only ask the last added prompt when the condition returns `true`.
+    ///
+    /// The condition receives the answers of the previous visible prompts, by their key,
+    /// and it's checked again when they are edited.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// use asky::{Answer, Form, Text};
+    ///
+    /// # fn main() -> std::io::Result<()> {
+    /// let answers = Form::new()
+    ///     .add("host", &mut Text::new("Host"))
+    ///     .add("tls", Text::new("Use TLS?").initial("yes"))
+    ///     .add("cert", &mut Text::new("Certificate path"))
+    ///     .when(|answers| answers.get("tls") == Some(&Answer::from("yes")))
+    ///     .prompt()?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn when<C>(&mut self, condition: C) -> &mut Self
+    where
+        C: Fn(&Answers) -> bool + 'a,
+    {
+        if let Some(entry) = self.fields.last_mut() {
+            entry.condition = Some(Box::new(condition));
+        }
+
+        self
+    }
+
+    /// Display the prompts and return their answers, in the order they were added.
+    pub fn prompt(&mut self) -> Result<Vec<(&'a str, Answer)>, Error> {
+        key_listener::listen(self, false)?;
+
+        Ok(self.answers())
+    }
+
+    /// Async version of [`Form::prompt`].
+    #[cfg(feature = "async")]
+    pub async fn prompt_async(&mut self) -> Result<Vec<(&'a str, Answer)>, Error> {
+        key_listener::listen_async(self, false).await?;
+
+        Ok(self.answers())
+    }
+
+    /// Returns the index of the focused prompt.
+    pub fn focused(&self) -> usize {
+        self.focused
+    }
+
+    fn answers(&mut self) -> Vec<(&'a str, Answer)> {
+        self.fields
+            .iter_mut()
+            .filter(|entry| entry.visible)
+            .map(|entry| (entry.key, entry.answer()))
+            .collect()
+    }
+
+    /// Check the conditions again, with the answers of the previous visible prompts.
+    fn update_visibility(&mut self) {
+        let mut answers = Answers::new();
+
+        for entry in &mut self.fields {
+            entry.visible = match &entry.condition {
+                Some(condition) => condition(&answers),
+                None => true,
+            };
+
+            if entry.visible {
+                answers.insert(entry.key, entry.answer());
+            }
+        }
+    }
+
+    /// Focus the next visible prompt, returns `true` if there are no more.
+    fn focus_next(&mut self) -> bool {
+        self.update_visibility();
+        self.focused = (self.focused + 1..self.fields.len())
+            .find(|&i| self.fields[i].visible)
+            .unwrap_or(self.fields.len());
+
+        self.focused == self.fields.len()
+    }
+
+    /// Focus the previous visible prompt, if any.
+    fn focus_previous(&mut self) {
+        if let Some(i) = (0..self.focused).rev().find(|&i| self.fields[i].visible) {
+            self.focused = i;
+        }
+    }
+}
+
+impl Entry<'_, '_> {
+    fn answer(&mut self) -> Answer {
+        match self.skipped {
+            true => Answer::Unit,
+            false => self.field.answer(),
+        }
+    }
+}
+
+impl Default for Form<'_, '_> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Typeable for Form<'_, '_> {
+    fn handle_key(&mut self, key: KeyEvent) -> bool {
+        let Some(entry) = self.fields.get_mut(self.focused) else {
+            // nothing to ask
+            return true;
+        };
+
+        let is_ctrl = |c| key.code == KeyCode::Char(c) && key.modifiers == KeyModifiers::CONTROL;
+        let go_back = key.code == KeyCode::BackTab || is_ctrl('p');
+
+        match key.code {
+            // edit the previous answer
+            _ if go_back => self.focus_previous(),
+            // skip the focused prompt, like submitting it without answer
+            _ if is_ctrl('s') => {
+                if !entry.skippable {
+                    return false;
+                }
+
+                entry.skipped = true;
+                return self.focus_next();
+            }
+            // submit the focused prompt, and the form after the last one
+            KeyCode::Enter if entry.field.handle_key(key) => {
+                entry.skipped = false;
+                return self.focus_next();
+            }
+            // update focused
+            _ => {
+                entry.field.handle_key(key);
+            }
+        }
+
+        false
+    }
+
+    fn is_sensitive(&self) -> bool {
+        self.fields
+            .get(self.focused)
+            .is_some_and(|entry| entry.field.is_sensitive())
+    }
+
+    fn prepare(&mut self) {
+        for entry in &mut self.fields {
+            entry.skipped = false;
+            entry.field.prepare();
+        }
+
+        self.update_visibility();
+        self.focused = 0;
+
+        if !self.fields.first().is_some_and(|entry| entry.visible) {
+            self.focus_next();
+        }
+    }
+}
+
+impl Printable for Form<'_, '_> {
+    fn draw(&self, renderer: &mut Renderer) -> io::Result<()> {
+        let layout = LayoutContext::current();
+        let mut lines: Vec<String> = self.title.map(theme::fmt_form_title).into_iter().collect();
+        let mut cursor = [0, 0];
+
+        for (i, entry) in self.fields.iter().enumerate() {
+            if !entry.visible {
+                continue;
+            }
+
+            let draw_time = match i < self.focused {
+                true => DrawTime::Last,
+                false => renderer.draw_time,
+            };
+
+            // the prompts after the focused one are shown when they are reached
+            if i > self.focused {
+                break;
+            }
+
+            let (text, [x, y]) = match entry.skipped && i != self.focused {
+                true => (theme::fmt_form_skipped(&entry.field.message()), [0, 0]),
+                false => entry.field.frame(draw_time),
+            };
+
+            if i == self.focused {
+                cursor = [x, y + layout.rows(&lines.join("\n"))];
+            }
+
+            lines.push(text.trim_end_matches('\n').to_string());
+        }
+
+        renderer.print(lines.join("\n"))?;
+        renderer.set_cursor(cursor)
+    }
+
+    fn snapshot(&self) -> Option<PromptState> {
+        self.fields.get(self.focused)?.field.snapshot()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{test::Session, utils::settings::AskySettings, Number, Text};
+
+    #[test]
+    fn answer_in_order() {
+        let mut name = Text::new("Name");
+        let mut age = Number::<u8>::new("Age");
+        let mut form = Form::new();
+
+        age.sensitive();
+
+        name.validate(|value| match value.is_empty() {
+            true => Err("required"),
+            false => Ok(()),
+        });
+        form.add("name", &mut name).add("age", &mut age);
+
+        assert!(!form.handle_key(KeyEvent::from(KeyCode::Enter)));
+        assert_eq!(form.focused(), 0);
+
+        form.handle_key(KeyEvent::from(KeyCode::Char('a')));
+        assert!(!form.is_sensitive());
+        assert_eq!(form.snapshot().unwrap().value, "a");
+        form.handle_key(KeyEvent::from(KeyCode::Enter));
+        assert!(form.is_sensitive());
+        assert_eq!(form.snapshot().unwrap().message, "Age");
+        form.handle_key(KeyEvent::from(KeyCode::Char('4')));
+
+        // go back to edit the name
+        form.handle_key(KeyEvent::from(KeyCode::BackTab));
+        form.handle_key(KeyEvent::from(KeyCode::Char('b')));
+        form.handle_key(KeyEvent::from(KeyCode::Enter));
+        assert_eq!(form.focused(), 1);
+
+        assert!(form.handle_key(KeyEvent::from(KeyCode::Enter)));
+        assert_eq!(
+            form.answers(),
+            [("name", Answer::from("ab")), ("age", Answer::from(4u8))]
+        );
+    }
+
+    #[test]
+    fn skip_optional_prompts() {
+        let mut email = Text::new("Email");
+        let mut name = Text::new("Name");
+        let mut form = Form::new();
+        let skip = KeyEvent::new(KeyCode::Char('s'), KeyModifiers::CONTROL);
+
+        form.add("email", &mut email)
+            .skippable()
+            .add("name", &mut name);
+
+        form.handle_key(KeyEvent::from(KeyCode::Char('a')));
+        form.handle_key(skip);
+        assert_eq!(form.focused(), 1);
+        assert_eq!(form.answers()[0], ("email", Answer::Unit));
+
+        // only the skippable prompts can be skipped
+        form.handle_key(skip);
+        assert_eq!(form.focused(), 1);
+
+        // submitting it again replaces the skip
+        form.handle_key(KeyEvent::from(KeyCode::BackTab));
+        form.handle_key(KeyEvent::from(KeyCode::Enter));
+        assert_eq!(form.answers()[0], ("email", Answer::from("a")));
+
+        form.handle_key(KeyEvent::from(KeyCode::BackTab));
+        form.handle_key(skip);
+        assert!(form.handle_key(KeyEvent::from(KeyCode::Enter)));
+        assert_eq!(
+            form.answers(),
+            [("email", Answer::Unit), ("name", Answer::from(""))]
+        );
+    }
+
+    #[test]
+    fn draw_skipped_prompts() {
+        let mut email = Text::new("Email");
+        let mut name = Text::new("Name");
+        let mut form = Form::new();
+        let mut session = Session::new(40, 10);
+
+        form.add("email", &mut email)
+            .skippable()
+            .add("name", &mut name);
+        session
+            .key(KeyEvent::new(KeyCode::Char('s'), KeyModifiers::CONTROL))
+            .run(&mut form)
+            .unwrap();
+
+        let frame = session.last_frame().unwrap();
+        assert_eq!(
+            frame.lines[0],
+            format!(
+                "{} Email skipped",
+                AskySettings::global().theme.glyphs().answered
+            )
+        );
+    }
+
+    #[test]
+    fn hide_prompts_by_condition() {
+        let mut tls = Text::new("Use TLS?");
+        let mut cert = Text::new("Certificate");
+        let mut port = Number::<u16>::new("Port");
+        let mut form = Form::new();
+
+        tls.initial("no");
+        form.add("tls", &mut tls)
+            .add("cert", &mut cert)
+            .when(|answers| answers.get("tls") == Some(&Answer::from("yes")))
+            .add("port", &mut port);
+        form.prepare();
+
+        form.handle_key(KeyEvent::from(KeyCode::Enter));
+        assert_eq!(form.focused(), 2);
+        assert_eq!(
+            form.answers(),
+            [("tls", Answer::from("no")), ("port", Answer::Unit)]
+        );
+
+        // the condition is checked again after editing the answer
+        form.handle_key(KeyEvent::from(KeyCode::BackTab));
+        assert_eq!(form.focused(), 0);
+
+        for key in [KeyCode::Backspace, KeyCode::Backspace] {
+            form.handle_key(KeyEvent::from(key));
+        }
+
+        "yes".chars().for_each(|c| {
+            form.handle_key(KeyEvent::from(KeyCode::Char(c)));
+        });
+        form.handle_key(KeyEvent::from(KeyCode::Enter));
+        assert_eq!(form.focused(), 1);
+        assert_eq!(form.answers().len(), 3);
+    }
+}
@@ -1,7 +1,11 @@
 pub mod confirm;
+pub mod form;
 pub mod multi_select;
 pub mod number;
+pub mod pair;
 pub mod password;
+pub mod percent;
 pub mod select;
 pub mod text;
 pub mod toggle;
+pub mod type_to_confirm;
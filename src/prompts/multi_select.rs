@@ -1,16 +1,32 @@
-use std::io;
+use std::{cmp::Ordering, io};
 
 use crossterm::event::{KeyCode, KeyEvent};
 
 use crate::utils::{
+    answer::{Answer, Recordable},
+    answer_source,
+    error::Error,
     key_listener::{self, Typeable},
+    last_choices::LastChoices,
     renderer::{DrawTime, Printable, Renderer},
+    state::PromptState,
     theme,
 };
 
 use super::select::{Direction, SelectInput, SelectOption};
 
 type Formatter<'a, T> = dyn Fn(&MultiSelect<T>, DrawTime) -> String + 'a;
+type Reporter<'a, T> = dyn Fn(&[&T]) -> String + 'a;
+
+/// Order of the values returned by [`MultiSelect`].
+pub enum ResultOrder<T> {
+    /// Same order as the options.
+    ListOrder,
+    /// Order in which the user selected the options.
+    SelectionOrder,
+    /// Sorted with a comparison function, like `|a, b| a.cmp(b)`.
+    Sorted(fn(&T, &T) -> Ordering),
+}
 
 /// Prompt to select multiple items from a list.
 ///
@@ -41,6 +57,10 @@ type Formatter<'a, T> = dyn Fn(&MultiSelect<T>, DrawTime) -> String + 'a;
 pub struct MultiSelect<'a, T> {
     /// Message used to display in the prompt.
     pub message: &'a str,
+    /// Stable identifier of the prompt.
+    pub name: Option<&'a str>,
+    /// Key of the answer, when it's recorded in [`Answers`](crate::Answers).
+    pub key: Option<&'a str>,
     /// List of options.
     pub options: Vec<SelectOption<'a, T>>,
     /// Minimum number of items required to be selected.
@@ -49,7 +69,14 @@ pub struct MultiSelect<'a, T> {
     pub max: Option<usize>,
     /// Input state.
     pub input: SelectInput,
+    /// Order of the returned values, by default the same as the options.
+    pub result_order: ResultOrder<T>,
     selected_count: usize,
+    /// Indices of the selected options, in the order they were selected.
+    selection_order: Vec<usize>,
+    last_choices: Option<&'a mut LastChoices>,
+    submitted: Answer,
+    reporter: Option<Box<Reporter<'a, T>>>,
     formatter: Box<Formatter<'a, T>>,
 }
 
@@ -83,15 +110,20 @@ impl<'a, T: 'a> MultiSelect<'a, T> {
     /// # Ok(())
     /// # }
     pub fn new_complex(message: &'a str, options: Vec<SelectOption<'a, T>>) -> Self {
-        let options_len = options.len();
-
         MultiSelect {
             message,
+            name: None,
+            key: None,
+            input: SelectInput::new(&options),
             options,
             min: None,
             max: None,
             selected_count: 0,
-            input: SelectInput::new(options_len),
+            selection_order: Vec::new(),
+            result_order: ResultOrder::ListOrder,
+            last_choices: None,
+            submitted: Answer::Unit,
+            reporter: None,
             formatter: Box::new(theme::fmt_multi_select),
         }
     }
@@ -102,6 +134,7 @@ impl<'a, T: 'a> MultiSelect<'a, T> {
             if let Some(option) = self.options.get_mut(*i) {
                 option.active = true;
                 self.selected_count += 1;
+                self.selection_order.push(*i);
             }
         }
 
@@ -132,6 +165,47 @@ impl<'a, T: 'a> MultiSelect<'a, T> {
         self
     }
 
+    /// Set the order of the returned values.
+    ///
+    /// ```no_run
+    /// use asky::{MultiSelect, ResultOrder};
+    ///
+    /// # fn main() -> std::io::Result<()> {
+    /// let toppings = MultiSelect::new("Toppings, by preference", ["Cheese", "Ham", "Olives"])
+    ///     .result_order(ResultOrder::SelectionOrder)
+    ///     .prompt()?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn result_order(&mut self, order: ResultOrder<T>) -> &mut Self {
+        self.result_order = order;
+        self
+    }
+
+    /// Select the options chosen the last time, and remember the new choices.
+    ///
+    /// The choices are saved by the prompt [`name`](MultiSelect::name) and the option titles,
+    /// it does nothing for prompts without a name.
+    /// The remembered options replace the ones set with [`MultiSelect::selected`].
+    pub fn remember_last(&mut self, choices: &'a mut LastChoices) -> &mut Self {
+        self.last_choices = Some(choices);
+        self
+    }
+
+    /// Set a stable identifier for the prompt, like `"db.host"`.
+    ///
+    /// It is available to custom formatters, which can use it to render per-field markers.
+    pub fn name(&mut self, name: &'a str) -> &mut Self {
+        self.name = Some(name);
+        self
+    }
+
+    /// Set the key of the answer, when it's recorded in [`Answers`](crate::Answers).
+    pub fn key(&mut self, key: &'a str) -> &mut Self {
+        self.key = Some(key);
+        self
+    }
+
     /// Set custom closure to format the prompt.
     ///
     /// See: [`Customization`](index.html#customization).
@@ -143,24 +217,140 @@ impl<'a, T: 'a> MultiSelect<'a, T> {
         self
     }
 
+    /// Set a closure to display the chosen options in the final line, like `"3 items"`.
+    ///
+    /// It receives the values of the chosen options, in the order of the list.
+    pub fn report_with<F>(&mut self, reporter: F) -> &mut Self
+    where
+        F: Fn(&[&T]) -> String + 'a,
+    {
+        self.reporter = Some(Box::new(reporter));
+        self
+    }
+
     /// Display the prompt and return the user answer.
-    pub fn prompt(&mut self) -> io::Result<Vec<T>> {
+    pub fn prompt(&mut self) -> Result<Vec<T>, Error> {
         key_listener::listen(self, true)?;
+        self.save_choices()?;
+        Ok(self.take_selected())
+    }
 
-        let (selected, _): (Vec<_>, Vec<_>) = self.options.drain(..).partition(|x| x.active);
-        let selected = selected.into_iter().map(|x| x.value).collect();
-
-        Ok(selected)
+    /// Async version of [`MultiSelect::prompt`].
+    #[cfg(feature = "async")]
+    pub async fn prompt_async(&mut self) -> Result<Vec<T>, Error> {
+        key_listener::listen_async(self, true).await?;
+        self.save_choices()?;
+        Ok(self.take_selected())
     }
 }
 
 impl<T> MultiSelect<'_, T> {
+    /// Returns the answer to display in the final line, when using `report_with`.
+    pub(crate) fn report(&self) -> Option<String> {
+        let reporter = self.reporter.as_ref()?;
+        let values: Vec<&T> = self
+            .options
+            .iter()
+            .filter(|option| option.active)
+            .map(|option| &option.value)
+            .collect();
+
+        Some(reporter(&values))
+    }
+
+    fn take_selected(&mut self) -> Vec<T> {
+        let order = match self.result_order {
+            ResultOrder::SelectionOrder => self.selection_order.clone(),
+            _ => Vec::new(),
+        };
+
+        let mut values: Vec<_> = self
+            .options
+            .drain(..)
+            .map(|x| x.active.then_some(x.value))
+            .collect();
+        let len = values.len();
+
+        // options activated without toggling them keep the list order
+        let mut selected: Vec<T> = order
+            .into_iter()
+            .chain(0..len)
+            .filter_map(|i| values.get_mut(i)?.take())
+            .collect();
+
+        if let ResultOrder::Sorted(compare) = self.result_order {
+            selected.sort_by(compare);
+        }
+
+        selected
+    }
+
+    /// Select the last chosen options, in the order they were selected.
+    fn restore_last_choices(&mut self) {
+        let (Some(name), Some(choices)) = (self.name, self.last_choices.as_deref()) else {
+            return;
+        };
+
+        let Some(titles) = choices.get(name) else {
+            return;
+        };
+
+        let chosen: Vec<usize> = titles
+            .iter()
+            .filter_map(|title| {
+                (0..self.options.len()).find(|&i| self.options[i].get_title() == title)
+            })
+            .filter(|&i| !self.options[i].disabled)
+            .take(self.max.unwrap_or(usize::MAX))
+            .collect();
+
+        for option in &mut self.options {
+            option.active = false;
+        }
+
+        for &i in &chosen {
+            self.options[i].active = true;
+        }
+
+        self.selected_count = chosen.len();
+        self.selection_order = chosen;
+    }
+
+    /// Save the selected options, before they are taken out.
+    fn save_choices(&mut self) -> io::Result<()> {
+        self.submitted = (0..self.options.len())
+            .filter(|&i| self.options[i].active)
+            .collect::<Vec<_>>()
+            .into();
+
+        let (Some(name), Some(choices)) = (self.name, self.last_choices.as_deref_mut()) else {
+            return Ok(());
+        };
+
+        // selection order first, then the options activated without toggling them
+        let titles = self
+            .selection_order
+            .iter()
+            .copied()
+            .chain(0..self.options.len())
+            .filter(|&i| self.options.get(i).is_some_and(|option| option.active))
+            .map(|i| self.options[i].get_title().to_owned())
+            .fold(Vec::new(), |mut titles, title| {
+                if !titles.contains(&title) {
+                    titles.push(title);
+                }
+                titles
+            });
+
+        choices.set(name, titles)
+    }
+
     fn toggle_focused(&mut self) {
         let selected = self.input.focused;
-        let focused = &self.options[selected];
 
-        if focused.disabled {
-            return;
+        match self.options.get(selected) {
+            Some(focused) if !focused.disabled => (),
+            _ => return,
         }
 
         let under_limit = match self.max {
@@ -173,9 +363,11 @@ impl<T> MultiSelect<'_, T> {
         if focused.active {
             focused.active = false;
             self.selected_count -= 1;
+            self.selection_order.retain(|&i| i != selected);
         } else if under_limit {
             focused.active = true;
             self.selected_count += 1;
+            self.selection_order.push(selected);
         }
     }
 
@@ -188,11 +380,27 @@ impl<T> MultiSelect<'_, T> {
     }
 }
 
+impl<'a, T: 'a> Recordable for MultiSelect<'a, T> {
+    type Output = Vec<T>;
+
+    fn record_key(&self) -> Option<&str> {
+        self.key
+    }
+
+    fn ask(&mut self) -> Result<Vec<T>, Error> {
+        self.prompt()
+    }
+
+    fn submitted_answer(&self) -> Answer {
+        self.submitted.clone()
+    }
+}
+
 impl<T> Typeable for MultiSelect<'_, T> {
     fn handle_key(&mut self, key: KeyEvent) -> bool {
         let mut submit = false;
 
-        match key.code {
+        match key_listener::navigation_key(key.code) {
             // submit
             KeyCode::Enter | KeyCode::Backspace => submit = self.validate_to_submit(),
             // select/unselect
@@ -207,6 +415,61 @@ impl<T> Typeable for MultiSelect<'_, T> {
 
         submit
     }
+
+    fn answer_key(&self) -> Option<&str> {
+        self.key.or(self.name)
+    }
+
+    fn seed(&mut self, value: &str) -> bool {
+        let indices: Option<Vec<usize>> = answer_source::split_titles(value)
+            .map(|title| answer_source::find_option(&self.options, title))
+            .collect();
+
+        let Some(indices) = indices else {
+            return false;
+        };
+
+        self.selection_order.clear();
+
+        for i in indices {
+            if !self.selection_order.contains(&i) {
+                self.selection_order.push(i);
+            }
+        }
+
+        for (i, option) in self.options.iter_mut().enumerate() {
+            option.active = self.selection_order.contains(&i);
+        }
+
+        self.selected_count = self.selection_order.len();
+
+        let under_limit = match self.max {
+            None => true,
+            Some(max) => self.selected_count <= max,
+        };
+
+        under_limit && self.validate_to_submit()
+    }
+
+    fn prepare(&mut self) {
+        self.restore_last_choices();
+    }
+}
+
+impl<T> MultiSelect<'_, T> {
+    /// Returns a snapshot of the prompt state, see [`set_state_observer`](crate::set_state_observer).
+    pub fn state(&self) -> PromptState {
+        let value = self
+            .options
+            .iter()
+            .filter(|option| option.active)
+            .map(|option| option.get_title())
+            .collect::<Vec<_>>()
+            .join(", ");
+
+        PromptState::new(self.message, self.name, value)
+            .with_options(&self.options, self.input.focused_index())
+    }
 }
 
 impl<T> Printable for MultiSelect<'_, T> {
@@ -214,6 +477,10 @@ impl<T> Printable for MultiSelect<'_, T> {
         let text = (self.formatter)(self, renderer.draw_time);
         renderer.print(text)
     }
+
+    fn snapshot(&self) -> Option<PromptState> {
+        Some(self.state())
+    }
 }
 
 #[cfg(test)]
@@ -229,6 +496,56 @@ mod tests {
         assert!(prompt.options[2].active);
     }
 
+    #[test]
+    fn order_results() {
+        let select = |order| {
+            let mut prompt = MultiSelect::new("", ["b", "a", "c"]);
+            prompt.result_order(order);
+
+            for focused in [2, 0, 1] {
+                prompt.input.focused = focused;
+                prompt.handle_key(KeyEvent::from(KeyCode::Char(' ')));
+            }
+
+            prompt.take_selected()
+        };
+
+        assert_eq!(select(ResultOrder::ListOrder), ["b", "a", "c"]);
+        assert_eq!(select(ResultOrder::SelectionOrder), ["c", "b", "a"]);
+        assert_eq!(
+            select(ResultOrder::Sorted(|a, b| a.cmp(b))),
+            ["a", "b", "c"]
+        );
+    }
+
+    #[test]
+    fn remember_last_choices() {
+        let mut choices = LastChoices::new();
+        let mut prompt = MultiSelect::new("", ["a", "b", "c"]);
+
+        prompt.name("letters").remember_last(&mut choices);
+
+        for focused in [2, 0] {
+            prompt.input.focused = focused;
+            prompt.handle_key(KeyEvent::from(KeyCode::Char(' ')));
+        }
+
+        prompt.save_choices().unwrap();
+        assert_eq!(prompt.submitted_answer(), Answer::Indices(vec![0, 2]));
+        drop(prompt);
+
+        let mut prompt = MultiSelect::new("", ["a", "b", "c"]);
+
+        prompt
+            .name("letters")
+            .selected(&[1])
+            .result_order(ResultOrder::SelectionOrder)
+            .remember_last(&mut choices);
+        prompt.restore_last_choices();
+
+        assert_eq!(prompt.take_selected(), ["c", "a"]);
+    }
+
     #[test]
     fn set_min() {
         let mut prompt = MultiSelect::<&str>::new("", vec![]);
@@ -257,6 +574,15 @@ mod tests {
         assert!(prompt.input.loop_mode);
     }
 
+    #[test]
+    fn set_name() {
+        let mut prompt = MultiSelect::new("", ["a", "b", "c"]);
+
+        assert_eq!(prompt.name, None);
+        prompt.name("foo");
+        assert_eq!(prompt.name, Some("foo"));
+    }
+
     #[test]
     fn set_custom_formatter() {
         let mut prompt: MultiSelect<u8> = MultiSelect::new("", vec![]);
@@ -268,6 +594,18 @@ mod tests {
         assert_eq!((prompt.formatter)(&prompt, draw_time), EXPECTED_VALUE);
     }
 
+    #[test]
+    fn report_answer() {
+        let mut prompt = MultiSelect::new("Toppings", ["a", "b", "c"]);
+
+        prompt
+            .selected(&[0, 2])
+            .report_with(|values| format!("{} items", values.len()));
+
+        let text = (prompt.formatter)(&prompt, DrawTime::Last);
+        assert!(text.contains("2 items") && !text.contains("[a, c]"));
+    }
+
     #[test]
     fn submit_keys() {
         let events = [KeyCode::Enter, KeyCode::Backspace];
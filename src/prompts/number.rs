@@ -1,19 +1,128 @@
-use std::{io, str::FromStr};
+use std::{borrow::Cow, io, str::FromStr};
 
 use crossterm::event::{KeyCode, KeyEvent};
 
 use crate::utils::{
+    answer::{Answer, Recordable},
+    error::Error,
     key_listener::{self, Typeable},
     num_like::NumLike,
     renderer::{DrawTime, Printable, Renderer},
+    state::PromptState,
     theme,
 };
 
+use super::pair::Composable;
 use super::text::{Direction, LineInput};
 
 type InputValidator<'a, T> =
-    dyn Fn(&str, Result<T, <T as FromStr>::Err>) -> Result<(), &'a str> + 'a;
+    dyn Fn(&str, Result<T, <T as FromStr>::Err>) -> Result<(), Cow<'a, str>> + 'a;
 type Formatter<'a, T> = dyn Fn(&Number<T>, DrawTime) -> (String, [usize; 2]) + 'a;
+type Reporter<'a, T> = dyn Fn(&T) -> String + 'a;
+
+/// Default message of [`Number::prompt_validated`] for invalid numbers.
+const INVALID_NUMBER_MESSAGE: &str = "Please enter a valid number";
+
+/// How the input of a [`Number`] is parsed.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum NumberMode {
+    /// Only digits, the decimal point, and the sign.
+    #[default]
+    Plain,
+    /// Also digits grouped with `_` or `,`, like `1_000` or `1,000`.
+    ///
+    /// The `,` (or the group symbol of the locale) must be between groups of 3 digits.
+    Separators,
+    /// Also integers with a `0x`, `0b`, or `0o` prefix, like `0xff`. Digits can be grouped too.
+    Radix,
+}
+
+/// Symbols of the numbers in a [`Number`] prompt, by language, see [`Number::locale`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct NumberLocale {
+    /// Symbol of the decimal point, like `.` in `1.5` or `,` in `1,5`.
+    pub decimal: char,
+    /// Symbol between groups of digits, like `,` in `1,000` or `.` in `1.000`.
+    pub group: char,
+}
+
+impl NumberLocale {
+    /// Returns the symbols of a language tag, like `"de-DE"` or `"fr"`, if it's known.
+    ///
+    /// The tag is case-insensitive, and `_` can be used instead of `-`, like in `LANG=de_DE.UTF-8`.
+    pub fn from_tag(tag: &str) -> Option<Self> {
+        let tag = tag.split('.').next().unwrap_or_default().to_lowercase();
+        let mut subtags = tag.split(['-', '_']);
+        let language = subtags.next().unwrap_or_default();
+        let region = subtags.next_back().unwrap_or_default();
+
+        let (decimal, group) = match (language, region) {
+            ("de" | "it", "ch" | "li") => ('.', '\''),
+            ("es", "mx" | "us") => ('.', ','),
+            ("en" | "ja" | "zh" | "ko" | "he" | "th" | "hi" | "ms", _) => ('.', ','),
+            (
+                "de" | "es" | "it" | "nl" | "pt" | "id" | "tr" | "da" | "el" | "ro" | "hr" | "sl"
+                | "sr" | "vi",
+                _,
+            ) => (',', '.'),
+            (
+                "fr" | "ru" | "pl" | "cs" | "sk" | "sv" | "fi" | "nb" | "no" | "uk" | "hu" | "bg"
+                | "lt" | "lv" | "et",
+                _,
+            ) => (',', ' '),
+            _ => return None,
+        };
+
+        Some(NumberLocale { decimal, group })
+    }
+
+    /// Format a number displayed by Rust, like `-1234.5`, with the symbols of the locale.
+    fn format(&self, value: &str, grouped: bool) -> String {
+        let (integer, fraction) = match value.split_once('.') {
+            Some((integer, fraction)) => (integer, Some(fraction)),
+            None => (value, None),
+        };
+        let (sign, digits) = integer.split_at(usize::from(integer.starts_with(['-', '+'])));
+        let len = digits.chars().count();
+        let mut text = sign.to_string();
+
+        for (i, c) in digits.chars().enumerate() {
+            if grouped && i > 0 && (len - i) % 3 == 0 {
+                text.push(self.group);
+            }
+
+            text.push(c);
+        }
+
+        if let Some(fraction) = fraction {
+            text.push(self.decimal);
+            text.push_str(fraction);
+        }
+
+        text
+    }
+
+    /// Replace the symbols of the locale in a number by the ones of another locale.
+    fn translate(&self, value: &str, other: NumberLocale) -> String {
+        value
+            .chars()
+            .map(|c| match c {
+                _ if c == self.decimal => other.decimal,
+                _ if c == self.group => other.group,
+                _ => c,
+            })
+            .collect()
+    }
+}
+
+impl Default for NumberLocale {
+    fn default() -> Self {
+        NumberLocale {
+            decimal: '.',
+            group: ',',
+        }
+    }
+}
 
 /// Prompt to get one-line user input of numbers.
 ///
@@ -28,11 +137,18 @@ type Formatter<'a, T> = dyn Fn(&Number<T>, DrawTime) -> (String, [usize; 2]) + '
 /// | `Delete`    | Delete current character     |
 /// | `Left`      | Move cursor left             |
 /// | `Right`     | Move cursor right            |
+/// | `Up`        | Increment value by the step  |
+/// | `Down`      | Decrement value by the step  |
 /// | `Backspace` | Delete previous character    |
 /// | `.`         | Add decimal point [^decimal]  |
 /// | `-`, `+`    | Add sign to the input [^sign] |
 ///
-/// [^decimal]: Only for floating values.
+/// Digit separators and radix prefixes are accepted depending on the [`NumberMode`],
+/// and the symbols of the decimal point and the separators depend on the [`Number::locale`].
+///
+/// Also the `Home`, `End`, word-jump and readline-like shortcuts of [`Text`], like `Ctrl+A`.
+///
+/// [^decimal]: Only for floating values, `,` in some locales.
 ///
 /// [^sign]:  Only for signed values and when cursor is at start of the input.
 ///
@@ -50,15 +166,44 @@ type Formatter<'a, T> = dyn Fn(&Number<T>, DrawTime) -> (String, [usize; 2]) + '
 pub struct Number<'a, T: NumLike> {
     /// Message used to display in the prompt.
     pub message: &'a str,
+    /// Stable identifier of the prompt.
+    pub name: Option<&'a str>,
+    /// Key of the answer, when it's recorded in [`Answers`](crate::Answers).
+    pub key: Option<&'a str>,
     /// Input state for the prompt.
     pub input: LineInput,
     /// Placeholder to show when the input is empty.
     pub placeholder: Option<&'a str>,
+    /// Non-editable text to show before the input.
+    pub prefix: Option<&'a str>,
+    /// Non-editable text to show after the input.
+    pub suffix: Option<&'a str>,
     /// Default value to submit when the input is empty.
     pub default_value: Option<String>,
     /// State of the validation of the user input.
-    pub validator_result: Result<(), &'a str>,
+    pub validator_result: Result<(), Cow<'a, str>>,
+    /// Whether the validator runs again when the input is edited after an invalid submit.
+    pub validate_on_edit: bool,
+    /// Indicate if the input was edited after an invalid submit, when using `validate_on_edit`.
+    pub validator_edited: bool,
+    /// Message to show when the input is not a valid number, which can't be submitted.
+    ///
+    /// By default (`None`), invalid numbers are submitted as a parse error.
+    pub invalid_message: Option<&'a str>,
+    /// Minimum value accepted, inclusive.
+    pub min: Option<T>,
+    /// Maximum value accepted, inclusive.
+    pub max: Option<T>,
+    /// Amount to increment or decrement the value with `Up` and `Down`.
+    pub step: T,
+    /// How the input is parsed.
+    pub mode: NumberMode,
+    /// Symbols of the decimal point and the digit groups, see [`Number::locale`].
+    pub locale: NumberLocale,
+    /// Whether the answer is masked once submitted, see [`Number::sensitive`].
+    pub sensitive: bool,
     validator: Option<Box<InputValidator<'a, T>>>,
+    reporter: Option<Box<Reporter<'a, T>>>,
     formatter: Box<Formatter<'a, T>>,
 }
 
@@ -67,11 +212,25 @@ impl<'a, T: NumLike + 'a> Number<'a, T> {
     pub fn new(message: &'a str) -> Self {
         Number {
             message,
+            name: None,
+            key: None,
             input: LineInput::new(),
             placeholder: None,
+            prefix: None,
+            suffix: None,
             default_value: None,
             validator: None,
             validator_result: Ok(()),
+            validate_on_edit: false,
+            validator_edited: false,
+            invalid_message: None,
+            min: None,
+            max: None,
+            step: T::unit(),
+            mode: NumberMode::Plain,
+            locale: NumberLocale::default(),
+            sensitive: false,
+            reporter: None,
             formatter: Box::new(theme::fmt_number),
         }
     }
@@ -84,24 +243,117 @@ impl<'a, T: NumLike + 'a> Number<'a, T> {
         self
     }
 
+    /// Set non-editable text to show before the input, like `"$ "`.
+    pub fn prefix(&mut self, value: &'a str) -> &mut Self {
+        self.prefix = Some(value);
+        self
+    }
+
+    /// Set non-editable text to show after the input, like `" MB"`.
+    pub fn suffix(&mut self, value: &'a str) -> &mut Self {
+        self.suffix = Some(value);
+        self
+    }
+
     /// Set default value to submit when the input is empty.
     pub fn default(&mut self, value: T) -> &mut Self {
-        self.default_value = Some(value.to_string());
+        self.default_value = Some(self.display(&value));
         self
     }
 
     /// Set initial value, could be deleted by the user.
     pub fn initial(&mut self, value: T) -> &mut Self {
-        self.input.set_value(&value.to_string());
+        self.input.set_value(&self.display(&value));
         self
     }
 
     /// Set validator to the user input.
-    pub fn validate<F>(&mut self, validator: F) -> &mut Self
+    ///
+    /// The error message can be borrowed or owned, like `format!("must be less than {}", max)`.
+    pub fn validate<F, E>(&mut self, validator: F) -> &mut Self
     where
-        F: Fn(&str, Result<T, T::Err>) -> Result<(), &'a str> + 'static,
+        F: Fn(&str, Result<T, T::Err>) -> Result<(), E> + 'static,
+        E: Into<Cow<'a, str>>,
     {
-        self.validator = Some(Box::new(validator));
+        self.validator = Some(Box::new(move |input, value| {
+            validator(input, value).map_err(Into::into)
+        }));
+        self
+    }
+
+    /// Set the message to show when the input is not a valid number, like an empty input
+    /// without default value. Invalid numbers can't be submitted when it's set.
+    pub fn invalid_message(&mut self, message: &'a str) -> &mut Self {
+        self.invalid_message = Some(message);
+        self
+    }
+
+    /// Set the minimum value accepted, inclusive.
+    ///
+    /// Lower values can't be submitted, and `Down` stops at it.
+    pub fn min(&mut self, value: T) -> &mut Self {
+        self.min = Some(value);
+        self
+    }
+
+    /// Set the maximum value accepted, inclusive.
+    ///
+    /// Greater values can't be submitted, and `Up` stops at it.
+    pub fn max(&mut self, value: T) -> &mut Self {
+        self.max = Some(value);
+        self
+    }
+
+    /// Set the amount to increment or decrement the value with `Up` and `Down`, `1` by default.
+    pub fn step(&mut self, value: T) -> &mut Self {
+        self.step = value;
+        self
+    }
+
+    /// Set how the input is parsed, to accept digit separators or radix prefixes.
+    pub fn mode(&mut self, mode: NumberMode) -> &mut Self {
+        self.mode = mode;
+        self
+    }
+
+    /// Set the symbols of the numbers by a language tag, like `"de-DE"` for `1.000,5`.
+    ///
+    /// They are used to type and parse the input, and to display the initial and default values.
+    /// The current input and default value are updated to the new symbols.
+    /// Unknown tags use the default symbols, like `1,000.5`, see [`NumberLocale::from_tag`].
+    pub fn locale(&mut self, tag: &str) -> &mut Self {
+        let locale = NumberLocale::from_tag(tag).unwrap_or_default();
+        let input = self.locale.translate(&self.input.value, locale);
+
+        self.input.set_value(&input);
+        self.default_value = self
+            .default_value
+            .as_deref()
+            .map(|value| self.locale.translate(value, locale));
+        self.locale = locale;
+        self
+    }
+
+    /// Set a stable identifier for the prompt, like `"db.host"`.
+    ///
+    /// It is available to custom formatters, which can use it to render per-field markers.
+    pub fn name(&mut self, name: &'a str) -> &mut Self {
+        self.name = Some(name);
+        self
+    }
+
+    /// Set the key of the answer, when it's recorded in [`Answers`](crate::Answers).
+    pub fn key(&mut self, key: &'a str) -> &mut Self {
+        self.key = Some(key);
+        self
+    }
+
+    /// Set whether the validator runs again when the input is edited after an invalid submit.
+    ///
+    /// The error is kept visible (dimmed) while the user fixes the input,
+    /// and it's cleared as soon as the input is valid.
+    pub fn validate_on_edit(&mut self, validate_on_edit: bool) -> &mut Self {
+        self.validate_on_edit = validate_on_edit;
         self
     }
 
@@ -116,25 +368,138 @@ impl<'a, T: NumLike + 'a> Number<'a, T> {
         self
     }
 
+    /// Set a closure to display the submitted answer in the final line, like `"3 retries"`.
+    ///
+    /// It receives the submitted number, and its result replaces the answer and its adornments,
+    /// the rest of the line is still displayed by the formatter.
+    /// It isn't used if the submitted input is not a valid number.
+    pub fn report_with<F>(&mut self, reporter: F) -> &mut Self
+    where
+        F: Fn(&T) -> String + 'a,
+    {
+        self.reporter = Some(Box::new(reporter));
+        self
+    }
+
+    /// Mask the answer once submitted, like a PIN code.
+    ///
+    /// The input is displayed while editing, but the final line, the state sent to the observer
+    /// and the recorded keys don't include it. The `report_with` closure is still used, if set.
+    ///
+    /// The answer is recorded as [`Answer::Secret`].
+    pub fn sensitive(&mut self) -> &mut Self {
+        self.sensitive = true;
+        self
+    }
+
     /// Display the prompt and return the user answer.
-    pub fn prompt(&mut self) -> io::Result<Result<T, T::Err>> {
+    ///
+    /// Returns [`Error::InvalidInput`] if the submitted input is not a valid number,
+    /// see [`Number::prompt_validated`] to ask again instead.
+    pub fn prompt(&mut self) -> Result<T, Error> {
         key_listener::listen(self, false)?;
-        Ok(self.get_value())
+        self.answer()
+    }
+
+    /// Async version of [`Number::prompt`].
+    #[cfg(feature = "async")]
+    pub async fn prompt_async(&mut self) -> Result<T, Error> {
+        key_listener::listen_async(self, false).await?;
+        self.answer()
+    }
+
+    /// Display the prompt and return the number, asking again while the input isn't valid.
+    ///
+    /// The error is displayed with the message set in [`Number::invalid_message`],
+    /// or a default one.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// use asky::Number;
+    ///
+    /// # fn main() -> std::io::Result<()> {
+    /// let age = Number::<u8>::new("How old are you?").prompt_validated()?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn prompt_validated(&mut self) -> Result<T, Error> {
+        self.invalid_message.get_or_insert(INVALID_NUMBER_MESSAGE);
+        self.prompt()
+    }
+
+    /// Async version of [`Number::prompt_validated`].
+    #[cfg(feature = "async")]
+    pub async fn prompt_validated_async(&mut self) -> Result<T, Error> {
+        self.invalid_message.get_or_insert(INVALID_NUMBER_MESSAGE);
+        self.prompt_async().await
     }
 }
 
 impl<T: NumLike> Number<'_, T> {
+    /// Returns the answer to display in the final line, when using `report_with`.
+    pub(crate) fn report(&self) -> Option<String> {
+        let reporter = self.reporter.as_ref()?;
+        self.get_value().ok().map(|value| reporter(&value))
+    }
+
+    fn answer(&self) -> Result<T, Error> {
+        self.get_value()
+            .map_err(|_| Error::InvalidInput(self.input.value.clone()))
+    }
+
     fn get_value(&self) -> Result<T, T::Err> {
         match self.input.value.is_empty() {
-            true => self.default_value.clone().unwrap_or_default().parse(),
-            false => self.input.value.parse(),
+            true => self.parse(&self.default_value.clone().unwrap_or_default()),
+            false => self.parse(&self.input.value),
+        }
+    }
+
+    fn parse(&self, value: &str) -> Result<T, T::Err> {
+        let NumberLocale { decimal, group } = self.locale;
+        let separators = self.mode != NumberMode::Plain;
+        let grouped = separators && is_grouped(value, self.locale);
+        let value: String = value
+            .chars()
+            .filter_map(|c| match c {
+                '_' if separators => None,
+                _ if c == group && grouped => None,
+                // invalid for any type, so badly grouped numbers fail with the error of the type
+                _ if c == group => Some('_'),
+                _ if c == decimal => Some('.'),
+                _ => Some(c),
+            })
+            .collect();
+
+        if self.mode == NumberMode::Plain {
+            return value.parse();
+        }
+
+        if self.mode == NumberMode::Radix {
+            let (sign, radix, digits) = split_radix(&value);
+
+            if radix != 10 {
+                if let Some(number) = T::from_str_radix(&(sign.to_owned() + digits), radix) {
+                    return Ok(number);
+                }
+            }
         }
+
+        // invalid prefixed numbers fail here too, with the error of the type
+        value.parse()
     }
 
     fn insert(&mut self, ch: char) {
+        let radix = split_radix(&self.input.value).1;
+
         let is_valid = match ch {
             '-' | '+' => T::is_signed() && self.input.col == 0,
-            '.' => T::is_float() && !self.input.value.contains('.'),
+            _ if ch == self.locale.decimal => {
+                T::is_float() && !self.input.value.contains(self.locale.decimal)
+            }
+            _ if ch == '_' || ch == self.locale.group => self.mode != NumberMode::Plain,
+            'x' | 'b' | 'o' if self.accepts_prefix() => true,
+            _ if radix == 16 => ch.is_ascii_hexdigit(),
             _ => ch.is_ascii_digit(),
         };
 
@@ -143,20 +508,177 @@ impl<T: NumLike> Number<'_, T> {
         }
     }
 
+    /// Check if a radix prefix can be typed, right after a leading zero.
+    fn accepts_prefix(&self) -> bool {
+        if self.mode != NumberMode::Radix || T::is_float() {
+            return false;
+        }
+
+        let value = &self.input.value;
+        let (sign, radix, digits) = split_radix(value);
+
+        radix == 10 && digits.starts_with('0') && self.input.col == sign.len() + 1
+    }
+
     fn validate_to_submit(&mut self) -> bool {
-        if let Some(validator) = &self.validator {
-            self.validator_result = validator(&self.input.value, self.get_value());
+        self.validator_edited = false;
+
+        self.validator_result = match &self.validator {
+            Some(validator) => validator(&self.input.value, self.get_value()),
+            None => Ok(()),
+        };
+
+        if let (Ok(_), Some(message)) = (&self.validator_result, self.invalid_message) {
+            if self.get_value().is_err() {
+                self.validator_result = Err(message.into());
+            }
+        }
+
+        if let (Ok(_), Ok(value)) = (&self.validator_result, self.get_value()) {
+            if let Some(message) = self.range_message(&value) {
+                self.validator_result = Err(message.into());
+            }
         }
 
         self.validator_result.is_ok()
     }
+
+    /// Returns the error message if the value is out of the `min` and `max` bounds.
+    fn range_message(&self, value: &T) -> Option<String> {
+        let below = self.min.as_ref().is_some_and(|min| value < min);
+        let above = self.max.as_ref().is_some_and(|max| value > max);
+
+        if !below && !above {
+            return None;
+        }
+
+        Some(match (&self.min, &self.max) {
+            (Some(min), Some(max)) => format!(
+                "Please enter a number between {} and {}",
+                self.display(min),
+                self.display(max)
+            ),
+            (Some(min), None) => format!(
+                "Please enter a number greater than or equal to {}",
+                self.display(min)
+            ),
+            (None, Some(max)) => format!(
+                "Please enter a number less than or equal to {}",
+                self.display(max)
+            ),
+            (None, None) => unreachable!(),
+        })
+    }
+
+    /// Increment or decrement the value by the step, keeping it within the bounds.
+    ///
+    /// An empty or invalid input starts from the default value, or from `min`.
+    fn step_value(&mut self, increment: bool) {
+        let value = match self.get_value() {
+            Ok(value) => match increment {
+                true => value.add_step(&self.step),
+                false => value.sub_step(&self.step),
+            },
+            Err(_) => self.min.clone().unwrap_or_default(),
+        };
+
+        let value = match (&self.min, &self.max) {
+            (Some(min), _) if value < *min => min,
+            (_, Some(max)) if value > *max => max,
+            _ => &value,
+        };
+
+        self.input.set_value(&self.display(value));
+    }
+
+    /// Display a value with the symbols of the locale, grouping the digits if they are accepted.
+    fn display(&self, value: &T) -> String {
+        let grouped = self.mode != NumberMode::Plain;
+        self.locale.format(&value.to_string(), grouped)
+    }
+
+    /// Validate the initial value, if any, so an invalid one is shown on the first draw.
+    fn validate_initial(&mut self) {
+        if !self.input.value.is_empty() {
+            self.validate_to_submit();
+        }
+    }
+
+    /// Validate again after an edit, only if the last submit was invalid.
+    fn validate_edited(&mut self) {
+        if !self.validate_on_edit || self.validator_result.is_ok() {
+            return;
+        }
+
+        self.validate_to_submit();
+        self.validator_edited = self.validator_result.is_err();
+    }
+}
+
+/// Whether the group symbols of the locale split the integer part in groups of 3 digits,
+/// like `1,000,000`. `_` can also be used between the groups, or anywhere without them.
+fn is_grouped(value: &str, locale: NumberLocale) -> bool {
+    let (_, radix, digits) = split_radix(value);
+    let (integer, fraction) = digits.split_once(locale.decimal).unwrap_or((digits, ""));
+
+    if radix != 10 || !digits.contains(locale.group) {
+        return true;
+    }
+
+    if fraction.contains(locale.group) {
+        return false;
+    }
+
+    let mut groups = integer.split([locale.group, '_']);
+    let first = groups.next().unwrap_or_default();
+
+    (1..=3).contains(&first.len()) && groups.all(|group| group.len() == 3)
+}
+
+/// Split a number into its sign, radix, and digits, by its `0x`, `0b`, or `0o` prefix.
+fn split_radix(value: &str) -> (&str, u32, &str) {
+    let (sign, unsigned) = value.split_at(usize::from(value.starts_with(['-', '+'])));
+
+    let radix = match unsigned.get(..2) {
+        Some("0x" | "0X") => 16,
+        Some("0o" | "0O") => 8,
+        Some("0b" | "0B") => 2,
+        _ => return (sign, 10, unsigned),
+    };
+
+    (sign, radix, &unsigned[2..])
+}
+
+impl<'a, T: NumLike + 'a> Recordable for Number<'a, T> {
+    type Output = T;
+
+    fn record_key(&self) -> Option<&str> {
+        self.key
+    }
+
+    fn ask(&mut self) -> Result<T, Error> {
+        self.prompt()
+    }
+
+    fn submitted_answer(&self) -> Answer {
+        match self.sensitive {
+            true => Answer::Secret,
+            false => self.get_value().map(|value| value.to_answer()).into(),
+        }
+    }
 }
 
 impl<T: NumLike> Typeable for Number<'_, T> {
     fn handle_key(&mut self, key: KeyEvent) -> bool {
         let mut submit = false;
+        let edited = matches!(
+            key.code,
+            KeyCode::Char(_) | KeyCode::Backspace | KeyCode::Delete | KeyCode::Up | KeyCode::Down
+        );
 
         match key.code {
+            // editing shortcuts
+            _ if self.input.handle_shortcut(key) => (),
             // submit
             KeyCode::Enter => submit = self.validate_to_submit(),
             // type
@@ -167,11 +689,59 @@ impl<T: NumLike> Typeable for Number<'_, T> {
             // move cursor
             KeyCode::Left => self.input.move_cursor(Direction::Left),
             KeyCode::Right => self.input.move_cursor(Direction::Right),
+            // step value
+            KeyCode::Up => self.step_value(true),
+            KeyCode::Down => self.step_value(false),
             _ => (),
         }
 
+        if edited {
+            self.validate_edited();
+        }
+
         submit
     }
+
+    fn answer_key(&self) -> Option<&str> {
+        self.key.or(self.name)
+    }
+
+    fn is_sensitive(&self) -> bool {
+        self.sensitive
+    }
+
+    fn seed(&mut self, value: &str) -> bool {
+        self.input.set_value(value);
+        self.validate_to_submit()
+    }
+
+    fn prepare(&mut self) {
+        self.validate_initial();
+    }
+}
+
+impl<T: NumLike> Composable for Number<'_, T> {
+    type Output = Result<T, Error>;
+
+    fn frame(&self, draw_time: DrawTime) -> (String, [usize; 2]) {
+        (self.formatter)(self, draw_time)
+    }
+
+    fn output(&mut self) -> Self::Output {
+        self.answer()
+    }
+}
+
+impl<T: NumLike> Number<'_, T> {
+    /// Returns a snapshot of the prompt state, see [`set_state_observer`](crate::set_state_observer).
+    pub fn state(&self) -> PromptState {
+        let value = match self.sensitive {
+            true => "*".repeat(self.input.len()),
+            false => self.input.value.clone(),
+        };
+
+        PromptState::new(self.message, self.name, value).with_error(&self.validator_result)
+    }
 }
 
 impl<T: NumLike> Printable for Number<'_, T> {
@@ -180,6 +750,10 @@ impl<T: NumLike> Printable for Number<'_, T> {
         renderer.print(text)?;
         renderer.set_cursor(cursor)
     }
+
+    fn snapshot(&self) -> Option<PromptState> {
+        Some(self.state())
+    }
 }
 
 impl<'a, T: NumLike + 'a> Default for Number<'a, T> {
@@ -191,6 +765,7 @@ impl<'a, T: NumLike + 'a> Default for Number<'a, T> {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::Answers;
 
     #[test]
     fn set_placeholder() {
@@ -201,6 +776,85 @@ mod tests {
         assert_eq!(text.placeholder, Some("foo"));
     }
 
+    #[test]
+    fn report_parsed_answer() {
+        let mut prompt = Number::<u8>::new("Retries");
+
+        prompt
+            .initial(3)
+            .report_with(|retries| format!("{} retries", retries + 1));
+
+        let (text, _) = (prompt.formatter)(&prompt, DrawTime::Last);
+        assert!(text.contains("4 retries"));
+
+        prompt.input.set_value("300");
+
+        let (text, _) = (prompt.formatter)(&prompt, DrawTime::Last);
+        assert!(text.contains("300") && !text.contains("retries"));
+    }
+
+    #[test]
+    fn record_large_integers() {
+        let mut answers = Answers::new();
+        let mut prompt = Number::<u64>::new("");
+
+        prompt.key("id").initial(u64::MAX);
+        answers.record(&prompt);
+        assert_eq!(answers.get("id"), Some(&Answer::Unsigned(u64::MAX)));
+
+        let mut prompt = Number::<u128>::new("");
+
+        prompt.key("id").initial(u128::MAX);
+        answers.record(&prompt);
+        assert_eq!(
+            answers.get("id"),
+            Some(&Answer::Text(u128::MAX.to_string()))
+        );
+    }
+
+    #[test]
+    fn mask_sensitive_answer() {
+        let mut answers = Answers::new();
+        let mut prompt = Number::<u32>::new("PIN");
+
+        prompt.key("pin").initial(1234).sensitive();
+
+        let (text, _) = (prompt.formatter)(&prompt, DrawTime::Update);
+        assert!(text.contains("1234"));
+
+        let (text, _) = (prompt.formatter)(&prompt, DrawTime::Last);
+        assert!(!text.contains("1234"));
+        assert_eq!(prompt.state().value, "****");
+        assert!(prompt.is_sensitive());
+
+        answers.record(&prompt);
+        assert_eq!(answers.get("pin"), Some(&Answer::Secret));
+    }
+
+    #[test]
+    fn reject_invalid_numbers() {
+        let mut prompt = Number::<u8>::new("");
+
+        prompt.invalid_message("Not a number");
+
+        assert!(!prompt.handle_key(KeyEvent::from(KeyCode::Enter)));
+        assert_eq!(prompt.validator_result, Err("Not a number".into()));
+
+        prompt.handle_key(KeyEvent::from(KeyCode::Char('7')));
+        assert!(prompt.handle_key(KeyEvent::from(KeyCode::Enter)));
+        assert_eq!(prompt.get_value(), Ok(7));
+    }
+
+    #[test]
+    fn set_adornments() {
+        let mut prompt = Number::<i32>::new("");
+
+        prompt.prefix("$ ").suffix(" MB");
+
+        assert_eq!(prompt.prefix, Some("$ "));
+        assert_eq!(prompt.suffix, Some(" MB"));
+    }
+
     #[test]
     fn set_default_value() {
         let mut text = Number::<i32>::new("");
@@ -227,6 +881,15 @@ mod tests {
         );
     }
 
+    #[test]
+    fn set_name() {
+        let mut prompt = Number::<i32>::new("");
+
+        assert_eq!(prompt.name, None);
+        prompt.name("foo");
+        assert_eq!(prompt.name, Some("foo"));
+    }
+
     #[test]
     fn set_custom_formatter() {
         let mut prompt: Number<u8> = Number::new("");
@@ -256,6 +919,21 @@ mod tests {
         }
     }
 
+    #[test]
+    fn validate_initial_value() {
+        let mut prompt = Number::<i32>::new("");
+        let err_str = "Must be positive";
+
+        prompt.initial(-1);
+        prompt.validate(move |_, n| match n {
+            Ok(n) if n < 0 => Err(err_str),
+            _ => Ok(()),
+        });
+        prompt.validate_initial();
+
+        assert_eq!(prompt.validator_result, Err(err_str.into()));
+    }
+
     #[test]
     fn submit_input_value() {
         let mut prompt = Number::<i32>::new("");
@@ -265,6 +943,108 @@ mod tests {
         assert_eq!(prompt.get_value(), Ok(10));
     }
 
+    #[test]
+    fn step_within_bounds() {
+        let mut prompt = Number::<i32>::new("");
+        prompt.min(0).max(10).step(4);
+
+        let mut press = |key| {
+            prompt.handle_key(KeyEvent::from(key));
+            prompt.input.value.clone()
+        };
+
+        assert_eq!(press(KeyCode::Up), "0");
+        assert_eq!(press(KeyCode::Up), "4");
+        assert_eq!(press(KeyCode::Up), "8");
+        assert_eq!(press(KeyCode::Up), "10");
+        assert_eq!(press(KeyCode::Down), "6");
+    }
+
+    #[test]
+    fn reject_out_of_range() {
+        let mut prompt = Number::<u8>::new("");
+        prompt.min(1).max(5).initial(9);
+
+        assert!(!prompt.handle_key(KeyEvent::from(KeyCode::Enter)));
+        assert_eq!(
+            prompt.validator_result,
+            Err("Please enter a number between 1 and 5".into())
+        );
+
+        prompt.input.set_value("5");
+        assert!(prompt.handle_key(KeyEvent::from(KeyCode::Enter)));
+    }
+
+    #[test]
+    fn localize_numbers() {
+        let mut prompt = Number::<f64>::new("");
+        prompt.initial(1234.5).default(2.0).min(1.5).locale("de-DE");
+
+        assert_eq!(prompt.input.value, "1234,5");
+        assert_eq!(prompt.get_value(), Ok(1234.5));
+
+        prompt.mode(NumberMode::Separators).input.set_value("");
+
+        for key in "1.000.5,2,5".chars() {
+            prompt.handle_key(KeyEvent::from(KeyCode::Char(key)));
+        }
+
+        assert_eq!(prompt.input.value, "1.000.5,25");
+        assert!(prompt.get_value().is_err());
+
+        prompt.input.set_value("-12.345.678,25");
+        assert_eq!(prompt.get_value(), Ok(-12345678.25));
+
+        for invalid in ["1.5", "1234.567", ".123", "1.000.", "1.000,5.000"] {
+            prompt.input.set_value(invalid);
+            assert!(prompt.get_value().is_err(), "{invalid}");
+        }
+
+        prompt.locale("fr_FR.UTF-8").initial(-12345.75);
+        assert_eq!(prompt.input.value, "-12 345,75");
+        assert_eq!(prompt.get_value(), Ok(-12345.75));
+        assert_eq!(
+            prompt.range_message(&0.0).unwrap(),
+            "Please enter a number greater than or equal to 1,5"
+        );
+
+        assert_eq!(NumberLocale::from_tag("de-CH").unwrap().group, '\'');
+        assert_eq!(NumberLocale::from_tag("xx"), None);
+    }
+
+    #[test]
+    fn parse_modes() {
+        let mut prompt = Number::<i32>::new("");
+
+        for key in "-0x1f".chars() {
+            prompt.handle_key(KeyEvent::from(KeyCode::Char(key)));
+        }
+
+        assert_eq!(prompt.input.value, "-01");
+
+        prompt.mode(NumberMode::Separators);
+        prompt.input.set_value("1,000_000");
+        assert_eq!(prompt.get_value(), Ok(1_000_000));
+
+        prompt.mode(NumberMode::Radix);
+        prompt.input.set_value("");
+
+        for key in "-0x1f_ff".chars() {
+            prompt.handle_key(KeyEvent::from(KeyCode::Char(key)));
+        }
+
+        assert_eq!(prompt.input.value, "-0x1f_ff");
+        assert_eq!(prompt.get_value(), Ok(-0x1fff));
+
+        for (input, expected) in [("0b101", Ok(5)), ("0o17", Ok(15)), ("42", Ok(42))] {
+            prompt.input.set_value(input);
+            assert_eq!(prompt.get_value().map_err(|_| ()), expected);
+        }
+
+        prompt.input.set_value("0b102");
+        assert!(prompt.get_value().is_err());
+    }
+
     #[test]
     fn submit_default_value() {
         let mut prompt = Number::<i32>::new("");
@@ -0,0 +1,231 @@
+use std::io;
+
+use crossterm::event::{KeyCode, KeyEvent};
+
+use crate::utils::{
+    error::Error,
+    key_listener::{self, Typeable},
+    layout::LayoutContext,
+    renderer::{DrawTime, Printable, Renderer},
+    state::PromptState,
+};
+
+/// Prompts that can be displayed together in a [`Pair`].
+///
+/// Implemented by the one-line input prompts, like [`Text`], [`Password`] and [`Number`].
+///
+/// [`Text`]: crate::Text
+/// [`Password`]: crate::Password
+/// [`Number`]: crate::Number
+pub trait Composable: Typeable + Printable {
+    /// Value returned when the prompts are submitted.
+    type Output;
+
+    /// Returns the text to display and the cursor position, relative to the text.
+    fn frame(&self, draw_time: DrawTime) -> (String, [usize; 2]);
+
+    /// Returns the value of the prompt after submitting.
+    fn output(&mut self) -> Self::Output;
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Focus {
+    First,
+    Second,
+}
+
+/// Display two prompts on the same screen and submit them together, like a username and a password.
+///
+/// # Key Events
+///
+/// | Key              | Action                             |
+/// | ---------------- | ---------------------------------- |
+/// | `Enter`          | Submit both prompts, if both valid |
+/// | `Tab`, `BackTab` | Switch the focused prompt          |
+/// | Other keys       | Handled by the focused prompt      |
+///
+/// When one of the prompts is invalid on submit, it gets the focus.
+///
+/// # Examples
+///
+/// ```no_run
+/// use asky::{Pair, Password, Text};
+///
+/// # fn main() -> std::io::Result<()> {
+/// let (username, password) = Pair::new(
+///     Text::new("Username").placeholder("admin"),
+///     Password::new("Password").hidden(true),
+/// )
+/// .prompt()?;
+/// # Ok(())
+/// # }
+/// ```
+pub struct Pair<'p, A, B> {
+    first: &'p mut A,
+    second: &'p mut B,
+    focus: Focus,
+}
+
+impl<'p, A: Composable, B: Composable> Pair<'p, A, B> {
+    /// Create a pair of prompts, the first one is focused at start.
+    pub fn new(first: &'p mut A, second: &'p mut B) -> Self {
+        Pair {
+            first,
+            second,
+            focus: Focus::First,
+        }
+    }
+
+    /// Display both prompts and return their answers.
+    pub fn prompt(&mut self) -> Result<(A::Output, B::Output), Error> {
+        key_listener::listen(self, false)?;
+
+        Ok((self.first.output(), self.second.output()))
+    }
+
+    /// Async version of [`Pair::prompt`].
+    #[cfg(feature = "async")]
+    pub async fn prompt_async(&mut self) -> Result<(A::Output, B::Output), Error> {
+        key_listener::listen_async(self, false).await?;
+
+        Ok((self.first.output(), self.second.output()))
+    }
+
+    /// Returns whether the first prompt is focused.
+    pub fn is_first_focused(&self) -> bool {
+        self.focus == Focus::First
+    }
+}
+
+impl<A: Composable, B: Composable> Typeable for Pair<'_, A, B> {
+    fn handle_key(&mut self, key: KeyEvent) -> bool {
+        let mut submit = false;
+
+        match key.code {
+            // submit both, or focus the invalid one
+            KeyCode::Enter => match (self.first.handle_key(key), self.second.handle_key(key)) {
+                (true, true) => submit = true,
+                (false, _) => self.focus = Focus::First,
+                (true, false) => self.focus = Focus::Second,
+            },
+            // switch focus
+            KeyCode::Tab | KeyCode::BackTab => {
+                self.focus = match self.focus {
+                    Focus::First => Focus::Second,
+                    Focus::Second => Focus::First,
+                }
+            }
+            // update focused
+            _ => {
+                match self.focus {
+                    Focus::First => self.first.handle_key(key),
+                    Focus::Second => self.second.handle_key(key),
+                };
+            }
+        }
+
+        submit
+    }
+
+    fn is_sensitive(&self) -> bool {
+        match self.focus {
+            Focus::First => self.first.is_sensitive(),
+            Focus::Second => self.second.is_sensitive(),
+        }
+    }
+
+    fn prepare(&mut self) {
+        self.first.prepare();
+        self.second.prepare();
+    }
+}
+
+impl<A: Composable, B: Composable> Printable for Pair<'_, A, B> {
+    fn draw(&self, renderer: &mut Renderer) -> io::Result<()> {
+        let (first, first_cursor) = self.first.frame(renderer.draw_time);
+        let (second, [x, y]) = self.second.frame(renderer.draw_time);
+        let first = first.trim_end_matches('\n');
+
+        let cursor = match self.focus {
+            Focus::First => first_cursor,
+            Focus::Second => [x, y + LayoutContext::current().rows(first)],
+        };
+
+        renderer.print(format!("{}\n{}", first, second))?;
+        renderer.set_cursor(cursor)
+    }
+
+    fn snapshot(&self) -> Option<PromptState> {
+        match self.focus {
+            Focus::First => self.first.snapshot(),
+            Focus::Second => self.second.snapshot(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{Password, Text};
+
+    #[test]
+    fn switch_focus() {
+        let mut first = Text::new("");
+        let mut second = Text::new("");
+        let mut prompt = Pair::new(&mut first, &mut second);
+
+        prompt.handle_key(KeyEvent::from(KeyCode::Char('a')));
+        prompt.handle_key(KeyEvent::from(KeyCode::Tab));
+        assert!(!prompt.is_first_focused());
+        prompt.handle_key(KeyEvent::from(KeyCode::Char('b')));
+
+        assert_eq!(prompt.first.input.value, "a");
+        assert_eq!(prompt.second.input.value, "b");
+    }
+
+    #[test]
+    fn sensitive_when_focused() {
+        let mut first = Text::new("");
+        let mut second = Password::new("");
+        let mut prompt = Pair::new(&mut first, &mut second);
+
+        assert!(!prompt.is_sensitive());
+        prompt.handle_key(KeyEvent::from(KeyCode::Tab));
+        assert!(prompt.is_sensitive());
+    }
+
+    #[test]
+    fn snapshot_focused() {
+        let mut first = Text::new("Username");
+        let mut second = Password::new("Password");
+        let mut prompt = Pair::new(&mut first, &mut second);
+
+        assert_eq!(prompt.snapshot().unwrap().message, "Username");
+        prompt.handle_key(KeyEvent::from(KeyCode::Tab));
+        assert_eq!(prompt.snapshot().unwrap().message, "Password");
+    }
+
+    #[test]
+    fn submit_when_both_valid() {
+        let mut first = Text::new("");
+        let mut second = Text::new("");
+
+        first.initial("foo");
+        second.validate(|value| match value.is_empty() {
+            true => Err("required"),
+            false => Ok(()),
+        });
+
+        let mut prompt = Pair::new(&mut first, &mut second);
+
+        assert!(!prompt.handle_key(KeyEvent::from(KeyCode::Enter)));
+        assert!(!prompt.is_first_focused());
+
+        prompt.handle_key(KeyEvent::from(KeyCode::Char('x')));
+        assert!(prompt.handle_key(KeyEvent::from(KeyCode::Enter)));
+        assert_eq!(
+            (prompt.first.output(), prompt.second.output()),
+            (String::from("foo"), String::from("x"))
+        );
+    }
+}
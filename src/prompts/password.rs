@@ -1,21 +1,120 @@
-use std::io;
+use std::{
+    borrow::Cow,
+    env, fs,
+    io::{self, BufRead, IsTerminal},
+    mem,
+    path::PathBuf,
+};
 
-use crossterm::event::{KeyCode, KeyEvent};
+use crossterm::event::{KeyCode, KeyEvent, KeyModifiers};
 
 use crate::utils::{
+    error::Error,
     key_listener::{self, Typeable},
     renderer::{DrawTime, Printable, Renderer},
+    state::PromptState,
     theme,
 };
 
+use super::pair::Composable;
 use super::text::{Direction, InputValidator, LineInput};
 
+#[cfg(feature = "keyring")]
+use super::confirm::Confirm;
+
+#[cfg(feature = "secrecy")]
+use secrecy::SecretString;
+#[cfg(feature = "secrecy")]
+use zeroize::Zeroize;
+
 type Formatter<'a> = dyn Fn(&Password, DrawTime) -> (String, [usize; 2]) + 'a;
+type StrengthEstimator<'a> = dyn Fn(&str) -> Strength + 'a;
+
+/// How the input of a [`Password`] is displayed, toggled with `Ctrl+R`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum PasswordVisibility {
+    /// The input is not displayed at all.
+    Hidden,
+    /// Each character is displayed as `*`.
+    #[default]
+    Masked,
+    /// The input is displayed as plain text.
+    Visible,
+}
+
+impl PasswordVisibility {
+    fn next(self) -> Self {
+        match self {
+            PasswordVisibility::Hidden => PasswordVisibility::Masked,
+            PasswordVisibility::Masked => PasswordVisibility::Visible,
+            PasswordVisibility::Visible => PasswordVisibility::Hidden,
+        }
+    }
+}
+
+/// Strength of a password, displayed under the input, see [`Password::strength`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub enum Strength {
+    /// Short or predictable password.
+    Weak,
+    /// Acceptable password.
+    Medium,
+    /// Long password with varied characters.
+    Strong,
+}
+
+impl Strength {
+    /// Built-in heuristic, based on the length and the kinds of characters.
+    ///
+    /// It doesn't check dictionaries or common passwords,
+    /// use a dedicated crate for a more accurate estimation.
+    pub fn estimate(password: &str) -> Strength {
+        let len = password.chars().count();
+        let has = |f: fn(&char) -> bool| password.chars().any(|c| f(&c));
+
+        let score = [
+            len >= 8,
+            len >= 12,
+            has(char::is_ascii_lowercase) && has(char::is_ascii_uppercase),
+            has(char::is_ascii_digit),
+            has(|c| !c.is_alphanumeric()),
+        ]
+        .into_iter()
+        .filter(|&passed| passed)
+        .count();
+
+        match score {
+            0..=2 => Strength::Weak,
+            3 => Strength::Medium,
+            _ => Strength::Strong,
+        }
+    }
+}
+
+/// Error displayed when the repeated password doesn't match, see [`Password::confirm`].
+const MISMATCH_MESSAGE: &str = "Passwords don't match";
+
+/// Bytes reserved for the secrets with the `secrecy` feature, so they are rarely reallocated.
+#[cfg(feature = "secrecy")]
+const SECRET_CAPACITY: usize = 128;
 
 /// Prompt to get one-line user input as password.
 ///
 /// Similar to [`Text`] prompt, but replace input characters with `*`.
-/// Also allow to hide user input completely.
+/// Also allow to hide user input completely, or to show it, see [`PasswordVisibility`].
+///
+/// # Non-interactive sources
+///
+/// The secret can also be read without prompting, in the following order:
+///
+/// 1. Environment variable, set with [`Password::env`].
+/// 2. File, set with [`Password::file`], e.g. from a `--password-file` argument.
+/// 3. Piped standard input, enabled with [`Password::stdin`], only if it's not a terminal.
+/// 4. Interactive prompt, if none of the above is available.
+///
+/// The first line of the file or the standard input is used, without the line ending.
+/// Values from these sources are checked with the validator, returning an
+/// [`io::ErrorKind::InvalidInput`] error if they are invalid.
 ///
 /// # Key Events
 ///
@@ -26,14 +125,23 @@ type Formatter<'a> = dyn Fn(&Password, DrawTime) -> (String, [usize; 2]) + 'a;
 /// | `Delete`    | Delete current character     |
 /// | `Left`      | Move cursor left             |
 /// | `Right`     | Move cursor right            |
+/// | `Ctrl+R`    | Toggle the input visibility  |
+///
+/// Also the `Home`, `End`, word-jump and readline-like shortcuts of [`Text`](crate::Text), like `Ctrl+W`.
 ///
 /// # Examples
 ///
 /// ```no_run
-/// use asky::Password;
+/// use asky::{Password, Strength};
 ///
 /// # fn main() -> std::io::Result<()> {
 /// let password = Password::new("Your IG Password:").prompt()?;
+///
+/// // ask twice, for new passwords
+/// let new_password = Password::new("New password:")
+///     .confirm("Repeat password:")
+///     .strength(Strength::estimate)
+///     .prompt()?;
 /// # Ok(())
 /// # }
 /// ```
@@ -41,17 +149,39 @@ type Formatter<'a> = dyn Fn(&Password, DrawTime) -> (String, [usize; 2]) + 'a;
 pub struct Password<'a> {
     /// Message used to display in the prompt.
     pub message: &'a str,
+    /// Stable identifier of the prompt.
+    pub name: Option<&'a str>,
     /// Input state for the prompt.
     pub input: LineInput,
     /// Placeholder to show when the input is empty.
     pub placeholder: Option<&'a str>,
     /// Default value to submit when the input is empty.
     pub default_value: Option<&'a str>,
-    /// Must hide user input or show `*` characters
-    pub hidden: bool,
+    /// How the user input is displayed.
+    pub visibility: PasswordVisibility,
+    /// Environment variable to read the secret from, before prompting.
+    pub env_var: Option<&'a str>,
+    /// File to read the secret from, before prompting.
+    pub file: Option<PathBuf>,
+    /// Whether to read the secret from the standard input when it's piped, before prompting.
+    pub stdin: bool,
+    /// Service and user to remember the secret in the OS keychain.
+    #[cfg(feature = "keyring")]
+    pub remember: Option<(&'a str, &'a str)>,
     /// State of the validation of the user input.
-    pub validator_result: Result<(), &'a str>,
+    pub validator_result: Result<(), Cow<'a, str>>,
+    /// Whether the validator runs again when the input is edited after an invalid submit.
+    pub validate_on_edit: bool,
+    /// Indicate if the input was edited after an invalid submit, when using `validate_on_edit`.
+    pub validator_edited: bool,
+    /// Message to ask for the password again, see [`Password::confirm`].
+    pub confirm_message: Option<&'a str>,
+    /// Whether the user is repeating the password, when using `confirm`.
+    pub confirming: bool,
+    /// First entry of the password, when using `confirm`.
+    first_entry: String,
     validator: Option<Box<InputValidator<'a>>>,
+    strength: Option<Box<StrengthEstimator<'a>>>,
     formatter: Box<Formatter<'a>>,
 }
 
@@ -60,12 +190,27 @@ impl<'a> Password<'a> {
     pub fn new(message: &'a str) -> Self {
         Password {
             message,
-            input: LineInput::new(),
+            name: None,
+            input: LineInput {
+                value: secret_buffer(),
+                col: 0,
+            },
             placeholder: None,
             default_value: None,
-            hidden: false,
+            visibility: PasswordVisibility::Masked,
+            env_var: None,
+            file: None,
+            stdin: false,
+            #[cfg(feature = "keyring")]
+            remember: None,
             validator: None,
             validator_result: Ok(()),
+            validate_on_edit: false,
+            validator_edited: false,
+            confirm_message: None,
+            confirming: false,
+            first_entry: secret_buffer(),
+            strength: None,
             formatter: Box::new(theme::fmt_password),
         }
     }
@@ -86,25 +231,117 @@ impl<'a> Password<'a> {
 
     /// Set initial value, could be deleted by the user.
     pub fn initial(&mut self, value: &str) -> &mut Self {
+        clear_secret(&mut self.input.value);
         self.input.set_value(value);
         self
     }
 
-    /// Set whether to hide user input or show `*` characters
+    /// Set whether to hide user input or show `*` characters.
+    ///
+    /// Shortcut of [`Password::visibility`].
     pub fn hidden(&mut self, hidden: bool) -> &mut Self {
-        self.hidden = hidden;
+        self.visibility = match hidden {
+            true => PasswordVisibility::Hidden,
+            false => PasswordVisibility::Masked,
+        };
+        self
+    }
+
+    /// Set how the user input is displayed initially, the user can toggle it with `Ctrl+R`.
+    pub fn visibility(&mut self, visibility: PasswordVisibility) -> &mut Self {
+        self.visibility = visibility;
+        self
+    }
+
+    /// Set an environment variable to read the secret from, before prompting.
+    pub fn env(&mut self, var: &'a str) -> &mut Self {
+        self.env_var = Some(var);
+        self
+    }
+
+    /// Set a file to read the secret from, before prompting.
+    pub fn file(&mut self, path: impl Into<PathBuf>) -> &mut Self {
+        self.file = Some(path.into());
+        self
+    }
+
+    /// Set whether to read the secret from the standard input when it's piped, before prompting.
+    pub fn stdin(&mut self, stdin: bool) -> &mut Self {
+        self.stdin = stdin;
+        self
+    }
+
+    /// Remember the secret in the OS keychain, under the given service and user.
+    ///
+    /// A remembered secret is returned without prompting if it passes the validator.
+    /// Otherwise, the user is prompted and asked whether to remember the new secret.
+    /// If the keychain is not available, the secret is just prompted.
+    #[cfg(feature = "keyring")]
+    pub fn remember(&mut self, service: &'a str, user: &'a str) -> &mut Self {
+        self.remember = Some((service, user));
         self
     }
 
     /// Set validator to the user input.
-    pub fn validate<F>(&mut self, validator: F) -> &mut Self
+    ///
+    /// The error message can be borrowed or owned, like `format!("got {} chars", value.len())`.
+    pub fn validate<F, E>(&mut self, validator: F) -> &mut Self
+    where
+        F: Fn(&str) -> Result<(), E> + 'a,
+        E: Into<Cow<'a, str>>,
+    {
+        self.validator = Some(Box::new(move |value| validator(value).map_err(Into::into)));
+        self
+    }
+
+    /// Set a stable identifier for the prompt, like `"db.host"`.
+    ///
+    /// It is available to custom formatters, which can use it to render per-field markers.
+    pub fn name(&mut self, name: &'a str) -> &mut Self {
+        self.name = Some(name);
+        self
+    }
+
+    /// Set whether the validator runs again when the input is edited after an invalid submit.
+    ///
+    /// The error is kept visible (dimmed) while the user fixes the input,
+    /// and it's cleared as soon as the input is valid.
+    pub fn validate_on_edit(&mut self, validate_on_edit: bool) -> &mut Self {
+        self.validate_on_edit = validate_on_edit;
+        self
+    }
+
+    /// Ask for the password twice, the second time with this message, like `"Repeat password:"`.
+    ///
+    /// The password is only submitted when both entries match.
+    /// Otherwise, an error is displayed and the password is asked again from the start.
+    pub fn confirm(&mut self, message: &'a str) -> &mut Self {
+        self.confirm_message = Some(message);
+        self
+    }
+
+    /// Set a function to estimate the strength of the input, displayed under it.
+    ///
+    /// It isn't displayed while the input is [`PasswordVisibility::Hidden`].
+    /// Use [`Strength::estimate`] for the built-in heuristic.
+    pub fn strength<F>(&mut self, estimator: F) -> &mut Self
     where
-        F: Fn(&str) -> Result<(), &'a str> + 'a,
+        F: Fn(&str) -> Strength + 'a,
     {
-        self.validator = Some(Box::new(validator));
+        self.strength = Some(Box::new(estimator));
         self
     }
 
+    /// Returns the strength of the current input, if there is an estimator and the input is not empty.
+    pub fn get_strength(&self) -> Option<Strength> {
+        let estimator = self.strength.as_ref()?;
+
+        match self.input.value.is_empty() {
+            true => None,
+            false => Some(estimator(&self.input.value)),
+        }
+    }
+
     /// Set custom closure to format the prompt.
     ///
     /// See: [`Customization`](index.html#customization).
@@ -117,9 +354,48 @@ impl<'a> Password<'a> {
     }
 
     /// Display the prompt and return the user answer.
-    pub fn prompt(&mut self) -> io::Result<String> {
+    ///
+    /// See [non-interactive sources](Password#non-interactive-sources) to skip the prompt.
+    pub fn prompt(&mut self) -> Result<String, Error> {
+        if let Some(secret) = self.read_non_interactive()? {
+            return Ok(secret);
+        }
+
+        #[cfg(feature = "keyring")]
+        if let Some(secret) = self.read_keyring() {
+            return Ok(secret);
+        }
+
         key_listener::listen(self, false)?;
-        Ok(self.get_value().to_owned())
+
+        let secret = self.get_value().to_owned();
+
+        #[cfg(feature = "keyring")]
+        self.offer_to_remember(&secret)?;
+
+        Ok(secret)
+    }
+
+    /// Async version of [`Password::prompt`].
+    #[cfg(feature = "async")]
+    pub async fn prompt_async(&mut self) -> Result<String, Error> {
+        if let Some(secret) = self.read_non_interactive()? {
+            return Ok(secret);
+        }
+
+        #[cfg(feature = "keyring")]
+        if let Some(secret) = self.read_keyring() {
+            return Ok(secret);
+        }
+
+        key_listener::listen_async(self, false).await?;
+
+        let secret = self.get_value().to_owned();
+
+        #[cfg(feature = "keyring")]
+        self.offer_to_remember_async(&secret).await?;
+
+        Ok(secret)
     }
 }
 
@@ -131,24 +407,248 @@ impl Password<'_> {
         }
     }
 
+    /// Read the secret from the environment, file or piped stdin, if any is available.
+    fn read_non_interactive(&self) -> io::Result<Option<String>> {
+        self.read_non_interactive_with(|var| env::var(var).ok())
+    }
+
+    /// Same as `read_non_interactive`, getting the environment variables with `get_var`.
+    fn read_non_interactive_with<F>(&self, get_var: F) -> io::Result<Option<String>>
+    where
+        F: Fn(&str) -> Option<String>,
+    {
+        let secret = if let Some(value) = self.env_var.and_then(get_var) {
+            value
+        } else if let Some(path) = &self.file {
+            first_line(fs::read_to_string(path)?.as_bytes())?
+        } else if self.stdin && !io::stdin().is_terminal() {
+            first_line(io::stdin().lock())?
+        } else {
+            return Ok(None);
+        };
+
+        if let Some(validator) = &self.validator {
+            validator(&secret)
+                .map_err(|e| io::Error::new(io::ErrorKind::InvalidInput, e.into_owned()))?;
+        }
+
+        Ok(Some(secret))
+    }
+
+    /// Validate the input, and ask it again when using `confirm`.
+    fn submit(&mut self) -> bool {
+        if self.confirm_message.is_none() {
+            return self.validate_to_submit();
+        }
+
+        if !self.confirming {
+            // clear a previous mismatch, the validator is optional
+            self.validator_result = Ok(());
+
+            if self.validate_to_submit() {
+                clear_secret(&mut self.first_entry);
+
+                // move the typed buffer instead of copying it
+                match self.input.value.is_empty() {
+                    true => self
+                        .first_entry
+                        .push_str(self.default_value.unwrap_or_default()),
+                    false => mem::swap(&mut self.first_entry, &mut self.input.value),
+                }
+
+                self.clear_input();
+                self.confirming = true;
+            }
+
+            return false;
+        }
+
+        if self.get_value() == self.first_entry {
+            return true;
+        }
+
+        clear_secret(&mut self.first_entry);
+        self.clear_input();
+        self.confirming = false;
+        self.validator_result = Err(Cow::Borrowed(MISMATCH_MESSAGE));
+
+        false
+    }
+
+    fn clear_input(&mut self) {
+        clear_secret(&mut self.input.value);
+        self.input.col = 0;
+    }
+
+    /// Grow the input before it's full, so the old buffer is zeroized instead of reallocated.
+    #[cfg(feature = "secrecy")]
+    fn reserve_input(&mut self) {
+        let value = &mut self.input.value;
+
+        if value.capacity() - value.len() < char::MAX.len_utf8() {
+            let mut grown = String::with_capacity(value.capacity() * 2);
+
+            grown.push_str(value);
+            value.zeroize();
+            *value = grown;
+        }
+    }
+
     fn validate_to_submit(&mut self) -> bool {
+        self.validator_edited = false;
+
         if let Some(validator) = &self.validator {
             self.validator_result = validator(self.get_value());
         }
 
         self.validator_result.is_ok()
     }
+
+    /// Validate the initial value, if any, so an invalid one is shown on the first draw.
+    fn validate_initial(&mut self) {
+        if !self.input.value.is_empty() {
+            self.validate_to_submit();
+        }
+    }
+
+    /// Validate again after an edit, only if the last submit was invalid.
+    fn validate_edited(&mut self) {
+        if !self.validate_on_edit || self.validator_result.is_ok() {
+            return;
+        }
+
+        self.validate_to_submit();
+        self.validator_edited = self.validator_result.is_err();
+    }
+}
+
+#[cfg(feature = "keyring")]
+const REMEMBER_MESSAGE: &str = "Remember it in the keychain?";
+
+#[cfg(feature = "keyring")]
+impl Password<'_> {
+    fn keyring_entry(&self) -> Option<keyring::Entry> {
+        let (service, user) = self.remember?;
+        keyring::Entry::new(service, user).ok()
+    }
+
+    /// Returns the remembered secret, if it's valid.
+    fn read_keyring(&self) -> Option<String> {
+        let secret = self.keyring_entry()?.get_password().ok()?;
+        let is_valid = match &self.validator {
+            Some(validator) => validator(&secret).is_ok(),
+            None => true,
+        };
+
+        is_valid.then_some(secret)
+    }
+
+    /// Ask whether to remember the secret in the keychain.
+    fn offer_to_remember(&self, secret: &str) -> Result<(), Error> {
+        let entry = match self.keyring_entry() {
+            Some(entry) if !secret.is_empty() => entry,
+            _ => return Ok(()),
+        };
+
+        if Confirm::new(REMEMBER_MESSAGE).prompt()? {
+            // the secret was already typed, so it's returned even if the keychain fails
+            let _ = entry.set_password(secret);
+        }
+
+        Ok(())
+    }
+
+    #[cfg(feature = "async")]
+    async fn offer_to_remember_async(&self, secret: &str) -> Result<(), Error> {
+        let entry = match self.keyring_entry() {
+            Some(entry) if !secret.is_empty() => entry,
+            _ => return Ok(()),
+        };
+
+        if Confirm::new(REMEMBER_MESSAGE).prompt_async().await? {
+            let _ = entry.set_password(secret);
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(feature = "secrecy")]
+impl Password<'_> {
+    /// Same as [`Password::prompt`], but returns the secret as a [`SecretString`].
+    ///
+    /// The input is zeroized after the prompt, even if it's cancelled. The prompt is not
+    /// zeroized when dropped, as implementing `Drop` would change how it can borrow its
+    /// arguments, so call this method instead of [`Password::prompt`] to not leave copies.
+    pub fn prompt_secret(&mut self) -> Result<SecretString, Error> {
+        let result = self.prompt();
+        self.zeroize_input();
+        result.map(SecretString::from)
+    }
+
+    /// Async version of [`Password::prompt_secret`].
+    #[cfg(feature = "async")]
+    pub async fn prompt_secret_async(&mut self) -> Result<SecretString, Error> {
+        let result = self.prompt_async().await;
+        self.zeroize_input();
+        result.map(SecretString::from)
+    }
+
+    fn zeroize_input(&mut self) {
+        self.clear_input();
+        self.first_entry.zeroize();
+    }
+}
+
+/// Returns an empty buffer for a secret, with room for the usual ones with the `secrecy` feature.
+fn secret_buffer() -> String {
+    #[cfg(feature = "secrecy")]
+    return String::with_capacity(SECRET_CAPACITY);
+
+    #[cfg(not(feature = "secrecy"))]
+    String::new()
+}
+
+/// Clear a buffer with a secret, overwriting it first with the `secrecy` feature.
+fn clear_secret(buffer: &mut String) {
+    #[cfg(feature = "secrecy")]
+    buffer.zeroize();
+
+    #[cfg(not(feature = "secrecy"))]
+    buffer.clear();
+}
+
+fn first_line(mut reader: impl BufRead) -> io::Result<String> {
+    let mut line = String::new();
+
+    reader.read_line(&mut line)?;
+
+    Ok(line.trim_end_matches(['\n', '\r']).to_string())
 }
 
 impl Typeable for Password<'_> {
     fn handle_key(&mut self, key: KeyEvent) -> bool {
         let mut submit = false;
+        let edited = matches!(
+            key.code,
+            KeyCode::Char(_) | KeyCode::Backspace | KeyCode::Delete
+        );
 
         match key.code {
+            // toggle visibility
+            KeyCode::Char('r') if key.modifiers == KeyModifiers::CONTROL => {
+                self.visibility = self.visibility.next()
+            }
+            // editing shortcuts
+            _ if self.input.handle_shortcut(key) => (),
             // submit
-            KeyCode::Enter => submit = self.validate_to_submit(),
+            KeyCode::Enter => submit = self.submit(),
             // type
-            KeyCode::Char(c) => self.input.insert(c),
+            KeyCode::Char(c) => {
+                #[cfg(feature = "secrecy")]
+                self.reserve_input();
+                self.input.insert(c)
+            }
             // remove delete
             KeyCode::Backspace => self.input.backspace(),
             KeyCode::Delete => self.input.delete(),
@@ -158,8 +658,44 @@ impl Typeable for Password<'_> {
             _ => (),
         };
 
+        if edited {
+            self.validate_edited();
+        }
+
         submit
     }
+
+    fn is_sensitive(&self) -> bool {
+        true
+    }
+
+    fn prepare(&mut self) {
+        self.validate_initial();
+    }
+}
+
+impl Composable for Password<'_> {
+    type Output = String;
+
+    fn frame(&self, draw_time: DrawTime) -> (String, [usize; 2]) {
+        (self.formatter)(self, draw_time)
+    }
+
+    fn output(&mut self) -> String {
+        self.get_value().to_owned()
+    }
+}
+
+impl Password<'_> {
+    /// Returns a snapshot of the prompt state, see [`set_state_observer`](crate::set_state_observer).
+    pub fn state(&self) -> PromptState {
+        let value = match self.visibility {
+            PasswordVisibility::Hidden => String::new(),
+            _ => "*".repeat(self.input.len()),
+        };
+
+        PromptState::new(self.message, self.name, value).with_error(&self.validator_result)
+    }
 }
 
 impl Printable for Password<'_> {
@@ -168,6 +704,10 @@ impl Printable for Password<'_> {
         renderer.print(text)?;
         renderer.set_cursor(cursor)
     }
+
+    fn snapshot(&self) -> Option<PromptState> {
+        Some(self.state())
+    }
 }
 
 #[cfg(test)]
@@ -209,6 +749,49 @@ mod tests {
         );
     }
 
+    #[test]
+    fn set_name() {
+        let mut prompt = Password::new("");
+
+        assert_eq!(prompt.name, None);
+        prompt.name("foo");
+        assert_eq!(prompt.name, Some("foo"));
+    }
+
+    #[test]
+    fn read_non_interactive_sources() {
+        let path = env::temp_dir().join(format!("asky_password_{}", std::process::id()));
+        let get_var = |var: &str| (var == "SECRET").then(|| String::from("from-env"));
+        let mut prompt = Password::new("");
+
+        assert_eq!(prompt.read_non_interactive_with(get_var).unwrap(), None);
+
+        fs::write(&path, "from-file\r\nfoo").unwrap();
+        prompt.file(&path);
+        assert_eq!(
+            prompt.read_non_interactive_with(get_var).unwrap(),
+            Some(String::from("from-file"))
+        );
+
+        prompt.env("UNSET");
+        assert_eq!(
+            prompt.read_non_interactive_with(get_var).unwrap(),
+            Some(String::from("from-file"))
+        );
+
+        prompt.env("SECRET");
+        assert_eq!(
+            prompt.read_non_interactive_with(get_var).unwrap(),
+            Some(String::from("from-env"))
+        );
+
+        prompt.validate(|_| Err("invalid"));
+        let err = prompt.read_non_interactive_with(get_var).unwrap_err();
+        assert_eq!(err.kind(), io::ErrorKind::InvalidInput);
+
+        fs::remove_file(path).unwrap();
+    }
+
     #[test]
     fn set_custom_formatter() {
         let mut prompt: Password = Password::new("");
@@ -227,9 +810,16 @@ mod tests {
     fn set_hidden_value() {
         let mut prompt = Password::new("");
 
-        assert!(!prompt.hidden);
+        assert_eq!(prompt.visibility, PasswordVisibility::Masked);
         prompt.hidden(true);
-        assert!(prompt.hidden)
+        assert_eq!(prompt.visibility, PasswordVisibility::Hidden);
+
+        for expected in [PasswordVisibility::Masked, PasswordVisibility::Visible] {
+            prompt.handle_key(KeyEvent::new(KeyCode::Char('r'), KeyModifiers::CONTROL));
+            assert_eq!(prompt.visibility, expected);
+        }
+
+        assert_eq!(prompt.input.value, "");
     }
 
     #[test]
@@ -247,6 +837,110 @@ mod tests {
         }
     }
 
+    #[cfg(feature = "secrecy")]
+    #[test]
+    fn zeroize_secret_input() {
+        let mut prompt = Password::new("");
+
+        prompt.confirm("Repeat");
+
+        for c in "hunter2".chars() {
+            prompt.handle_key(KeyEvent::from(KeyCode::Char(c)));
+        }
+
+        let typed = prompt.input.value.as_ptr();
+
+        // the typed buffer is moved, and a new one is used for the confirmation
+        prompt.handle_key(KeyEvent::from(KeyCode::Enter));
+        assert_eq!(prompt.first_entry.as_ptr(), typed);
+        assert_eq!(prompt.input.value, "");
+
+        prompt.handle_key(KeyEvent::from(KeyCode::Char('x')));
+        prompt.handle_key(KeyEvent::from(KeyCode::Enter));
+
+        // the buffers are zeroized in place on a mismatch, not reallocated
+        assert_eq!(prompt.validator_result, Err(MISMATCH_MESSAGE.into()));
+        assert_eq!(prompt.first_entry, "");
+        assert_eq!(prompt.first_entry.as_ptr(), typed);
+        assert!(prompt.first_entry.capacity() >= SECRET_CAPACITY);
+        assert_eq!(prompt.input.value, "");
+
+        // the input grows without leaving copies
+        for _ in 0..SECRET_CAPACITY {
+            prompt.handle_key(KeyEvent::from(KeyCode::Char('a')));
+        }
+
+        assert_eq!(prompt.input.len(), SECRET_CAPACITY);
+        prompt.zeroize_input();
+        assert_eq!(prompt.input.value, "");
+    }
+
+    #[test]
+    fn estimate_strength() {
+        assert_eq!(Strength::estimate("hunter2"), Strength::Weak);
+        assert_eq!(Strength::estimate("Hunter2024"), Strength::Medium);
+        assert_eq!(
+            Strength::estimate("correct-Horse-battery-9"),
+            Strength::Strong
+        );
+
+        let mut prompt = Password::new("");
+        assert_eq!(prompt.get_strength(), None);
+
+        prompt.strength(|_| Strength::Medium);
+        assert_eq!(prompt.get_strength(), None);
+        prompt.input.set_value("foo");
+        assert_eq!(prompt.get_strength(), Some(Strength::Medium));
+
+        let (text, _) = (prompt.formatter)(&prompt, DrawTime::Update);
+        assert!(text.contains("Medium"));
+
+        prompt.visibility(PasswordVisibility::Hidden);
+
+        let (text, _) = (prompt.formatter)(&prompt, DrawTime::Update);
+        assert!(!text.contains("Medium") && !text.contains('■'));
+    }
+
+    #[test]
+    fn confirm_password() {
+        let mut prompt = Password::new("");
+        let submit = |prompt: &mut Password, value| {
+            prompt.input.set_value(value);
+            prompt.handle_key(KeyEvent::from(KeyCode::Enter))
+        };
+
+        prompt.confirm("Repeat");
+
+        assert!(!submit(&mut prompt, "foo"));
+        assert!(prompt.confirming);
+        assert_eq!(prompt.input.value, "");
+
+        assert!(!submit(&mut prompt, "bar"));
+        assert!(!prompt.confirming);
+        assert_eq!(prompt.validator_result, Err(MISMATCH_MESSAGE.into()));
+
+        assert!(!submit(&mut prompt, "foo"));
+        assert!(submit(&mut prompt, "foo"));
+        assert_eq!(prompt.get_value(), "foo");
+    }
+
+    #[test]
+    fn validate_initial_value() {
+        let mut prompt = Password::new("");
+        let err_str = "Too short";
+
+        prompt.validate(move |s| if s.len() < 8 { Err(err_str) } else { Ok(()) });
+
+        // an empty input is not validated before the first draw
+        prompt.validate_initial();
+        assert_eq!(prompt.validator_result, Ok(()));
+
+        prompt.initial("1234");
+        prompt.validate_initial();
+
+        assert_eq!(prompt.validator_result, Err(err_str.into()));
+    }
+
     #[test]
     fn submit_input_value() {
         let mut prompt = Password::new("");
@@ -0,0 +1,263 @@
+use std::io;
+
+use crossterm::event::{KeyCode, KeyEvent};
+
+use crate::utils::{
+    answer::{Answer, Recordable},
+    error::Error,
+    key_listener::{self, Typeable},
+    renderer::{DrawTime, Printable, Renderer},
+    state::PromptState,
+    theme,
+};
+
+type Formatter<'a> = dyn Fn(&Percent, DrawTime) -> String + 'a;
+
+/// Prompt to get a percentage between 0 and 100.
+///
+/// # Key Events
+///
+/// | Key                         | Action                       |
+/// | --------------------------- | ---------------------------- |
+/// | `Enter`                     | Submit current/initial value |
+/// | `0`-`9`                     | Type the value [^type]       |
+/// | `Backspace`                 | Delete last digit            |
+/// | `Left`, `Down`, `h`, `j`    | Decrease by step             |
+/// | `Right`, `Up`, `l`, `k`     | Increase by step             |
+///
+/// [^type]: Digits are appended to the value, if the result is over 100 the value starts again.
+///
+/// # Examples
+///
+/// ```no_run
+/// use asky::Percent;
+///
+/// # fn main() -> std::io::Result<()> {
+/// let cpu = Percent::new("CPU limit").initial(50).prompt()?;
+/// # Ok(())
+/// # }
+/// ```
+pub struct Percent<'a> {
+    /// Message used to display in the prompt.
+    pub message: &'a str,
+    /// Stable identifier of the prompt.
+    pub name: Option<&'a str>,
+    /// Key of the answer, when it's recorded in [`Answers`](crate::Answers).
+    pub key: Option<&'a str>,
+    /// Current value of the prompt, between 0 and 100.
+    pub value: u8,
+    /// Amount to increase or decrease with arrow keys.
+    pub step: u8,
+    formatter: Box<Formatter<'a>>,
+}
+
+impl<'a> Percent<'a> {
+    /// Create a new percent prompt.
+    pub fn new(message: &'a str) -> Self {
+        Percent {
+            message,
+            name: None,
+            key: None,
+            value: 0,
+            step: 5,
+            formatter: Box::new(theme::fmt_percent),
+        }
+    }
+
+    /// Set initial value, limited to 100.
+    pub fn initial(&mut self, value: u8) -> &mut Self {
+        self.value = value.min(100);
+        self
+    }
+
+    /// Set amount to increase or decrease with arrow keys.
+    pub fn step(&mut self, step: u8) -> &mut Self {
+        self.step = step;
+        self
+    }
+
+    /// Set a stable identifier for the prompt, like `"db.host"`.
+    ///
+    /// It is available to custom formatters, which can use it to render per-field markers.
+    pub fn name(&mut self, name: &'a str) -> &mut Self {
+        self.name = Some(name);
+        self
+    }
+
+    /// Set the key of the answer, when it's recorded in [`Answers`](crate::Answers).
+    pub fn key(&mut self, key: &'a str) -> &mut Self {
+        self.key = Some(key);
+        self
+    }
+
+    /// Set custom closure to format the prompt.
+    ///
+    /// See: [`Customization`](index.html#customization).
+    pub fn format<F>(&mut self, formatter: F) -> &mut Self
+    where
+        F: Fn(&Percent, DrawTime) -> String + 'a,
+    {
+        self.formatter = Box::new(formatter);
+        self
+    }
+
+    /// Display the prompt and return the user answer, between 0 and 100.
+    pub fn prompt(&mut self) -> Result<u8, Error> {
+        key_listener::listen(self, true)?;
+        Ok(self.value)
+    }
+
+    /// Async version of [`Percent::prompt`].
+    #[cfg(feature = "async")]
+    pub async fn prompt_async(&mut self) -> Result<u8, Error> {
+        key_listener::listen_async(self, true).await?;
+        Ok(self.value)
+    }
+
+    /// Display the prompt and return the user answer as a ratio, between 0.0 and 1.0.
+    pub fn prompt_ratio(&mut self) -> Result<f32, Error> {
+        self.prompt().map(|value| value as f32 / 100.0)
+    }
+}
+
+impl Percent<'_> {
+    fn type_digit(&mut self, digit: u8) {
+        let value = self.value as u16 * 10 + digit as u16;
+
+        self.value = match value > 100 {
+            true => digit,
+            false => value as u8,
+        };
+    }
+
+    fn increase(&mut self) {
+        self.value = self.value.saturating_add(self.step).min(100);
+    }
+
+    fn decrease(&mut self) {
+        self.value = self.value.saturating_sub(self.step);
+    }
+}
+
+impl Recordable for Percent<'_> {
+    type Output = u8;
+
+    fn record_key(&self) -> Option<&str> {
+        self.key
+    }
+
+    fn ask(&mut self) -> Result<u8, Error> {
+        self.prompt()
+    }
+
+    fn submitted_answer(&self) -> Answer {
+        self.value.into()
+    }
+}
+
+impl Typeable for Percent<'_> {
+    fn handle_key(&mut self, key: KeyEvent) -> bool {
+        let mut submit = false;
+
+        match key_listener::navigation_key(key.code) {
+            // submit
+            KeyCode::Enter => submit = true,
+            // type
+            KeyCode::Char(c @ '0'..='9') => self.type_digit(c as u8 - b'0'),
+            KeyCode::Backspace => self.value /= 10,
+            // update value
+            KeyCode::Left | KeyCode::Down | KeyCode::Char('h' | 'j') => self.decrease(),
+            KeyCode::Right | KeyCode::Up | KeyCode::Char('l' | 'k') => self.increase(),
+            _ => (),
+        }
+
+        submit
+    }
+
+    fn answer_key(&self) -> Option<&str> {
+        self.key.or(self.name)
+    }
+
+    fn seed(&mut self, value: &str) -> bool {
+        match value.trim().parse() {
+            Ok(value @ 0..=100) => {
+                self.value = value;
+                true
+            }
+            _ => false,
+        }
+    }
+}
+
+impl Percent<'_> {
+    /// Returns a snapshot of the prompt state, see [`set_state_observer`](crate::set_state_observer).
+    pub fn state(&self) -> PromptState {
+        PromptState::new(self.message, self.name, self.value.to_string())
+    }
+}
+
+impl Printable for Percent<'_> {
+    fn draw(&self, renderer: &mut Renderer) -> io::Result<()> {
+        let text = (self.formatter)(self, renderer.draw_time);
+        renderer.print(text)
+    }
+
+    fn snapshot(&self) -> Option<PromptState> {
+        Some(self.state())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn set_initial_value() {
+        let mut prompt = Percent::new("");
+
+        prompt.initial(30);
+        assert_eq!(prompt.value, 30);
+        prompt.initial(200);
+        assert_eq!(prompt.value, 100);
+    }
+
+    #[test]
+    fn set_custom_formatter() {
+        let mut prompt = Percent::new("");
+        let draw_time = DrawTime::First;
+        const EXPECTED_VALUE: &str = "foo";
+
+        prompt.format(|_, _| String::from(EXPECTED_VALUE));
+
+        assert_eq!((prompt.formatter)(&prompt, draw_time), EXPECTED_VALUE);
+    }
+
+    #[test]
+    fn type_value() {
+        let mut prompt = Percent::new("");
+        let keys = [('4', 4), ('2', 42), ('7', 7), ('0', 70), ('0', 0)];
+
+        for (c, expected) in keys {
+            prompt.handle_key(KeyEvent::from(KeyCode::Char(c)));
+            assert_eq!(prompt.value, expected);
+        }
+
+        prompt.initial(100);
+        prompt.handle_key(KeyEvent::from(KeyCode::Backspace));
+        assert_eq!(prompt.value, 10);
+    }
+
+    #[test]
+    fn update_value_with_step() {
+        let mut prompt = Percent::new("");
+
+        prompt.initial(98).step(5);
+
+        prompt.handle_key(KeyEvent::from(KeyCode::Right));
+        assert_eq!(prompt.value, 100);
+
+        prompt.initial(3);
+        prompt.handle_key(KeyEvent::from(KeyCode::Left));
+        assert_eq!(prompt.value, 0);
+    }
+}
@@ -1,10 +1,23 @@
-use std::io;
+use std::{
+    any::Any,
+    borrow::Cow,
+    cmp::Reverse,
+    fmt, io,
+    ops::Range,
+    sync::{Arc, OnceLock},
+};
 
-use crossterm::event::{KeyCode, KeyEvent};
+use crossterm::event::{KeyCode, KeyEvent, KeyModifiers};
 
 use crate::utils::{
+    answer::{Answer, Recordable},
+    answer_source,
+    error::Error,
+    fuzzy,
     key_listener::{self, Typeable},
+    last_choices::LastChoices,
     renderer::{DrawTime, Printable, Renderer},
+    state::PromptState,
     theme,
 };
 
@@ -17,23 +30,74 @@ pub enum Direction {
 
 // region: SelectOption
 
+/// Arbitrary data attached to an option, compared by reference.
+#[derive(Clone)]
+struct Meta(Arc<dyn Any + Send + Sync>);
+
+impl fmt::Debug for Meta {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("Meta(..)")
+    }
+}
+
+impl PartialEq for Meta {
+    fn eq(&self, other: &Self) -> bool {
+        Arc::ptr_eq(&self.0, &other.0)
+    }
+}
+
+impl Eq for Meta {}
+
+/// Title computed the first time it's displayed, compared by reference.
+#[derive(Clone)]
+struct LazyTitle<'a> {
+    title_fn: Arc<dyn Fn() -> String + Send + Sync + 'a>,
+    title: OnceLock<String>,
+}
+
+impl fmt::Debug for LazyTitle<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_tuple("LazyTitle").field(&self.title.get()).finish()
+    }
+}
+
+impl PartialEq for LazyTitle<'_> {
+    fn eq(&self, other: &Self) -> bool {
+        Arc::ptr_eq(&self.title_fn, &other.title_fn)
+    }
+}
+
+impl Eq for LazyTitle<'_> {}
+
 /// Utility struct to create items for select-like prompts (like [`Select`]).
 #[derive(Debug, Clone, PartialEq, Eq, Default)]
 pub struct SelectOption<'a, T> {
     /// Value that will be returned by the prompt when the user selects the option.
     pub value: T,
     /// String that will be displayed in the prompt.
+    ///
+    /// It's empty for options created with [`SelectOption::with_title_fn`],
+    /// use [`SelectOption::get_title`] to read the title of any option.
     pub title: String,
     /// Description text to show in the prompt when focus the option.
     pub description: Option<&'a str>,
     /// Indicate if the option is disabled.
     pub disabled: bool,
-    /// Indicate if the option is active..
+    /// Reason to show in the prompt when focus the option and it's disabled.
+    pub disabled_reason: Option<&'a str>,
+    /// Secondary text displayed aligned to the right of the terminal.
+    pub annotation: Option<Cow<'a, str>>,
+    /// Indicate if the option is a separator, see [`SelectOption::separator`].
+    pub separator: bool,
+    /// Indicate if the option is active.
     ///
-    /// **Note**: This field is only used for [`MultiSelect`] prompt, not for [`Select`] prompt.
+    /// **Note**: This field is only used for [`MultiSelect`] prompt, and for [`Select`] prompt
+    /// when using [`Select::prompt_picked`].
     ///
     /// [`MultiSelect`]: crate::MultiSelect
     pub active: bool,
+    meta: Option<Meta>,
+    lazy_title: Option<LazyTitle<'a>>,
 }
 
 impl<'a, T: ToString> SelectOption<'a, T> {
@@ -43,24 +107,73 @@ impl<'a, T: ToString> SelectOption<'a, T> {
     pub fn new(value: T) -> Self {
         let title = value.to_string();
 
+        SelectOption::new_titled(value, title)
+    }
+}
+
+impl<'a, T> SelectOption<'a, T> {
+    /// Create a new option with a custom title, the value doesn't need to implement [`ToString`].
+    ///
+    /// * `value`: value that will be returned by the prompt when the user selects the option.
+    /// * `title`: string that will be displayed in the prompt.
+    pub fn new_titled(value: T, title: impl Into<String>) -> Self {
         SelectOption {
             value,
-            title,
+            title: title.into(),
             description: None,
             disabled: false,
+            disabled_reason: None,
+            annotation: None,
+            separator: false,
             active: false,
+            meta: None,
+            lazy_title: None,
         }
     }
 
+    /// Create a new option with a title computed only when it's displayed,
+    /// useful for expensive titles, like the ones requiring a lookup.
+    ///
+    /// The title is computed once and reused in the next draws.
+    ///
+    /// **Note**: Filtering the options of a [`Select::filterable`] prompt computes the titles
+    /// of all the options.
+    ///
+    /// * `value`: value that will be returned by the prompt when the user selects the option.
+    /// * `title_fn`: closure that returns the string to display in the prompt.
+    pub fn with_title_fn<F>(value: T, title_fn: F) -> Self
+    where
+        F: Fn() -> String + Send + Sync + 'a,
+    {
+        let mut option = SelectOption::new_titled(value, String::new());
+
+        option.lazy_title = Some(LazyTitle {
+            title_fn: Arc::new(title_fn),
+            title: OnceLock::new(),
+        });
+
+        option
+    }
+
     /// Create a new option with a custom title.
     ///
     /// * `value`: value that will be returned by the prompt when the user selects the option.
     /// * `title`: string that will be displayed in the prompt.
     pub fn title(mut self, title: &'a str) -> Self {
         self.title = title.to_string();
+        self.lazy_title = None;
         self
     }
 
+    /// Returns the title of the option, computing it if it was created with
+    /// [`SelectOption::with_title_fn`].
+    pub fn get_title(&self) -> &str {
+        match &self.lazy_title {
+            Some(lazy) => lazy.title.get_or_init(|| (lazy.title_fn)()),
+            None => &self.title,
+        }
+    }
+
     /// Description text to show in the prompt when focus the option.
     pub fn description(mut self, description: &'a str) -> Self {
         self.description = Some(description);
@@ -72,6 +185,51 @@ impl<'a, T: ToString> SelectOption<'a, T> {
         self.disabled = disabled;
         self
     }
+
+    /// Disable the option, with a reason to show when focus it.
+    pub fn disabled_reason(mut self, reason: &'a str) -> Self {
+        self.disabled = true;
+        self.disabled_reason = Some(reason);
+        self
+    }
+
+    /// Secondary text to display aligned to the right, like a version or a size.
+    pub fn annotation(mut self, annotation: impl Into<Cow<'a, str>>) -> Self {
+        self.annotation = Some(annotation.into());
+        self
+    }
+
+    /// Attach arbitrary data to the option, like a size or a status.
+    ///
+    /// It can be read with [`get_meta`] in custom formatters.
+    ///
+    /// [`get_meta`]: SelectOption::get_meta
+    pub fn meta<M: Any + Send + Sync>(mut self, meta: M) -> Self {
+        self.meta = Some(Meta(Arc::new(meta)));
+        self
+    }
+
+    /// Returns the data attached with [`meta`], if it's of type `M`.
+    ///
+    /// [`meta`]: SelectOption::meta
+    pub fn get_meta<M: Any>(&self) -> Option<&M> {
+        self.meta.as_ref()?.0.downcast_ref()
+    }
+}
+
+impl<'a, T: Default> SelectOption<'a, T> {
+    /// Create a separator, a row that can't be focused or chosen, to break up long lists.
+    ///
+    /// It's displayed as a horizontal rule, or as its [title](SelectOption::title) when set,
+    /// like a label of the next options, or a blank line with a `" "` title.
+    /// Separators are hidden while the options are filtered.
+    pub fn separator() -> Self {
+        SelectOption {
+            separator: true,
+            disabled: true,
+            ..SelectOption::new_titled(T::default(), "")
+        }
+    }
 }
 
 // endregion: SelectOption
@@ -83,13 +241,19 @@ impl<'a, T: ToString> SelectOption<'a, T> {
 /// **Note**: This structure is not expected to be created, but it can be consumed when using a custom formatter.
 pub struct SelectInput {
     /// Focused index of the list.
+    ///
+    /// When the list is filtered, it's the position in the filtered list, see [`SelectInput::option_index`].
     pub focused: usize,
     /// Number of items that must be displayed per page.
     pub items_per_page: usize,
     /// Indicate if the loop mode is enabled in the prompt.
     pub loop_mode: bool,
-    /// Number of total items in the prompt.
+    /// Number of total items in the prompt, or the matching items when the list is filtered.
     pub total_items: usize,
+    /// Text typed to filter the options.
+    pub filter: String,
+    filtered: Option<Vec<usize>>,
+    separators: Vec<usize>,
 }
 
 impl SelectInput {
@@ -106,16 +270,90 @@ impl SelectInput {
     pub fn get_page(&self) -> usize {
         self.focused / self.items_per_page
     }
+
+    /// Returns the index in the options of an item of the list, which may be filtered.
+    pub fn option_index(&self, position: usize) -> usize {
+        match &self.filtered {
+            Some(filtered) => filtered[position],
+            None => position,
+        }
+    }
+
+    /// Returns the index in the options of the focused item, if any.
+    pub fn focused_index(&self) -> Option<usize> {
+        match self.focused < self.total_items {
+            true => Some(self.option_index(self.focused)),
+            false => None,
+        }
+    }
+
+    /// Returns the positions of the items displayed in the current page.
+    pub fn visible_range(&self) -> Range<usize> {
+        let start = self.get_page() * self.items_per_page;
+
+        start..(start + self.items_per_page).min(self.total_items)
+    }
+
+    /// Focus the item at a position of the list, or the last item if it's out of range.
+    ///
+    /// Separators can't be focused, the next item is focused instead.
+    pub fn focus(&mut self, position: usize) {
+        self.focused = position.min(self.total_items.saturating_sub(1));
+        self.skip_separator(true);
+    }
+
+    /// Returns whether the item at a position of the list is a separator.
+    pub fn is_separator(&self, position: usize) -> bool {
+        position < self.total_items && self.separators.contains(&self.option_index(position))
+    }
+
+    /// Focus the item of an option by its index in the options.
+    ///
+    /// Returns `false` if the option is hidden by the filter.
+    pub fn focus_option(&mut self, index: usize) -> bool {
+        let position = (0..self.total_items).find(|position| self.option_index(*position) == index);
+
+        if let Some(position) = position {
+            self.focus(position);
+        }
+
+        position.is_some()
+    }
+
+    /// Focus the first item of a page, or the last page if it's out of range.
+    pub fn page_to(&mut self, page: usize) {
+        let page = page.min(self.count_pages().saturating_sub(1));
+
+        self.focus(page * self.items_per_page);
+    }
 }
 
 impl SelectInput {
-    pub(crate) fn new(total_items: usize) -> Self {
-        SelectInput {
-            total_items,
+    pub(crate) fn new<T>(options: &[SelectOption<T>]) -> Self {
+        let separators = (0..options.len())
+            .filter(|&i| options[i].separator)
+            .collect();
+
+        let mut input = SelectInput {
+            total_items: options.len(),
             focused: 0,
             items_per_page: 10,
             loop_mode: true,
-        }
+            filter: String::new(),
+            filtered: None,
+            separators,
+        };
+
+        input.skip_separator(true);
+        input
+    }
+
+    /// Show only the given options, in order, and focus the first one.
+    pub(crate) fn set_filtered(&mut self, filtered: Option<Vec<usize>>, total_options: usize) {
+        self.total_items = filtered.as_ref().map_or(total_options, Vec::len);
+        self.filtered = filtered;
+        self.focused = 0;
+        self.skip_separator(true);
     }
 
     pub(crate) fn set_loop_mode(&mut self, loop_mode: bool) {
@@ -132,57 +370,109 @@ impl SelectInput {
     }
 
     pub(crate) fn set_items_per_page(&mut self, item_per_page: usize) {
-        self.items_per_page = item_per_page.min(self.total_items);
+        self.items_per_page = item_per_page.min(self.total_items).max(1);
     }
 
     fn prev_item(&mut self) {
-        let max = self.total_items - 1;
-
-        self.focused = match self.loop_mode {
-            true => self.focused.checked_sub(1).unwrap_or(max),
-            false => self.focused.saturating_sub(1),
-        }
+        self.step_item(false)
     }
 
     fn next_item(&mut self) {
+        self.step_item(true)
+    }
+
+    /// Move the focus to the previous or next item, over the separators.
+    ///
+    /// The focus doesn't move if there is no item to focus in that direction.
+    fn step_item(&mut self, forward: bool) {
+        if self.total_items == 0 {
+            return;
+        }
+
+        let start = self.focused;
         let max = self.total_items - 1;
-        let new_value = self.focused + 1;
 
-        self.focused = match (new_value > max, self.loop_mode) {
-            (true, true) => 0,
-            (true, false) => max,
-            (false, _) => new_value,
+        for _ in 0..self.total_items {
+            let current = self.focused;
+
+            self.focused = match (forward, self.loop_mode) {
+                (true, true) if current == max => 0,
+                (true, _) => (current + 1).min(max),
+                (false, true) => current.checked_sub(1).unwrap_or(max),
+                (false, false) => current.saturating_sub(1),
+            };
+
+            if !self.is_separator(self.focused) {
+                return;
+            }
+
+            // reached the end of the list
+            if self.focused == current {
+                break;
+            }
         }
+
+        self.focused = start;
     }
 
     fn prev_page(&mut self) {
-        self.focused = self.focused.saturating_sub(self.items_per_page)
+        self.focused = self.focused.saturating_sub(self.items_per_page);
+        self.skip_separator(false);
     }
 
     fn next_page(&mut self) {
-        let max = self.total_items - 1;
+        let max = self.total_items.saturating_sub(1);
         let new_value = self.focused + self.items_per_page;
 
-        self.focused = new_value.min(max)
+        self.focused = new_value.min(max);
+        self.skip_separator(true);
+    }
+
+    /// Move the focus out of a separator, to the nearest item in the direction, or the other one.
+    fn skip_separator(&mut self, forward: bool) {
+        if !self.is_separator(self.focused) {
+            return;
+        }
+
+        let after = (self.focused..self.total_items).find(|&p| !self.is_separator(p));
+        let before = (0..self.focused).rev().find(|&p| !self.is_separator(p));
+
+        let position = match forward {
+            true => after.or(before),
+            false => before.or(after),
+        };
+
+        if let Some(position) = position {
+            self.focused = position;
+        }
     }
 }
 
 // endregion: SelectCursor
 
 type Formatter<'a, T> = dyn Fn(&Select<T>, DrawTime) -> String + 'a;
+type Reporter<'a, T> = dyn Fn(&T) -> String + 'a;
 
 /// Prompt to select an item from a list.
 ///
 /// To allow choosing multiple items, use the [`MultiSelect`] struct instead.
 /// # Key Events
 ///
-/// | Key                  | Action                       |
-/// | -------------------- | ---------------------------- |
-/// | `Enter`, `Backspace` | Submit current/initial value |
-/// | `Up`, `k`, `K`       | Focus next item              |
-/// | `Down`, `j`, `J`     | Focus previous item          |
-/// | `Left`, `h`, `H`     | Focus next page              |
-/// | `Right`, `l`, `L`    | Focus previous page          |
+/// | Key                  | Action                                |
+/// | -------------------- | ------------------------------------- |
+/// | `Enter`, `Backspace` | Submit current/initial value          |
+/// | `Ctrl+Enter`, `Tab`  | Pick focused item [^pick]             |
+/// | `Up`, `k`, `K`       | Focus next item                       |
+/// | `Down`, `j`, `J`     | Focus previous item                   |
+/// | `Left`, `h`, `H`     | Focus next page                       |
+/// | `Right`, `l`, `L`    | Focus previous page                   |
+/// | Any character        | Filter the options [^filter]          |
+///
+/// [^pick]: Only when using [`Select::prompt_picked`]. `Ctrl+Enter` needs a terminal with the
+/// keyboard enhancement protocol, like kitty or WezTerm, the others report it as `Enter`.
+///
+/// [^filter]: Only when using [`Select::filterable`], then the `h`, `j`, `k`, `l` keys
+/// are typed into the filter, and `Backspace` deletes the last character of the filter.
 ///
 /// # Examples
 ///
@@ -199,10 +489,29 @@ type Formatter<'a, T> = dyn Fn(&Select<T>, DrawTime) -> String + 'a;
 pub struct Select<'a, T> {
     /// Message used to display in the prompt.
     pub message: &'a str,
+    /// Stable identifier of the prompt.
+    pub name: Option<&'a str>,
+    /// Key of the answer, when it's recorded in [`Answers`](crate::Answers).
+    pub key: Option<&'a str>,
     /// List of options.
     pub options: Vec<SelectOption<'a, T>>,
     /// Input state.
     pub input: SelectInput,
+    /// Indicate if multiple options can be picked with `Ctrl+Enter` or `Tab`.
+    ///
+    /// See [`Select::prompt_picked`].
+    pub multi_pick: bool,
+    /// Indicate if typing characters filters the options.
+    ///
+    /// See [`Select::filterable`].
+    pub filterable: bool,
+    /// Index of the correct option, to display if the user was right after submitting.
+    pub correct: Option<usize>,
+    /// Explanation to display after submitting, when the correct option is set.
+    pub explanation: Option<&'a str>,
+    last_choices: Option<&'a mut LastChoices>,
+    submitted: Answer,
+    reporter: Option<Box<Reporter<'a, T>>>,
     formatter: Box<Formatter<'a, T>>,
 }
 
@@ -236,19 +545,26 @@ impl<'a, T: 'a> Select<'a, T> {
     /// # Ok(())
     /// # }
     pub fn new_complex(message: &'a str, options: Vec<SelectOption<'a, T>>) -> Self {
-        let options_len = options.len();
-
         Select {
             message,
+            name: None,
+            key: None,
+            input: SelectInput::new(&options),
             options,
-            input: SelectInput::new(options_len),
+            multi_pick: false,
+            filterable: false,
+            correct: None,
+            explanation: None,
+            last_choices: None,
+            submitted: Answer::Unit,
+            reporter: None,
             formatter: Box::new(theme::fmt_select),
         }
     }
 
     /// Set initial selected index.
     pub fn selected(&mut self, index: usize) -> &mut Self {
-        self.input.focused = index.min(self.options.len() - 1);
+        self.input.focus(index);
         self
     }
 
@@ -264,6 +580,50 @@ impl<'a, T: 'a> Select<'a, T> {
         self
     }
 
+    /// Set whether typing characters filters the options, using fuzzy matching on the titles.
+    ///
+    /// The options are sorted by relevance while the filter isn't empty.
+    pub fn filterable(&mut self, filterable: bool) -> &mut Self {
+        self.filterable = filterable;
+        self
+    }
+
+    /// Set the index of the correct option, to display if the user was right after submitting.
+    pub fn correct(&mut self, index: usize) -> &mut Self {
+        self.correct = Some(index);
+        self
+    }
+
+    /// Set an explanation to display after submitting, when the correct option is set.
+    pub fn explanation(&mut self, explanation: &'a str) -> &mut Self {
+        self.explanation = Some(explanation);
+        self
+    }
+
+    /// Set a stable identifier for the prompt, like `"db.host"`.
+    ///
+    /// It is available to custom formatters, which can use it to render per-field markers.
+    pub fn name(&mut self, name: &'a str) -> &mut Self {
+        self.name = Some(name);
+        self
+    }
+
+    /// Set the key of the answer, when it's recorded in [`Answers`](crate::Answers).
+    pub fn key(&mut self, key: &'a str) -> &mut Self {
+        self.key = Some(key);
+        self
+    }
+
+    /// Focus the option chosen the last time, and remember the new choice.
+    ///
+    /// The choice is saved by the prompt [`name`](Select::name) and the option title,
+    /// it does nothing for prompts without a name.
+    /// The remembered option takes precedence over [`Select::selected`].
+    pub fn remember_last(&mut self, choices: &'a mut LastChoices) -> &mut Self {
+        self.last_choices = Some(choices);
+        self
+    }
+
     /// Set custom closure to format the prompt.
     ///
     /// See: [`Customization`](index.html#customization).
@@ -275,22 +635,209 @@ impl<'a, T: 'a> Select<'a, T> {
         self
     }
 
+    /// Set a closure to display the chosen option in the final line, instead of its title.
+    ///
+    /// It receives the value of the option. It isn't used by [`Select::prompt_picked`].
+    pub fn report_with<F>(&mut self, reporter: F) -> &mut Self
+    where
+        F: Fn(&T) -> String + 'a,
+    {
+        self.reporter = Some(Box::new(reporter));
+        self
+    }
+
     /// Display the prompt and return the user answer.
-    pub fn prompt(&mut self) -> io::Result<T> {
+    pub fn prompt(&mut self) -> Result<T, Error> {
         key_listener::listen(self, true)?;
+        self.save_choice()?;
+        Ok(self.take_focused())
+    }
 
-        let selected = self.options.remove(self.input.focused);
+    /// Async version of [`Select::prompt`].
+    #[cfg(feature = "async")]
+    pub async fn prompt_async(&mut self) -> Result<T, Error> {
+        key_listener::listen_async(self, true).await?;
+        self.save_choice()?;
+        Ok(self.take_focused())
+    }
 
-        Ok(selected.value)
+    /// Display the prompt and return the indices of the picked options.
+    ///
+    /// `Ctrl+Enter` or `Tab` picks the focused option and keeps the prompt open,
+    /// `Enter` picks the focused option and submits.
+    pub fn prompt_picked(&mut self) -> Result<Vec<usize>, Error> {
+        self.multi_pick = true;
+        key_listener::listen(self, true)?;
+        self.save_choice()?;
+
+        Ok(self.picked_indices())
     }
 }
 
-impl<T> Select<'_, T> {
+impl<'a, T> Select<'a, T> {
+    /// Returns the answer to display in the final line, when using `report_with`.
+    pub(crate) fn report(&self, index: usize) -> Option<String> {
+        let reporter = self.reporter.as_ref()?;
+        Some(reporter(&self.options[index].value))
+    }
+
+    fn take_focused(&mut self) -> T {
+        let focused = self
+            .input
+            .focused_index()
+            .expect("submit requires a focused option");
+
+        self.options.remove(focused).value
+    }
+
+    /// Returns whether the focused option is the correct one, if it was set.
+    ///
+    /// With [`Select::prompt_picked`], the correct option must be the only one picked.
+    pub fn is_correct(&self) -> Option<bool> {
+        let correct = self.correct?;
+
+        match self.multi_pick {
+            true => Some(self.picked_indices() == [correct]),
+            false => Some(self.input.focused_index() == Some(correct)),
+        }
+    }
+
+    fn picked_indices(&self) -> Vec<usize> {
+        self.options
+            .iter()
+            .enumerate()
+            .filter(|(_, option)| option.active)
+            .map(|(i, _)| i)
+            .collect()
+    }
+
+    /// Focus the last chosen option, or pick the last picked ones with `multi_pick`.
+    fn restore_last_choice(&mut self) {
+        let (Some(name), Some(choices)) = (self.name, self.last_choices.as_deref()) else {
+            return;
+        };
+
+        let Some(titles) = choices.get(name) else {
+            return;
+        };
+
+        let chosen: Vec<usize> = (0..self.options.len())
+            .filter(|&i| !self.options[i].disabled)
+            .filter(|&i| titles.iter().any(|t| t == self.options[i].get_title()))
+            .collect();
+
+        if let Some(&first) = chosen.first() {
+            self.input.focus_option(first);
+        }
+
+        if self.multi_pick {
+            for i in chosen {
+                self.options[i].active = true;
+            }
+        }
+    }
+
+    /// Save the submitted choice, before the focused option is taken out.
+    fn save_choice(&mut self) -> io::Result<()> {
+        let indices = match self.multi_pick {
+            true => self.picked_indices(),
+            false => self.input.focused_index().into_iter().collect(),
+        };
+
+        self.submitted = match self.multi_pick {
+            true => Answer::Indices(indices.clone()),
+            false => indices.first().map_or(Answer::Unit, |&i| Answer::Index(i)),
+        };
+
+        let titles = indices
+            .into_iter()
+            .map(|i| self.options[i].get_title().to_owned())
+            .collect();
+
+        match (self.name, self.last_choices.as_deref_mut()) {
+            (Some(name), Some(choices)) => choices.set(name, titles),
+            _ => Ok(()),
+        }
+    }
+
+    fn focused_option_mut(&mut self) -> Option<&mut SelectOption<'a, T>> {
+        let index = self.input.focused_index()?;
+        self.options.get_mut(index)
+    }
+
+    /// Update the filtered options after the filter changed.
+    fn update_filter(&mut self) {
+        let filtered = match self.input.filter.is_empty() {
+            true => None,
+            false => {
+                let mut matches: Vec<(i64, usize)> = self
+                    .options
+                    .iter()
+                    .enumerate()
+                    .filter(|(_, option)| !option.separator)
+                    .filter_map(|(i, option)| {
+                        fuzzy::fuzzy_match(&self.input.filter, option.get_title())
+                            .map(|(score, _)| (score, i))
+                    })
+                    .collect();
+
+                // stable sort, so equal scores keep the original order
+                matches.sort_by_key(|(score, _)| Reverse(*score));
+
+                Some(matches.into_iter().map(|(_, i)| i).collect())
+            }
+        };
+
+        self.input.set_filtered(filtered, self.options.len());
+    }
+
+    /// Handle the keys that edit the filter, returns whether the key was handled.
+    fn handle_filter_key(&mut self, key: KeyEvent) -> bool {
+        match key.code {
+            KeyCode::Char(c) if !key.modifiers.contains(KeyModifiers::CONTROL) => {
+                self.input.filter.push(c)
+            }
+            KeyCode::Backspace => {
+                self.input.filter.pop();
+            }
+            _ => return false,
+        }
+
+        self.update_filter();
+        true
+    }
+
+    /// Toggle the focused option, only for multi-pick mode.
+    fn toggle_picked(&mut self) {
+        if let Some(focused) = self.focused_option_mut() {
+            if !focused.disabled {
+                focused.active = !focused.active;
+            }
+        }
+    }
+
     /// Only submit if the option isn't disabled.
     fn validate_to_submit(&self) -> bool {
-        let focused = &self.options[self.input.focused];
+        match self.input.focused_index().map(|i| &self.options[i]) {
+            Some(focused) => !focused.disabled,
+            None => false,
+        }
+    }
+}
+
+impl<'a, T: 'a> Recordable for Select<'a, T> {
+    type Output = T;
+
+    fn record_key(&self) -> Option<&str> {
+        self.key
+    }
+
+    fn ask(&mut self) -> Result<T, Error> {
+        self.prompt()
+    }
 
-        !focused.disabled
+    fn submitted_answer(&self) -> Answer {
+        self.submitted.clone()
     }
 }
 
@@ -298,9 +845,26 @@ impl<T> Typeable for Select<'_, T> {
     fn handle_key(&mut self, key: KeyEvent) -> bool {
         let mut submit = false;
 
-        match key.code {
+        if self.filterable && self.handle_filter_key(key) {
+            return submit;
+        }
+
+        match key_listener::navigation_key(key.code) {
+            // pick and keep open
+            KeyCode::Enter if self.multi_pick && key.modifiers == KeyModifiers::CONTROL => {
+                self.toggle_picked()
+            }
+            KeyCode::Tab if self.multi_pick => self.toggle_picked(),
             // submit
-            KeyCode::Enter | KeyCode::Backspace => submit = self.validate_to_submit(),
+            KeyCode::Enter | KeyCode::Backspace => {
+                submit = self.validate_to_submit();
+
+                if submit && self.multi_pick {
+                    if let Some(focused) = self.focused_option_mut() {
+                        focused.active = true;
+                    }
+                }
+            }
             // update value
             KeyCode::Up | KeyCode::Char('k' | 'K') => self.input.move_cursor(Direction::Up),
             KeyCode::Down | KeyCode::Char('j' | 'J') => self.input.move_cursor(Direction::Down),
@@ -311,6 +875,49 @@ impl<T> Typeable for Select<'_, T> {
 
         submit
     }
+
+    fn answer_key(&self) -> Option<&str> {
+        self.key.or(self.name)
+    }
+
+    fn needs_key_enhancement(&self) -> bool {
+        self.multi_pick
+    }
+
+    fn seed(&mut self, value: &str) -> bool {
+        if !self.multi_pick {
+            return answer_source::find_option(&self.options, value.trim())
+                .is_some_and(|index| self.input.focus_option(index));
+        }
+
+        let indices: Option<Vec<usize>> = answer_source::split_titles(value)
+            .map(|title| answer_source::find_option(&self.options, title))
+            .collect();
+
+        let Some(indices) = indices else {
+            return false;
+        };
+
+        for (i, option) in self.options.iter_mut().enumerate() {
+            option.active = indices.contains(&i);
+        }
+
+        true
+    }
+
+    fn prepare(&mut self) {
+        self.restore_last_choice();
+    }
+}
+
+impl<T> Select<'_, T> {
+    /// Returns a snapshot of the prompt state, see [`set_state_observer`](crate::set_state_observer).
+    pub fn state(&self) -> PromptState {
+        let focused = self.input.focused_index();
+        let value = focused.map_or("", |i| self.options[i].get_title());
+
+        PromptState::new(self.message, self.name, value).with_options(&self.options, focused)
+    }
 }
 
 impl<T> Printable for Select<'_, T> {
@@ -318,12 +925,163 @@ impl<T> Printable for Select<'_, T> {
         let text = (self.formatter)(self, renderer.draw_time);
         renderer.print(text)
     }
+
+    fn snapshot(&self) -> Option<PromptState> {
+        Some(self.state())
+    }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
 
+    #[test]
+    fn check_correct_answer() {
+        let mut prompt = Select::new("", ["foo", "bar", "baz"]);
+
+        assert_eq!(prompt.is_correct(), None);
+        prompt.correct(1);
+        assert_eq!(prompt.is_correct(), Some(false));
+        prompt.handle_key(KeyEvent::from(KeyCode::Down));
+        assert_eq!(prompt.is_correct(), Some(true));
+
+        prompt.multi_pick = true;
+        assert_eq!(prompt.is_correct(), Some(false));
+        prompt.toggle_picked();
+        assert_eq!(prompt.is_correct(), Some(true));
+    }
+
+    #[test]
+    fn compute_lazy_title_once() {
+        use std::sync::atomic::{AtomicUsize, Ordering};
+
+        let calls = AtomicUsize::new(0);
+        let option = SelectOption::with_title_fn(1, || {
+            calls.fetch_add(1, Ordering::SeqCst);
+            String::from("foo")
+        });
+
+        assert_eq!(calls.load(Ordering::SeqCst), 0);
+        assert_eq!(option.get_title(), "foo");
+        assert_eq!(option.get_title(), "foo");
+        assert_eq!(calls.load(Ordering::SeqCst), 1);
+
+        assert_eq!(option.title("bar").get_title(), "bar");
+    }
+
+    #[test]
+    fn navigate_programmatically() {
+        let mut prompt = Select::new("", 0..25);
+
+        prompt.items_per_page(10);
+        prompt.input.page_to(2);
+        assert_eq!(prompt.input.focused, 20);
+        assert_eq!(prompt.input.visible_range(), 20..25);
+
+        prompt.input.page_to(9);
+        assert_eq!(prompt.input.get_page(), 2);
+
+        prompt.input.focus(12);
+        assert_eq!(prompt.input.visible_range(), 10..20);
+        prompt.input.focus(99);
+        assert_eq!(prompt.input.focused, 24);
+
+        prompt.filterable(true);
+        prompt.handle_key(KeyEvent::from(KeyCode::Char('2')));
+        assert!(prompt.input.focus_option(12));
+        assert_eq!(prompt.input.focused_index(), Some(12));
+        assert!(!prompt.input.focus_option(13));
+    }
+
+    #[test]
+    fn skip_separators() {
+        let options = vec![
+            SelectOption::separator().title("Fruits"),
+            SelectOption::new("apple"),
+            SelectOption::separator(),
+            SelectOption::new("carrot"),
+            SelectOption::separator(),
+        ];
+        let mut prompt = Select::new_complex("", options);
+
+        // the first item is a separator
+        assert_eq!(prompt.input.focused, 1);
+
+        prompt.handle_key(KeyEvent::from(KeyCode::Down));
+        assert_eq!(prompt.input.focused, 3);
+        prompt.handle_key(KeyEvent::from(KeyCode::Down));
+        assert_eq!(prompt.input.focused, 1);
+        prompt.handle_key(KeyEvent::from(KeyCode::Up));
+        assert_eq!(prompt.input.focused, 3);
+
+        prompt.in_loop(false);
+        prompt.handle_key(KeyEvent::from(KeyCode::Down));
+        assert_eq!(prompt.input.focused, 3);
+
+        // separators take a row of the page
+        prompt.items_per_page(2);
+        assert_eq!(prompt.input.count_pages(), 3);
+        prompt.input.page_to(2);
+        assert_eq!(prompt.input.focused, 3);
+
+        prompt.filterable(true);
+        prompt.handle_key(KeyEvent::from(KeyCode::Char('r')));
+        assert_eq!(prompt.input.total_items, 1);
+        assert_eq!(prompt.input.focused_index(), Some(3));
+    }
+
+    #[test]
+    fn remember_last_choice() {
+        let mut choices = LastChoices::new();
+        let mut prompt = Select::new("", ["a", "b", "c"]);
+
+        prompt.name("letter").remember_last(&mut choices);
+        prompt.restore_last_choice();
+        assert_eq!(prompt.input.focused, 0);
+
+        prompt.handle_key(KeyEvent::from(KeyCode::Down));
+        prompt.save_choice().unwrap();
+        assert_eq!(prompt.submitted_answer(), Answer::Index(1));
+
+        drop(prompt);
+        assert_eq!(choices.get("letter"), Some(&[String::from("b")][..]));
+
+        let mut prompt = Select::new("", ["c", "b", "a"]);
+        prompt.name("letter").remember_last(&mut choices);
+        prompt.restore_last_choice();
+        assert_eq!(prompt.input.focused_index(), Some(1));
+    }
+
+    #[test]
+    fn filter_options() {
+        let mut prompt = Select::new("", ["Rust", "Go", "Python", "Javascript"]);
+        let type_keys = |prompt: &mut Select<&str>, keys: &[KeyCode]| {
+            for key in keys {
+                prompt.handle_key(KeyEvent::from(*key));
+            }
+        };
+
+        prompt.filterable(true);
+        type_keys(&mut prompt, &[KeyCode::Char('j'), KeyCode::Char('s')]);
+
+        assert_eq!(prompt.input.filter, "js");
+        assert_eq!(prompt.input.total_items, 1);
+        assert_eq!(prompt.input.focused_index(), Some(3));
+
+        type_keys(&mut prompt, &[KeyCode::Char('x')]);
+        assert_eq!(prompt.input.focused_index(), None);
+        assert!(!prompt.handle_key(KeyEvent::from(KeyCode::Enter)));
+
+        type_keys(&mut prompt, &[KeyCode::Backspace; 3]);
+        type_keys(&mut prompt, &[KeyCode::Char('o'), KeyCode::Down]);
+
+        // sorted by relevance, "Go" matches closer to the start
+        assert_eq!(prompt.input.total_items, 2);
+        assert_eq!(prompt.input.option_index(0), 1);
+        assert_eq!(prompt.input.focused_index(), Some(2));
+        assert!(prompt.handle_key(KeyEvent::from(KeyCode::Enter)));
+    }
+
     #[test]
     fn set_initial_value() {
         let mut prompt = Select::new("", ["foo", "bar"]);
@@ -343,6 +1101,15 @@ mod tests {
         assert!(prompt.input.loop_mode);
     }
 
+    #[test]
+    fn set_name() {
+        let mut prompt = Select::new("", ["foo", "bar"]);
+
+        assert_eq!(prompt.name, None);
+        prompt.name("foo");
+        assert_eq!(prompt.name, Some("foo"));
+    }
+
     #[test]
     fn set_custom_formatter() {
         let mut prompt = Select::new("", ["foo", "bar"]);
@@ -382,6 +1149,61 @@ mod tests {
         }
     }
 
+    #[test]
+    fn pick_multiple() {
+        let mut prompt = Select::new("", ["foo", "bar", "baz"]);
+        let pick_key = KeyEvent::new(KeyCode::Enter, KeyModifiers::CONTROL);
+
+        prompt.multi_pick = true;
+
+        // pick and unpick
+        assert!(!prompt.handle_key(pick_key));
+        assert!(!prompt.handle_key(pick_key));
+        assert!(prompt.picked_indices().is_empty());
+
+        prompt.handle_key(pick_key);
+        prompt.handle_key(KeyEvent::from(KeyCode::Down));
+        prompt.handle_key(KeyEvent::from(KeyCode::Tab));
+        prompt.handle_key(KeyEvent::from(KeyCode::Down));
+
+        assert!(prompt.needs_key_enhancement());
+        assert!(prompt.handle_key(KeyEvent::from(KeyCode::Enter)));
+        assert_eq!(prompt.picked_indices(), vec![0, 1, 2]);
+    }
+
+    #[test]
+    fn not_pick_without_multi_pick() {
+        let mut prompt = Select::new("", ["foo", "bar"]);
+        let submit = prompt.handle_key(KeyEvent::new(KeyCode::Enter, KeyModifiers::CONTROL));
+
+        assert!(submit);
+        assert!(prompt.picked_indices().is_empty());
+    }
+
+    #[test]
+    fn set_disabled_reason() {
+        let option = SelectOption::new("foo").disabled_reason("Not available");
+
+        assert!(option.disabled);
+        assert_eq!(option.disabled_reason, Some("Not available"));
+    }
+
+    #[test]
+    fn set_annotation() {
+        let option = SelectOption::new("foo").annotation("1.2.0");
+
+        assert_eq!(option.annotation.as_deref(), Some("1.2.0"));
+    }
+
+    #[test]
+    fn set_meta() {
+        let option = SelectOption::new("foo").meta(1024_u64);
+
+        assert_eq!(option.get_meta::<u64>(), Some(&1024));
+        assert_eq!(option.get_meta::<&str>(), None);
+        assert_eq!(SelectOption::new("foo").get_meta::<u64>(), None);
+    }
+
     #[test]
     fn update_focused() {
         let up_keys = [KeyCode::Up, KeyCode::Char('k'), KeyCode::Char('K')];
@@ -424,4 +1246,40 @@ mod tests {
             }
         }
     }
+
+    mod proptests {
+        use super::*;
+        use proptest::prelude::*;
+
+        fn key_code() -> impl Strategy<Value = KeyCode> {
+            prop_oneof![
+                Just(KeyCode::Up),
+                Just(KeyCode::Down),
+                Just(KeyCode::Left),
+                Just(KeyCode::Right),
+                Just(KeyCode::Enter),
+            ]
+        }
+
+        proptest! {
+            #[test]
+            fn handle_random_keys(
+                total in 0..40_usize,
+                items_per_page in 0..15_usize,
+                in_loop in any::<bool>(),
+                keys in prop::collection::vec(key_code(), 0..64),
+            ) {
+                let mut prompt = Select::new("", 0..total);
+                prompt.items_per_page(items_per_page);
+                prompt.in_loop(in_loop);
+
+                for key in keys {
+                    prompt.handle_key(KeyEvent::from(key));
+                    prop_assert!(prompt.input.focused < total.max(1));
+                }
+
+                (prompt.formatter)(&prompt, DrawTime::Update);
+            }
+        }
+    }
 }
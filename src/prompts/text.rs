@@ -1,28 +1,45 @@
-use std::io;
+use std::{borrow::Cow, io, mem};
 
-use crossterm::event::{KeyCode, KeyEvent};
+use crossterm::event::{KeyCode, KeyEvent, KeyModifiers};
+use unicode_segmentation::UnicodeSegmentation;
+use unicode_width::UnicodeWidthStr;
 
 use crate::utils::{
+    answer::{Answer, Recordable},
+    error::Error,
+    history::History,
     key_listener::{self, Typeable},
     renderer::{DrawTime, Printable, Renderer},
+    state::PromptState,
     theme,
 };
 
+use super::pair::Composable;
+
 pub enum Direction {
     Left,
     Right,
+    Home,
+    End,
+    WordLeft,
+    WordRight,
 }
 
 // region: TextInput
 
 /// State of the user input for read-line text prompts (like [`Text`]).
 ///
+/// The input is edited by grapheme clusters, so characters like `"é"` (`e` + accent)
+/// or emojis are moved and deleted as a whole.
+///
 /// **Note**: This structure is not expected to be created, but it can be consumed when using a custom formatter.
 #[derive(Debug, PartialEq, Eq, Default)]
 pub struct LineInput {
     /// Current value of the input.
     pub value: String,
-    /// Current position of the cursor.
+    /// Current position of the cursor, in grapheme clusters.
+    ///
+    /// Use [`LineInput::cursor_width`] to place the cursor in the terminal.
     pub col: usize,
 }
 
@@ -30,56 +47,175 @@ impl LineInput {
     pub(crate) fn new() -> Self {
         LineInput::default()
     }
+
+    /// Returns the width in the terminal of the text before the cursor.
+    ///
+    /// Wide characters, like CJK or emojis, take two columns.
+    pub fn cursor_width(&self) -> usize {
+        self.value[..self.byte_index(self.col)].width()
+    }
+
+    /// Returns the number of grapheme clusters of the value.
+    pub fn len(&self) -> usize {
+        self.value.graphemes(true).count()
+    }
+
+    /// Returns `true` if the value is empty.
+    pub fn is_empty(&self) -> bool {
+        self.value.is_empty()
+    }
 }
 
 impl LineInput {
     pub(crate) fn set_value(&mut self, value: &str) {
-        self.value = String::from(value);
-        self.col = value.len();
+        // reuse the buffer, to keep its capacity
+        self.value.clear();
+        self.value.push_str(value);
+        self.col = self.len();
     }
 
     pub(crate) fn insert(&mut self, ch: char) {
-        self.value.insert(self.col, ch);
-        self.col += 1;
+        let index = self.byte_index(self.col);
+
+        self.value.insert(index, ch);
+        // a combining character is merged with the previous grapheme
+        self.col = self.value[..index + ch.len_utf8()].graphemes(true).count();
     }
 
     pub(crate) fn backspace(&mut self) {
-        if !self.value.is_empty() && self.col > 0 {
-            self.col -= 1;
-            self.value.remove(self.col);
+        if self.col > 0 {
+            self.delete_to(self.col - 1);
         }
     }
 
     pub(crate) fn delete(&mut self) {
-        if !self.value.is_empty() && self.col < self.value.len() {
-            self.value.remove(self.col);
+        if self.col < self.len() {
+            self.delete_to(self.col + 1);
         }
     }
 
     pub(crate) fn move_cursor(&mut self, position: Direction) {
         self.col = match position {
             Direction::Left => self.col.saturating_sub(1),
-            Direction::Right => (self.col + 1).min(self.value.len()),
+            Direction::Right => (self.col + 1).min(self.len()),
+            Direction::Home => 0,
+            Direction::End => self.len(),
+            Direction::WordLeft => self.prev_word_start(),
+            Direction::WordRight => self.next_word_end(),
+        }
+    }
+
+    /// Handle the readline-like editing shortcuts, returns whether the key was handled.
+    pub(crate) fn handle_shortcut(&mut self, key: KeyEvent) -> bool {
+        match (key.modifiers, key.code) {
+            (KeyModifiers::NONE, KeyCode::Home) => self.move_cursor(Direction::Home),
+            (KeyModifiers::NONE, KeyCode::End) => self.move_cursor(Direction::End),
+            (KeyModifiers::CONTROL, KeyCode::Left) => self.move_cursor(Direction::WordLeft),
+            (KeyModifiers::CONTROL, KeyCode::Right) => self.move_cursor(Direction::WordRight),
+            (KeyModifiers::CONTROL, KeyCode::Char('a')) => self.move_cursor(Direction::Home),
+            (KeyModifiers::CONTROL, KeyCode::Char('e')) => self.move_cursor(Direction::End),
+            (KeyModifiers::ALT, KeyCode::Char('b')) => self.move_cursor(Direction::WordLeft),
+            (KeyModifiers::ALT, KeyCode::Char('f')) => self.move_cursor(Direction::WordRight),
+            (KeyModifiers::CONTROL, KeyCode::Char('w')) => self.delete_to(self.prev_word_start()),
+            (KeyModifiers::CONTROL, KeyCode::Char('u')) => self.delete_to(0),
+            (KeyModifiers::CONTROL, KeyCode::Char('k')) => self.delete_to(self.len()),
+            _ => return false,
+        }
+
+        true
+    }
+
+    /// Delete the graphemes between the cursor and `col`, in any direction.
+    fn delete_to(&mut self, col: usize) {
+        let (start, end) = (self.col.min(col), self.col.max(col));
+        let range = self.byte_index(start)..self.byte_index(end);
+
+        self.value.replace_range(range, "");
+        self.col = start;
+    }
+
+    /// Start of the word before the cursor, words are separated by whitespace.
+    fn prev_word_start(&self) -> usize {
+        let spaces = self.whitespace_map();
+        let mut col = self.col;
+
+        while col > 0 && spaces[col - 1] {
+            col -= 1;
+        }
+
+        while col > 0 && !spaces[col - 1] {
+            col -= 1;
         }
+
+        col
+    }
+
+    /// End of the word after the cursor, words are separated by whitespace.
+    fn next_word_end(&self) -> usize {
+        let spaces = self.whitespace_map();
+        let mut col = self.col;
+
+        while col < spaces.len() && spaces[col] {
+            col += 1;
+        }
+
+        while col < spaces.len() && !spaces[col] {
+            col += 1;
+        }
+
+        col
+    }
+
+    /// Whether each grapheme is whitespace.
+    fn whitespace_map(&self) -> Vec<bool> {
+        self.value
+            .graphemes(true)
+            .map(|g| g.chars().all(char::is_whitespace))
+            .collect()
+    }
+
+    /// `col` counts graphemes, not bytes
+    fn byte_index(&self, col: usize) -> usize {
+        self.value
+            .grapheme_indices(true)
+            .nth(col)
+            .map_or(self.value.len(), |(i, _)| i)
     }
 }
 
 // endregion: TextInput
 
-pub type InputValidator<'a> = dyn Fn(&str) -> Result<(), &'a str> + 'a;
+pub type InputValidator<'a> = dyn Fn(&str) -> Result<(), Cow<'a, str>> + 'a;
 type Formatter<'a> = dyn Fn(&Text, DrawTime) -> (String, [usize; 2]) + 'a;
+type Reporter<'a> = dyn Fn(&str) -> String + 'a;
 
 /// Prompt to get one-line user input.
 ///
 /// # Key Events
 ///
-/// | Key         | Action                       |
-/// | ----------- | ---------------------------- |
-/// | `Enter`     | Submit current/initial value |
-/// | `Backspace` | Delete previous character    |
-/// | `Delete`    | Delete current character     |
-/// | `Left`      | Move cursor left             |
-/// | `Right`     | Move cursor right            |
+/// | Key          | Action                       |
+/// | ------------ | ---------------------------- |
+/// | `Enter`      | Submit current/initial value |
+/// | `Backspace`  | Delete previous character    |
+/// | `Delete`     | Delete current character     |
+/// | `Left`       | Move cursor left             |
+/// | `Right`      | Move cursor right            |
+/// | `Home`       | Move cursor to the start     |
+/// | `End`        | Move cursor to the end       |
+/// | `Ctrl+Left`  | Move cursor to previous word |
+/// | `Ctrl+Right` | Move cursor to next word     |
+/// | `Up`, `Down` | Recall previous answers      |
+///
+/// The readline-like shortcuts are also available in all the one-line input prompts:
+///
+/// | Key              | Action                   |
+/// | ---------------- | ------------------------ |
+/// | `Ctrl+A`         | Move cursor to the start |
+/// | `Ctrl+E`         | Move cursor to the end   |
+/// | `Alt+B`, `Alt+F` | Move cursor by words     |
+/// | `Ctrl+W`         | Delete previous word     |
+/// | `Ctrl+U`         | Delete to the start      |
+/// | `Ctrl+K`         | Delete to the end        |
 ///
 /// # Examples
 ///
@@ -97,15 +233,35 @@ type Formatter<'a> = dyn Fn(&Text, DrawTime) -> (String, [usize; 2]) + 'a;
 pub struct Text<'a> {
     /// Message used to display in the prompt
     pub message: &'a str,
+    /// Stable identifier of the prompt.
+    pub name: Option<&'a str>,
+    /// Key of the answer, when it's recorded in [`Answers`](crate::Answers).
+    pub key: Option<&'a str>,
     /// Input state for the prompt
     pub input: LineInput,
     /// Placeholder to show when the input is empty
     pub placeholder: Option<&'a str>,
+    /// Non-editable text to show before the input.
+    pub prefix: Option<&'a str>,
+    /// Non-editable text to show after the input.
+    pub suffix: Option<&'a str>,
     /// Default value to submit when the input is empty
     pub default_value: Option<&'a str>,
     /// State of the validation of the user input
-    pub validator_result: Result<(), &'a str>,
+    pub validator_result: Result<(), Cow<'a, str>>,
+    /// Whether the validator runs again when the input is edited after an invalid submit.
+    pub validate_on_edit: bool,
+    /// Indicate if the input was edited after an invalid submit, when using `validate_on_edit`.
+    pub validator_edited: bool,
+    /// Whether the answer is masked once submitted, see [`Text::sensitive`].
+    pub sensitive: bool,
     validator: Option<Box<InputValidator<'a>>>,
+    history: Option<&'a mut History>,
+    /// Position of the recalled answer, from the newest one.
+    history_offset: Option<usize>,
+    /// Input before recalling the history, restored after the newest answer.
+    draft: String,
+    reporter: Option<Box<Reporter<'a>>>,
     formatter: Box<Formatter<'a>>,
 }
 
@@ -114,11 +270,22 @@ impl<'a> Text<'a> {
     pub fn new(message: &'a str) -> Self {
         Text {
             message,
+            name: None,
+            key: None,
             input: LineInput::new(),
             placeholder: None,
+            prefix: None,
+            suffix: None,
             default_value: None,
             validator: None,
             validator_result: Ok(()),
+            validate_on_edit: false,
+            validator_edited: false,
+            sensitive: false,
+            history: None,
+            history_offset: None,
+            draft: String::new(),
+            reporter: None,
             formatter: Box::new(theme::fmt_text),
         }
     }
@@ -131,6 +298,18 @@ impl<'a> Text<'a> {
         self
     }
 
+    /// Set non-editable text to show before the input, like `"$ "`.
+    pub fn prefix(&mut self, value: &'a str) -> &mut Self {
+        self.prefix = Some(value);
+        self
+    }
+
+    /// Set non-editable text to show after the input, like `" MB"`.
+    pub fn suffix(&mut self, value: &'a str) -> &mut Self {
+        self.suffix = Some(value);
+        self
+    }
+
     /// Set default value to submit when the input is empty.
     pub fn default(&mut self, value: &'a str) -> &mut Self {
         self.default_value = Some(value);
@@ -144,11 +323,45 @@ impl<'a> Text<'a> {
     }
 
     /// Set validator to the user input.
-    pub fn validate<F>(&mut self, validator: F) -> &mut Self
+    ///
+    /// The error message can be borrowed or owned, like `format!("got {} chars", value.len())`.
+    pub fn validate<F, E>(&mut self, validator: F) -> &mut Self
     where
-        F: Fn(&str) -> Result<(), &'a str> + 'a,
+        F: Fn(&str) -> Result<(), E> + 'a,
+        E: Into<Cow<'a, str>>,
     {
-        self.validator = Some(Box::new(validator));
+        self.validator = Some(Box::new(move |value| validator(value).map_err(Into::into)));
+        self
+    }
+
+    /// Set a stable identifier for the prompt, like `"db.host"`.
+    ///
+    /// It is available to custom formatters, which can use it to render per-field markers.
+    pub fn name(&mut self, name: &'a str) -> &mut Self {
+        self.name = Some(name);
+        self
+    }
+
+    /// Set the key of the answer, when it's recorded in [`Answers`](crate::Answers).
+    pub fn key(&mut self, key: &'a str) -> &mut Self {
+        self.key = Some(key);
+        self
+    }
+
+    /// Set whether the validator runs again when the input is edited after an invalid submit.
+    ///
+    /// The error is kept visible (dimmed) while the user fixes the input,
+    /// and it's cleared as soon as the input is valid.
+    pub fn validate_on_edit(&mut self, validate_on_edit: bool) -> &mut Self {
+        self.validate_on_edit = validate_on_edit;
+        self
+    }
+
+    /// Set the history of answers, recalled with `Up` and `Down`.
+    ///
+    /// The submitted answer is added to the history.
+    pub fn history(&mut self, history: &'a mut History) -> &mut Self {
+        self.history = Some(history);
         self
     }
 
@@ -163,14 +376,49 @@ impl<'a> Text<'a> {
         self
     }
 
+    /// Set a closure to display the submitted answer in the final line, like a masked email.
+    ///
+    /// It receives the submitted text, and its result replaces the answer and its adornments,
+    /// the rest of the line is still displayed by the formatter.
+    pub fn report_with<F>(&mut self, reporter: F) -> &mut Self
+    where
+        F: Fn(&str) -> String + 'a,
+    {
+        self.reporter = Some(Box::new(reporter));
+        self
+    }
+
+    /// Mask the answer once submitted, like an URL with an embedded token.
+    ///
+    /// The input is displayed while editing, but the final line, the state sent to the observer
+    /// and the recorded keys don't include it. The `report_with` closure is still used, if set.
+    ///
+    /// The answer is not added to the history, and it's recorded as [`Answer::Secret`].
+    pub fn sensitive(&mut self) -> &mut Self {
+        self.sensitive = true;
+        self
+    }
+
     /// Display the prompt and return the user answer.
-    pub fn prompt(&mut self) -> io::Result<String> {
+    pub fn prompt(&mut self) -> Result<String, Error> {
         key_listener::listen(self, false)?;
-        Ok(self.get_value().to_owned())
+        self.answer()
+    }
+
+    /// Async version of [`Text::prompt`].
+    #[cfg(feature = "async")]
+    pub async fn prompt_async(&mut self) -> Result<String, Error> {
+        key_listener::listen_async(self, false).await?;
+        self.answer()
     }
 }
 
 impl Text<'_> {
+    /// Returns the answer to display in the final line, when using `report_with`.
+    pub(crate) fn report(&self, answer: &str) -> Option<String> {
+        self.reporter.as_ref().map(|reporter| reporter(answer))
+    }
+
     fn get_value(&self) -> &str {
         match self.input.value.is_empty() {
             true => self.default_value.unwrap_or_default(),
@@ -178,20 +426,105 @@ impl Text<'_> {
         }
     }
 
+    /// Returns the submitted value, adding it to the history.
+    fn answer(&mut self) -> Result<String, Error> {
+        let value = self.get_value().to_owned();
+
+        self.history_offset = None;
+        self.draft.clear();
+
+        if let Some(history) = self.history.as_deref_mut().filter(|_| !self.sensitive) {
+            history.push(&value)?;
+        }
+
+        Ok(value)
+    }
+
+    /// Replace the input with an older or newer answer of the history.
+    fn recall(&mut self, older: bool) {
+        let Some(history) = self.history.as_deref() else {
+            return;
+        };
+
+        let offset = match (self.history_offset, older) {
+            (None, true) if !history.is_empty() => Some(0),
+            (Some(n), true) if n + 1 < history.len() => Some(n + 1),
+            (Some(0), false) => None,
+            (Some(n), false) => Some(n - 1),
+            _ => return,
+        };
+
+        let value = match offset {
+            Some(n) => history.recall(n).unwrap_or_default().to_owned(),
+            None => mem::take(&mut self.draft),
+        };
+
+        if self.history_offset.is_none() {
+            self.draft = mem::take(&mut self.input.value);
+        }
+
+        self.history_offset = offset;
+        self.input.set_value(&value);
+    }
+
     fn validate_to_submit(&mut self) -> bool {
+        self.validator_edited = false;
+
         if let Some(validator) = &self.validator {
             self.validator_result = validator(self.get_value());
         }
 
         self.validator_result.is_ok()
     }
+
+    /// Validate the initial value, if any, so an invalid one is shown on the first draw.
+    fn validate_initial(&mut self) {
+        if !self.input.value.is_empty() {
+            self.validate_to_submit();
+        }
+    }
+
+    /// Validate again after an edit, only if the last submit was invalid.
+    fn validate_edited(&mut self) {
+        if !self.validate_on_edit || self.validator_result.is_ok() {
+            return;
+        }
+
+        self.validate_to_submit();
+        self.validator_edited = self.validator_result.is_err();
+    }
+}
+
+impl Recordable for Text<'_> {
+    type Output = String;
+
+    fn record_key(&self) -> Option<&str> {
+        self.key
+    }
+
+    fn ask(&mut self) -> Result<String, Error> {
+        self.prompt()
+    }
+
+    fn submitted_answer(&self) -> Answer {
+        match self.sensitive {
+            true => Answer::Secret,
+            false => self.get_value().into(),
+        }
+    }
 }
 
 impl Typeable for Text<'_> {
     fn handle_key(&mut self, key: KeyEvent) -> bool {
         let mut submit = false;
+        let edited = matches!(
+            key.code,
+            KeyCode::Char(_) | KeyCode::Backspace | KeyCode::Delete
+        );
 
         match key.code {
+            // editing shortcuts
+            _ if self.input.handle_shortcut(key) => (),
             // submit
             KeyCode::Enter => submit = self.validate_to_submit(),
             // type
@@ -202,11 +535,59 @@ impl Typeable for Text<'_> {
             // move cursor
             KeyCode::Left => self.input.move_cursor(Direction::Left),
             KeyCode::Right => self.input.move_cursor(Direction::Right),
+            // history
+            KeyCode::Up => self.recall(true),
+            KeyCode::Down => self.recall(false),
             _ => (),
         };
 
+        if edited {
+            self.validate_edited();
+        }
+
         submit
     }
+
+    fn answer_key(&self) -> Option<&str> {
+        self.key.or(self.name)
+    }
+
+    fn is_sensitive(&self) -> bool {
+        self.sensitive
+    }
+
+    fn seed(&mut self, value: &str) -> bool {
+        self.input.set_value(value);
+        self.validate_to_submit()
+    }
+
+    fn prepare(&mut self) {
+        self.validate_initial();
+    }
+}
+
+impl Composable for Text<'_> {
+    type Output = String;
+
+    fn frame(&self, draw_time: DrawTime) -> (String, [usize; 2]) {
+        (self.formatter)(self, draw_time)
+    }
+
+    fn output(&mut self) -> String {
+        self.get_value().to_owned()
+    }
+}
+
+impl Text<'_> {
+    /// Returns a snapshot of the prompt state, see [`set_state_observer`](crate::set_state_observer).
+    pub fn state(&self) -> PromptState {
+        let value = match self.sensitive {
+            true => "*".repeat(self.input.len()),
+            false => self.input.value.clone(),
+        };
+
+        PromptState::new(self.message, self.name, value).with_error(&self.validator_result)
+    }
 }
 
 impl Printable for Text<'_> {
@@ -215,11 +596,16 @@ impl Printable for Text<'_> {
         renderer.print(text)?;
         renderer.set_cursor(cursor)
     }
+
+    fn snapshot(&self) -> Option<PromptState> {
+        Some(self.state())
+    }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::{Answers, Theme};
 
     #[test]
     fn set_placeholder() {
@@ -229,6 +615,29 @@ mod tests {
         assert_eq!(text.placeholder, Some("foo"));
     }
 
+    #[test]
+    fn set_adornments() {
+        let mut prompt = Text::new("");
+
+        prompt.prefix("$ ").suffix(" MB");
+
+        assert_eq!(prompt.prefix, Some("$ "));
+        assert_eq!(prompt.suffix, Some(" MB"));
+    }
+
+    #[test]
+    fn place_cursor_after_wide_prefix() {
+        let input = Theme::DEFAULT.glyphs().input;
+        let mut prompt = Text::new("Price");
+
+        prompt.prefix("¥ 円 ").suffix(" 税込").initial("日本");
+        prompt.handle_key(KeyEvent::from(KeyCode::Left));
+
+        // icon + space + prefix + input before the cursor
+        let (_, cursor) = (prompt.formatter)(&prompt, DrawTime::Update);
+        assert_eq!(cursor, [format!("{} ¥ 円 日", input).width(), 1]);
+    }
+
     #[test]
     fn set_default_value() {
         let mut text = Text::new("");
@@ -254,6 +663,15 @@ mod tests {
         );
     }
 
+    #[test]
+    fn set_name() {
+        let mut prompt = Text::new("");
+
+        assert_eq!(prompt.name, None);
+        prompt.name("foo");
+        assert_eq!(prompt.name, Some("foo"));
+    }
+
     #[test]
     fn set_custom_formatter() {
         let mut prompt: Text = Text::new("");
@@ -268,6 +686,169 @@ mod tests {
         );
     }
 
+    #[test]
+    fn format_final_states() {
+        let mut prompt = Text::new("Name");
+        let glyphs = Theme::DEFAULT.glyphs();
+
+        prompt.default("foo");
+
+        let (text, _) = (prompt.formatter)(&prompt, DrawTime::Last);
+        assert!(text.contains(glyphs.answered) && text.contains("foo"));
+
+        let (text, _) = (prompt.formatter)(&prompt, DrawTime::Cancelled);
+        assert!(text.contains(glyphs.cancelled) && !text.contains("foo"));
+    }
+
+    #[test]
+    fn report_answer() {
+        let mut prompt = Text::new("Email");
+
+        prompt
+            .initial("user@example.com")
+            .report_with(|value| value.replacen("user", "u***", 1));
+
+        let (text, _) = (prompt.formatter)(&prompt, DrawTime::Last);
+        assert!(text.contains("u***@example.com") && !text.contains("user"));
+    }
+
+    #[test]
+    fn mask_sensitive_answer() {
+        let mut prompt = Text::new("Endpoint");
+        prompt.initial("https://token@example.com").sensitive();
+
+        let (text, _) = (prompt.formatter)(&prompt, DrawTime::Update);
+        assert!(text.contains("token"));
+
+        let (text, _) = (prompt.formatter)(&prompt, DrawTime::Last);
+        assert!(!text.contains("token"));
+        assert_eq!(prompt.state().value, "*".repeat(25));
+        assert!(prompt.is_sensitive());
+    }
+
+    #[test]
+    fn keep_sensitive_answer_out() {
+        let mut history = History::new(10);
+        let mut answers = Answers::new();
+        let mut prompt = Text::new("Token");
+
+        prompt
+            .history(&mut history)
+            .key("token")
+            .initial("hunter2")
+            .sensitive();
+
+        assert_eq!(prompt.answer().unwrap(), "hunter2");
+        answers.record(&prompt);
+
+        drop(prompt);
+        assert!(history.is_empty());
+        assert_eq!(answers.get("token"), Some(&Answer::Secret));
+    }
+
+    #[test]
+    fn edit_with_shortcuts() {
+        let mut prompt = Text::new("");
+        let ctrl = |c| KeyEvent::new(KeyCode::Char(c), KeyModifiers::CONTROL);
+        let alt = |c| KeyEvent::new(KeyCode::Char(c), KeyModifiers::ALT);
+
+        prompt.initial("foo bar  baz");
+
+        prompt.handle_key(alt('b'));
+        assert_eq!(prompt.input.col, 9);
+        prompt.handle_key(alt('b'));
+        assert_eq!(prompt.input.col, 4);
+        prompt.handle_key(alt('f'));
+        assert_eq!(prompt.input.col, 7);
+
+        prompt.handle_key(ctrl('w'));
+        assert_eq!(
+            (prompt.input.value.as_str(), prompt.input.col),
+            ("foo   baz", 4)
+        );
+
+        prompt.handle_key(ctrl('k'));
+        assert_eq!(prompt.input.value, "foo ");
+        prompt.handle_key(ctrl('a'));
+        assert_eq!(prompt.input.col, 0);
+        prompt.handle_key(ctrl('e'));
+        prompt.handle_key(ctrl('u'));
+        assert_eq!((prompt.input.value.as_str(), prompt.input.col), ("", 0));
+    }
+
+    #[test]
+    fn move_cursor_by_boundaries() {
+        let mut prompt = Text::new("");
+        let ctrl = |code| KeyEvent::new(code, KeyModifiers::CONTROL);
+
+        prompt.initial("foo bar");
+
+        prompt.handle_key(KeyEvent::from(KeyCode::Home));
+        assert_eq!(prompt.input.col, 0);
+        prompt.handle_key(ctrl(KeyCode::Right));
+        assert_eq!(prompt.input.col, 3);
+        prompt.handle_key(ctrl(KeyCode::Right));
+        assert_eq!(prompt.input.col, 7);
+        prompt.handle_key(ctrl(KeyCode::Left));
+        assert_eq!(prompt.input.col, 4);
+        prompt.handle_key(KeyEvent::from(KeyCode::End));
+        assert_eq!(prompt.input.col, 7);
+    }
+
+    #[test]
+    fn recall_history() {
+        let mut history = History::new(10);
+        history.push("foo").unwrap();
+        history.push("bar").unwrap();
+
+        let mut prompt = Text::new("");
+        prompt.history(&mut history).initial("draft");
+
+        let mut press = |code| {
+            prompt.handle_key(KeyEvent::from(code));
+            prompt.input.value.clone()
+        };
+
+        assert_eq!(press(KeyCode::Up), "bar");
+        assert_eq!(press(KeyCode::Up), "foo");
+        assert_eq!(press(KeyCode::Up), "foo");
+        assert_eq!(press(KeyCode::Down), "bar");
+        assert_eq!(press(KeyCode::Down), "draft");
+        assert_eq!(press(KeyCode::Down), "draft");
+
+        press(KeyCode::Up);
+        assert_eq!(prompt.answer().unwrap(), "bar");
+        prompt.input.set_value("baz");
+        prompt.answer().unwrap();
+
+        drop(prompt);
+        assert_eq!(history.entries().collect::<Vec<_>>(), ["foo", "bar", "baz"]);
+    }
+
+    #[test]
+    fn edit_graphemes() {
+        let mut prompt = Text::new("");
+
+        prompt.initial("日本e\u{301}");
+        assert_eq!((prompt.input.col, prompt.input.cursor_width()), (3, 5));
+
+        prompt.handle_key(KeyEvent::from(KeyCode::Backspace));
+        assert_eq!(prompt.input.value, "日本");
+
+        prompt.handle_key(KeyEvent::from(KeyCode::Left));
+        assert_eq!(prompt.input.cursor_width(), 2);
+
+        for c in ['e', '\u{301}'] {
+            prompt.handle_key(KeyEvent::from(KeyCode::Char(c)));
+        }
+
+        assert_eq!(prompt.input.value, "日e\u{301}本");
+        assert_eq!((prompt.input.col, prompt.input.cursor_width()), (2, 3));
+
+        prompt.handle_key(KeyEvent::from(KeyCode::Delete));
+        assert_eq!(prompt.input.value, "日e\u{301}");
+    }
+
     #[test]
     fn update_value() {
         let mut prompt = Text::new("");
@@ -314,13 +895,13 @@ mod tests {
         let mut prompt = Text::new("");
         let err_str = "Please enter an response";
 
-        prompt.validate(|s| if s.is_empty() { Err(err_str) } else { Ok(()) });
+        prompt.validate(move |s| if s.is_empty() { Err(err_str) } else { Ok(()) });
 
         // invalid value
         let mut submit = prompt.handle_key(KeyEvent::from(KeyCode::Enter));
 
         assert!(!submit);
-        assert_eq!(prompt.validator_result, Err(err_str));
+        assert_eq!(prompt.validator_result, Err(err_str.into()));
 
         // valid value
         prompt.input.set_value("foo");
@@ -330,6 +911,70 @@ mod tests {
         assert_eq!(prompt.validator_result, Ok(()));
     }
 
+    #[test]
+    fn validate_with_owned_message() {
+        let mut prompt = Text::new("");
+
+        prompt.initial("foo");
+        prompt.validate(|s| match s.len() {
+            0..=7 => Err(format!("must be at least 8 chars, got {}", s.len())),
+            _ => Ok(()),
+        });
+
+        assert!(!prompt.handle_key(KeyEvent::from(KeyCode::Enter)));
+        assert_eq!(
+            prompt.validator_result,
+            Err("must be at least 8 chars, got 3".into())
+        );
+    }
+
+    #[test]
+    fn validate_initial_value() {
+        let mut prompt = Text::new("");
+        let err_str = "Too short";
+
+        prompt.initial("ab");
+        prompt.validate(move |s| if s.len() < 3 { Err(err_str) } else { Ok(()) });
+        prompt.validate_initial();
+
+        assert_eq!(prompt.validator_result, Err(err_str.into()));
+
+        // empty inputs are not validated until submit
+        let mut prompt = Text::new("");
+
+        prompt.validate(|_| Err(err_str));
+        prompt.validate_initial();
+
+        assert_eq!(prompt.validator_result, Ok(()));
+    }
+
+    #[test]
+    fn validate_on_edit() {
+        let mut prompt = Text::new("");
+        let err_str = "Too short";
+
+        prompt.validate(move |s| if s.len() < 3 { Err(err_str) } else { Ok(()) });
+
+        // not validated before the first submit
+        prompt.validate_on_edit(true);
+        prompt.handle_key(KeyEvent::from(KeyCode::Char('a')));
+        assert_eq!(prompt.validator_result, Ok(()));
+
+        prompt.handle_key(KeyEvent::from(KeyCode::Enter));
+        assert_eq!(prompt.validator_result, Err(err_str.into()));
+        assert!(!prompt.validator_edited);
+
+        // keep the error while it's invalid
+        prompt.handle_key(KeyEvent::from(KeyCode::Char('b')));
+        assert_eq!(prompt.validator_result, Err(err_str.into()));
+        assert!(prompt.validator_edited);
+
+        // clear the error once it's valid
+        prompt.handle_key(KeyEvent::from(KeyCode::Char('c')));
+        assert_eq!(prompt.validator_result, Ok(()));
+        assert!(!prompt.validator_edited);
+    }
+
     #[test]
     fn submit_input_value() {
         let mut prompt = Text::new("");
@@ -347,4 +992,34 @@ mod tests {
 
         assert_eq!(prompt.get_value(), "bar");
     }
+
+    mod proptests {
+        use super::*;
+        use proptest::prelude::*;
+
+        fn key_code() -> impl Strategy<Value = KeyCode> {
+            prop_oneof![
+                any::<char>().prop_map(KeyCode::Char),
+                Just(KeyCode::Backspace),
+                Just(KeyCode::Delete),
+                Just(KeyCode::Left),
+                Just(KeyCode::Right),
+            ]
+        }
+
+        proptest! {
+            #[test]
+            fn handle_random_keys(initial in ".*", keys in prop::collection::vec(key_code(), 0..64)) {
+                let mut prompt = Text::new("");
+                prompt.initial(&initial);
+
+                for key in keys {
+                    prompt.handle_key(KeyEvent::from(key));
+                    prop_assert!(prompt.input.col <= prompt.input.value.chars().count());
+                }
+
+                (prompt.formatter)(&prompt, DrawTime::Update);
+            }
+        }
+    }
 }
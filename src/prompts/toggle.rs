@@ -3,8 +3,11 @@ use std::io;
 use crossterm::event::{KeyCode, KeyEvent};
 
 use crate::utils::{
+    answer::{Answer, Recordable},
+    error::Error,
     key_listener::{self, Typeable},
     renderer::{DrawTime, Printable, Renderer},
+    state::PromptState,
     theme,
 };
 
@@ -35,10 +38,20 @@ type Formatter<'a> = dyn Fn(&Toggle, DrawTime) -> String + 'a;
 pub struct Toggle<'a> {
     /// Message used to display in the prompt.
     pub message: &'a str,
+    /// Stable identifier of the prompt.
+    pub name: Option<&'a str>,
+    /// Key of the answer, when it's recorded in [`Answers`](crate::Answers).
+    pub key: Option<&'a str>,
     /// Options to display in the prompt.
     pub options: [&'a str; 2],
     /// Current state of the prompt.
     pub active: bool,
+    /// Initial state of the prompt, to indicate which option is the default one.
+    pub initial_active: bool,
+    /// Correct answer, to display if the user was right after submitting.
+    pub correct: Option<bool>,
+    /// Explanation to display after submitting, when the correct answer is set.
+    pub explanation: Option<&'a str>,
     formatter: Box<Formatter<'a>>,
 }
 
@@ -47,8 +60,13 @@ impl<'a> Toggle<'a> {
     pub fn new(message: &'a str, options: [&'a str; 2]) -> Self {
         Toggle {
             message,
+            name: None,
+            key: None,
             options,
             active: false,
+            initial_active: false,
+            correct: None,
+            explanation: None,
             formatter: Box::new(theme::fmt_toggle),
         }
     }
@@ -56,6 +74,33 @@ impl<'a> Toggle<'a> {
     /// Set whether the prompt should be active at start.
     pub fn initial(&mut self, value: bool) -> &mut Self {
         self.active = value;
+        self.initial_active = value;
+        self
+    }
+
+    /// Set the correct answer, to display if the user was right after submitting.
+    pub fn correct(&mut self, active: bool) -> &mut Self {
+        self.correct = Some(active);
+        self
+    }
+
+    /// Set an explanation to display after submitting, when the correct answer is set.
+    pub fn explanation(&mut self, explanation: &'a str) -> &mut Self {
+        self.explanation = Some(explanation);
+        self
+    }
+
+    /// Set a stable identifier for the prompt, like `"db.host"`.
+    ///
+    /// It is available to custom formatters, which can use it to render per-field markers.
+    pub fn name(&mut self, name: &'a str) -> &mut Self {
+        self.name = Some(name);
+        self
+    }
+
+    /// Set the key of the answer, when it's recorded in [`Answers`](crate::Answers).
+    pub fn key(&mut self, key: &'a str) -> &mut Self {
+        self.key = Some(key);
         self
     }
 
@@ -71,23 +116,53 @@ impl<'a> Toggle<'a> {
     }
 
     /// Display the prompt and return the user answer.
-    pub fn prompt(&mut self) -> io::Result<String> {
+    pub fn prompt(&mut self) -> Result<String, Error> {
         key_listener::listen(self, true)?;
-        Ok(String::from(self.get_value()))
+        let value = String::from(self.get_value());
+        Ok(value)
+    }
+
+    /// Async version of [`Toggle::prompt`].
+    #[cfg(feature = "async")]
+    pub async fn prompt_async(&mut self) -> Result<String, Error> {
+        key_listener::listen_async(self, true).await?;
+        let value = String::from(self.get_value());
+        Ok(value)
     }
 }
 
 impl Toggle<'_> {
+    /// Returns whether the current answer is the correct one, if it was set.
+    pub fn is_correct(&self) -> Option<bool> {
+        self.correct.map(|correct| correct == self.active)
+    }
+
     fn get_value(&self) -> &str {
         self.options[self.active as usize]
     }
 }
 
+impl Recordable for Toggle<'_> {
+    type Output = String;
+
+    fn record_key(&self) -> Option<&str> {
+        self.key
+    }
+
+    fn ask(&mut self) -> Result<String, Error> {
+        self.prompt()
+    }
+
+    fn submitted_answer(&self) -> Answer {
+        self.get_value().into()
+    }
+}
+
 impl Typeable for Toggle<'_> {
     fn handle_key(&mut self, key: KeyEvent) -> bool {
         let mut submit = false;
 
-        match key.code {
+        match key_listener::navigation_key(key.code) {
             // submit focused/initial option
             KeyCode::Enter | KeyCode::Backspace => submit = true,
             // update focus option
@@ -98,6 +173,34 @@ impl Typeable for Toggle<'_> {
 
         submit
     }
+
+    fn answer_key(&self) -> Option<&str> {
+        self.key.or(self.name)
+    }
+
+    fn seed(&mut self, value: &str) -> bool {
+        match self
+            .options
+            .iter()
+            .position(|option| *option == value.trim())
+        {
+            Some(index) => {
+                self.active = index == 1;
+                true
+            }
+            None => false,
+        }
+    }
+}
+
+impl Toggle<'_> {
+    /// Returns a snapshot of the prompt state, see [`set_state_observer`](crate::set_state_observer).
+    pub fn state(&self) -> PromptState {
+        let focused = self.active as usize;
+
+        PromptState::new(self.message, self.name, self.options[focused])
+            .with_titles(&self.options, focused)
+    }
 }
 
 impl Printable for Toggle<'_> {
@@ -105,6 +208,10 @@ impl Printable for Toggle<'_> {
         let text = (self.formatter)(self, renderer.draw_time);
         renderer.print(text)
     }
+
+    fn snapshot(&self) -> Option<PromptState> {
+        Some(self.state())
+    }
 }
 
 #[cfg(test)]
@@ -121,6 +228,26 @@ mod tests {
         assert_eq!(prompt.get_value(), "bar");
     }
 
+    #[test]
+    fn keep_initial_value() {
+        let mut prompt = Toggle::new("", ["foo", "bar"]);
+
+        prompt.initial(true);
+        prompt.handle_key(KeyEvent::from(KeyCode::Left));
+
+        assert!(!prompt.active);
+        assert!(prompt.initial_active);
+    }
+
+    #[test]
+    fn set_name() {
+        let mut prompt = Toggle::new("", ["foo", "bar"]);
+
+        assert_eq!(prompt.name, None);
+        prompt.name("foo");
+        assert_eq!(prompt.name, Some("foo"));
+    }
+
     #[test]
     fn set_custom_formatter() {
         let mut prompt = Toggle::new("", ["foo", "bar"]);
@@ -0,0 +1,284 @@
+use std::io;
+
+use crossterm::event::{KeyCode, KeyEvent};
+
+use crate::utils::{
+    answer::{Answer, Recordable},
+    error::Error,
+    key_listener::{self, Typeable},
+    renderer::{DrawTime, Printable, Renderer},
+    state::PromptState,
+    theme,
+};
+
+use super::text::{Direction, LineInput};
+
+type Formatter<'a> = dyn Fn(&TypeToConfirm, DrawTime) -> (String, [usize; 2]) + 'a;
+
+/// Prompt to confirm a dangerous operation by typing an expected text, like a resource name.
+///
+/// # Key Events
+///
+/// | Key         | Action                    |
+/// | ----------- | ------------------------- |
+/// | `Enter`     | Submit current value      |
+/// | `Backspace` | Delete previous character |
+/// | `Delete`    | Delete current character  |
+/// | `Left`      | Move cursor left          |
+/// | `Right`     | Move cursor right         |
+///
+/// Also the `Home`, `End`, word-jump and readline-like shortcuts of [`Text`](crate::Text), like `Ctrl+U`.
+///
+/// # Examples
+///
+/// ```no_run
+/// use asky::TypeToConfirm;
+///
+/// # fn main() -> std::io::Result<()> {
+/// if TypeToConfirm::new("Type 'prod-db' to delete it", "prod-db").prompt()? {
+///     println!("Deleting prod-db...");
+/// }
+/// # Ok(())
+/// # }
+/// ```
+pub struct TypeToConfirm<'a> {
+    /// Message used to display in the prompt.
+    pub message: &'a str,
+    /// Stable identifier of the prompt.
+    pub name: Option<&'a str>,
+    /// Key of the answer, when it's recorded in [`Answers`](crate::Answers).
+    pub key: Option<&'a str>,
+    /// Text that must be typed to confirm.
+    pub expected: &'a str,
+    /// Input state for the prompt.
+    pub input: LineInput,
+    /// Whether the comparison ignores the case.
+    pub ignore_case: bool,
+    formatter: Box<Formatter<'a>>,
+}
+
+impl<'a> TypeToConfirm<'a> {
+    /// Create a new type-to-confirm prompt.
+    pub fn new(message: &'a str, expected: &'a str) -> Self {
+        TypeToConfirm {
+            message,
+            name: None,
+            key: None,
+            expected,
+            input: LineInput::new(),
+            ignore_case: false,
+            formatter: Box::new(theme::fmt_type_to_confirm),
+        }
+    }
+
+    /// Set whether the comparison ignores the case.
+    pub fn ignore_case(&mut self, ignore_case: bool) -> &mut Self {
+        self.ignore_case = ignore_case;
+        self
+    }
+
+    /// Set a stable identifier for the prompt, like `"db.host"`.
+    ///
+    /// It is available to custom formatters, which can use it to render per-field markers.
+    pub fn name(&mut self, name: &'a str) -> &mut Self {
+        self.name = Some(name);
+        self
+    }
+
+    /// Set the key of the answer, when it's recorded in [`Answers`](crate::Answers).
+    pub fn key(&mut self, key: &'a str) -> &mut Self {
+        self.key = Some(key);
+        self
+    }
+
+    /// Set custom closure to format the prompt.
+    ///
+    /// See: [`Customization`](index.html#customization).
+    pub fn format<F>(&mut self, formatter: F) -> &mut Self
+    where
+        F: Fn(&TypeToConfirm, DrawTime) -> (String, [usize; 2]) + 'a,
+    {
+        self.formatter = Box::new(formatter);
+        self
+    }
+
+    /// Display the prompt and return whether the user typed the expected text.
+    pub fn prompt(&mut self) -> Result<bool, Error> {
+        key_listener::listen(self, false)?;
+        let is_match = self.is_match();
+        Ok(is_match)
+    }
+
+    /// Async version of [`TypeToConfirm::prompt`].
+    #[cfg(feature = "async")]
+    pub async fn prompt_async(&mut self) -> Result<bool, Error> {
+        key_listener::listen_async(self, false).await?;
+        let is_match = self.is_match();
+        Ok(is_match)
+    }
+}
+
+impl TypeToConfirm<'_> {
+    fn eq_char(&self, a: char, b: char) -> bool {
+        match self.ignore_case {
+            true => a.to_lowercase().eq(b.to_lowercase()),
+            false => a == b,
+        }
+    }
+
+    /// Returns whether the input is the expected text.
+    pub fn is_match(&self) -> bool {
+        self.input.value.chars().count() == self.expected.chars().count()
+            && self.matched_len() == self.expected.chars().count()
+    }
+
+    /// Returns the number of leading characters of the input that match the expected text.
+    pub fn matched_len(&self) -> usize {
+        self.input
+            .value
+            .chars()
+            .zip(self.expected.chars())
+            .take_while(|(a, b)| self.eq_char(*a, *b))
+            .count()
+    }
+}
+
+impl Recordable for TypeToConfirm<'_> {
+    type Output = bool;
+
+    fn record_key(&self) -> Option<&str> {
+        self.key
+    }
+
+    fn ask(&mut self) -> Result<bool, Error> {
+        self.prompt()
+    }
+
+    fn submitted_answer(&self) -> Answer {
+        self.is_match().into()
+    }
+}
+
+impl Typeable for TypeToConfirm<'_> {
+    fn handle_key(&mut self, key: KeyEvent) -> bool {
+        let mut submit = false;
+
+        match key.code {
+            // editing shortcuts
+            _ if self.input.handle_shortcut(key) => (),
+            // submit
+            KeyCode::Enter => submit = true,
+            // type
+            KeyCode::Char(c) => self.input.insert(c),
+            // remove delete
+            KeyCode::Backspace => self.input.backspace(),
+            KeyCode::Delete => self.input.delete(),
+            // move cursor
+            KeyCode::Left => self.input.move_cursor(Direction::Left),
+            KeyCode::Right => self.input.move_cursor(Direction::Right),
+            _ => (),
+        };
+
+        submit
+    }
+
+    fn answer_key(&self) -> Option<&str> {
+        self.key.or(self.name)
+    }
+
+    fn seed(&mut self, value: &str) -> bool {
+        self.input.set_value(value);
+        true
+    }
+}
+
+impl TypeToConfirm<'_> {
+    /// Returns a snapshot of the prompt state, see [`set_state_observer`](crate::set_state_observer).
+    pub fn state(&self) -> PromptState {
+        PromptState::new(self.message, self.name, &self.input.value)
+    }
+}
+
+impl Printable for TypeToConfirm<'_> {
+    fn draw(&self, renderer: &mut Renderer) -> io::Result<()> {
+        let (text, cursor) = (self.formatter)(self, renderer.draw_time);
+        renderer.print(text)?;
+        renderer.set_cursor(cursor)
+    }
+
+    fn snapshot(&self) -> Option<PromptState> {
+        Some(self.state())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn type_text(prompt: &mut TypeToConfirm, text: &str) {
+        for c in text.chars() {
+            prompt.handle_key(KeyEvent::from(KeyCode::Char(c)));
+        }
+    }
+
+    #[test]
+    fn set_name() {
+        let mut prompt = TypeToConfirm::new("", "foo");
+
+        assert_eq!(prompt.name, None);
+        prompt.name("foo");
+        assert_eq!(prompt.name, Some("foo"));
+    }
+
+    #[test]
+    fn set_custom_formatter() {
+        let mut prompt = TypeToConfirm::new("", "foo");
+        let draw_time = DrawTime::First;
+        const EXPECTED_VALUE: &str = "foo";
+        const EXPECTED_CURSOR: [usize; 2] = [0, 0];
+
+        prompt.format(|_, _| (String::from(EXPECTED_VALUE), EXPECTED_CURSOR));
+
+        assert_eq!(
+            (prompt.formatter)(&prompt, draw_time),
+            (String::from(EXPECTED_VALUE), EXPECTED_CURSOR)
+        );
+    }
+
+    #[test]
+    fn match_expected_text() {
+        let mut prompt = TypeToConfirm::new("", "prod-db");
+
+        type_text(&mut prompt, "prod-x");
+        assert_eq!(prompt.matched_len(), 5);
+        assert!(!prompt.is_match());
+
+        prompt.handle_key(KeyEvent::from(KeyCode::Backspace));
+        type_text(&mut prompt, "db");
+        assert!(prompt.is_match());
+
+        type_text(&mut prompt, "2");
+        assert!(!prompt.is_match());
+    }
+
+    #[test]
+    fn match_ignoring_case() {
+        let mut prompt = TypeToConfirm::new("", "Prod-DB");
+
+        type_text(&mut prompt, "prod-db");
+        assert!(!prompt.is_match());
+
+        prompt.ignore_case(true);
+        assert!(prompt.is_match());
+    }
+
+    #[test]
+    fn submit_any_value() {
+        let mut prompt = TypeToConfirm::new("", "foo");
+
+        type_text(&mut prompt, "bar");
+
+        assert!(prompt.handle_key(KeyEvent::from(KeyCode::Enter)));
+        assert!(!prompt.is_match());
+    }
+}
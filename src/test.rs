@@ -0,0 +1,328 @@
+//! Helpers to test prompts without a real terminal.
+//!
+//! A [`Session`] feeds a script of key events into a prompt, like the user typing them,
+//! and captures each frame drawn in a virtual terminal, with the colors and attributes
+//! of the text to snapshot custom formatters, see [`Frame::styled_text`].
+//!
+//! # Examples
+//!
+//! ```
+//! use asky::{test::Session, Text};
+//! use crossterm::event::KeyCode;
+//!
+//! # fn main() -> Result<(), asky::Error> {
+//! let mut prompt = Text::new("Name");
+//! let mut session = Session::new(40, 10);
+//!
+//! let submitted = session.text("foo").key(KeyCode::Enter).run(&mut prompt)?;
+//!
+//! assert!(submitted);
+//! assert_eq!(prompt.input.value, "foo");
+//! assert!(session.last_frame().unwrap().text().contains("foo"));
+//! # Ok(())
+//! # }
+//! ```
+
+use std::{cell::RefCell, io};
+
+use crossterm::event::{Event, KeyCode, KeyEvent};
+
+use crate::utils::{
+    error::Error,
+    key_listener::{self, Typeable},
+    renderer::{DrawTime, Printable, Renderer},
+    state::PromptState,
+    virtual_terminal::VirtualTerminal,
+};
+
+pub use crate::utils::virtual_terminal::{Color, Span, Style};
+
+/// Screen of the virtual terminal after a prompt was drawn.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Frame {
+    /// Draw time of the prompt when the frame was drawn.
+    pub draw_time: DrawTime,
+    /// Rows of the screen, without trailing spaces and empty rows.
+    pub lines: Vec<String>,
+    /// Rows of the screen split by their style, the same ones of `lines`.
+    pub styled_lines: Vec<Vec<Span>>,
+    /// `[x, y]` position of the cursor.
+    pub cursor: [usize; 2],
+}
+
+impl Frame {
+    /// Returns the rows of the screen joined by newlines.
+    pub fn text(&self) -> String {
+        self.lines.join("\n")
+    }
+
+    /// Returns the rows of the screen joined by newlines, marking the styled text.
+    ///
+    /// Each styled span is wrapped in `{style}` and `{/}`, like `{fg:red,bold}Error{/} foo`.
+    /// The default formatters use [`colored`](https://docs.rs/colored), that disables the colors
+    /// when stdout is not a terminal, like in the tests, unless `colored::control::set_override`
+    /// is used.
+    pub fn styled_text(&self) -> String {
+        let lines: Vec<String> = self
+            .styled_lines
+            .iter()
+            .map(|spans| {
+                spans
+                    .iter()
+                    .map(|span| match span.style.is_plain() {
+                        true => span.text.clone(),
+                        false => format!("{{{}}}{}{{/}}", span.style, span.text),
+                    })
+                    .collect()
+            })
+            .collect();
+
+        lines.join("\n")
+    }
+}
+
+/// Script of key events to run a prompt in a virtual terminal.
+///
+/// The keys are handled like in [`Text::prompt`](crate::Text::prompt), with the keybindings
+/// of [`AskySettings`](crate::AskySettings), but [`AskySettings::exit_on_cancel`] is ignored
+/// to not end the tests. The terminal starts empty for each run, and the answer sources
+/// are not consulted.
+///
+/// [`AskySettings::exit_on_cancel`]: crate::AskySettings::exit_on_cancel
+#[derive(Debug, Clone)]
+pub struct Session {
+    width: usize,
+    height: usize,
+    events: Vec<Event>,
+    frames: Vec<Frame>,
+}
+
+impl Session {
+    /// Create a session with a virtual terminal of the size.
+    pub fn new(width: usize, height: usize) -> Self {
+        Session {
+            width,
+            height,
+            events: Vec::new(),
+            frames: Vec::new(),
+        }
+    }
+
+    /// Add a key event to the script, like `KeyCode::Enter`.
+    pub fn key(&mut self, key: impl Into<KeyEvent>) -> &mut Self {
+        self.events.push(Event::Key(key.into()));
+        self
+    }
+
+    /// Add several key events to the script.
+    pub fn keys<I>(&mut self, keys: I) -> &mut Self
+    where
+        I: IntoIterator,
+        I::Item: Into<KeyEvent>,
+    {
+        self.events
+            .extend(keys.into_iter().map(|key| Event::Key(key.into())));
+        self
+    }
+
+    /// Add a key event for each character of the text, like typing it.
+    pub fn text(&mut self, text: &str) -> &mut Self {
+        self.keys(text.chars().map(KeyCode::Char))
+    }
+
+    /// Resize the virtual terminal at this point of the script, drawing the prompt again.
+    ///
+    /// The rows are clipped, like in terminals that don't reflow the text.
+    pub fn resize(&mut self, width: usize, height: usize) -> &mut Self {
+        self.events.push(Event::Resize(width as u16, height as u16));
+        self
+    }
+
+    /// Run the prompt with the script, consuming its events.
+    ///
+    /// Returns whether the prompt was submitted, `false` if the events ran out before,
+    /// or [`Error::Cancel`] if it was cancelled.
+    pub fn run(&mut self, prompt: &mut (impl Printable + Typeable)) -> Result<bool, Error> {
+        let term = VirtualTerminal::new(self.width, self.height);
+        let mut renderer = Renderer::with_terminal(term.clone());
+        let mut recorder = Recorder {
+            prompt,
+            term: term.clone(),
+            frames: RefCell::new(Vec::new()),
+        };
+
+        let events = std::mem::take(&mut self.events)
+            .into_iter()
+            .inspect(|event| {
+                if let Event::Resize(width, height) = *event {
+                    term.resize(width.into(), height.into());
+                }
+            });
+
+        let result = key_listener::listen_events(&mut recorder, &mut renderer, events, false);
+        self.frames = recorder.frames.into_inner();
+
+        result
+    }
+
+    /// Returns the frames captured by the last run, in the order they were drawn.
+    pub fn frames(&self) -> &[Frame] {
+        &self.frames
+    }
+
+    /// Returns the last frame captured by the last run.
+    pub fn last_frame(&self) -> Option<&Frame> {
+        self.frames.last()
+    }
+}
+
+/// Prompt that captures a frame each time it's drawn.
+struct Recorder<'p, P> {
+    prompt: &'p mut P,
+    term: VirtualTerminal,
+    frames: RefCell<Vec<Frame>>,
+}
+
+impl<P: Typeable> Typeable for Recorder<'_, P> {
+    fn handle_key(&mut self, key: KeyEvent) -> bool {
+        self.prompt.handle_key(key)
+    }
+
+    fn answer_key(&self) -> Option<&str> {
+        self.prompt.answer_key()
+    }
+
+    fn is_sensitive(&self) -> bool {
+        self.prompt.is_sensitive()
+    }
+
+    fn needs_key_enhancement(&self) -> bool {
+        self.prompt.needs_key_enhancement()
+    }
+
+    fn seed(&mut self, value: &str) -> bool {
+        self.prompt.seed(value)
+    }
+
+    fn prepare(&mut self) {
+        self.prompt.prepare()
+    }
+}
+
+impl<P: Printable> Printable for Recorder<'_, P> {
+    fn draw(&self, renderer: &mut Renderer) -> io::Result<()> {
+        self.prompt.draw(renderer)?;
+
+        let mut lines = self.term.lines();
+        let mut styled_lines = self.term.styled_lines();
+
+        while lines.last().is_some_and(String::is_empty) && styled_lines.last() == Some(&vec![]) {
+            lines.pop();
+            styled_lines.pop();
+        }
+
+        self.frames.borrow_mut().push(Frame {
+            draw_time: renderer.draw_time,
+            lines,
+            styled_lines,
+            cursor: self.term.cursor(),
+        });
+
+        Ok(())
+    }
+
+    fn snapshot(&self) -> Option<PromptState> {
+        self.prompt.snapshot()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{Select, Text};
+
+    #[test]
+    fn capture_frames() {
+        let mut text = Text::new("Name");
+        let mut session = Session::new(40, 10);
+
+        let submitted = session.text("ab").run(&mut text).unwrap();
+        let draw_times: Vec<DrawTime> = session.frames().iter().map(|f| f.draw_time).collect();
+
+        assert!(!submitted);
+        assert_eq!(
+            draw_times,
+            [DrawTime::First, DrawTime::Update, DrawTime::Update]
+        );
+        assert_eq!(text.input.value, "ab");
+
+        let mut select = Select::new("Letter", ["a", "b", "c"]);
+        let submitted = session
+            .keys([KeyCode::Down, KeyCode::Enter])
+            .run(&mut select)
+            .unwrap();
+        let last = session.last_frame().unwrap();
+
+        assert!(submitted);
+        assert_eq!(session.frames().len(), 4);
+        assert_eq!(last.draw_time, DrawTime::Last);
+        assert!(last.text().contains('b'));
+
+        let result = session.key(KeyCode::Esc).run(&mut Text::new("Name"));
+        assert!(matches!(result, Err(Error::Cancel)));
+    }
+
+    #[test]
+    fn prepare_before_first_frame() {
+        let mut text = Text::new("Name");
+        let mut session = Session::new(40, 10);
+
+        text.initial("x").validate(|value| match value.len() < 2 {
+            true => Err("Too short"),
+            false => Ok(()),
+        });
+        session.run(&mut text).unwrap();
+
+        assert!(session.frames()[0].text().contains("Too short"));
+    }
+
+    #[test]
+    fn redraw_after_resize() {
+        let mut text = Text::new("Name");
+        let mut session = Session::new(20, 5);
+
+        text.initial("abcdefghijkl");
+        session.resize(10, 5).run(&mut text).unwrap();
+
+        let [before, after] = session.frames() else {
+            panic!("expected two frames");
+        };
+
+        assert_eq!(before.lines, ["? Name", "> abcdefghijkl"]);
+        assert_eq!(after.lines, ["? Name", "> abcdefgh", "ijkl"]);
+    }
+
+    #[test]
+    fn capture_styles() {
+        let mut text = Text::new("Name");
+        let mut session = Session::new(40, 10);
+
+        text.format(|prompt, _| {
+            let message = format!("\x1b[1;38;5;9m{}\x1b[22;39m", prompt.message);
+            let input = format!("\x1b[4;48;2;0;128;255m{}\x1b[0m", prompt.input.value);
+            (format!("{message} {input}  "), [0, 0])
+        });
+        session.text("ab").run(&mut text).unwrap();
+
+        let frame = session.last_frame().unwrap();
+        let spans = &frame.styled_lines[0];
+
+        assert_eq!(frame.text(), "Name ab");
+        assert_eq!(spans[0].style.foreground, Some(Color::Indexed(9)));
+        assert_eq!(spans[2].style.background, Some(Color::Rgb(0, 128, 255)));
+        assert_eq!(
+            frame.styled_text(),
+            "{fg:bright_red,bold}Name{/} {bg:#0080ff,underlined}ab{/}"
+        );
+    }
+}
@@ -0,0 +1,362 @@
+use std::collections::HashMap;
+
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+use super::error::Error;
+
+/// Machine-readable representation of a prompt answer.
+///
+/// With the `serde` feature, it's serialized with the variant in a `type` field
+/// and the answer in a `value` field, e.g. `{ "type": "bool", "value": true }`.
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(
+    feature = "serde",
+    derive(Serialize, Deserialize),
+    serde(tag = "type", content = "value", rename_all = "snake_case")
+)]
+pub enum Answer {
+    /// No answer, e.g. for skipped prompts.
+    Unit,
+    /// Answer of [`Confirm`](crate::Confirm).
+    Bool(bool),
+    /// Answer of text-like prompts, like [`Text`](crate::Text) or [`Toggle`](crate::Toggle).
+    Text(String),
+    /// Answer of floating point prompts, like [`Number<f64>`](crate::Number).
+    Number(f64),
+    /// Answer of signed integer prompts, like [`Number<i32>`](crate::Number).
+    Integer(i64),
+    /// Answer of unsigned integer prompts, like [`Number<u64>`](crate::Number).
+    Unsigned(u64),
+    /// Index of the chosen option, like in [`Select`](crate::Select).
+    Index(usize),
+    /// Indices of the chosen options, like in [`MultiSelect`](crate::MultiSelect).
+    Indices(Vec<usize>),
+    /// Answer of a sensitive prompt, like [`Text::sensitive`](crate::Text::sensitive),
+    /// without its value.
+    Secret,
+}
+
+impl Answer {
+    /// Returns the value if it's a [`Answer::Bool`].
+    pub fn as_bool(&self) -> Option<bool> {
+        match self {
+            Answer::Bool(value) => Some(*value),
+            _ => None,
+        }
+    }
+
+    /// Returns the value if it's a [`Answer::Text`].
+    pub fn as_text(&self) -> Option<&str> {
+        match self {
+            Answer::Text(value) => Some(value),
+            _ => None,
+        }
+    }
+
+    /// Returns the value if it's a [`Answer::Number`], [`Answer::Integer`] or [`Answer::Unsigned`].
+    ///
+    /// Integers above 2^53 lose precision, use [`Answer::as_integer`] for them.
+    pub fn as_number(&self) -> Option<f64> {
+        match self {
+            Answer::Number(value) => Some(*value),
+            Answer::Integer(value) => Some(*value as f64),
+            Answer::Unsigned(value) => Some(*value as f64),
+            _ => None,
+        }
+    }
+
+    /// Returns the value if it's a [`Answer::Integer`], or a [`Answer::Unsigned`] that fits.
+    pub fn as_integer(&self) -> Option<i64> {
+        match self {
+            Answer::Integer(value) => Some(*value),
+            Answer::Unsigned(value) => i64::try_from(*value).ok(),
+            _ => None,
+        }
+    }
+
+    /// Returns the value if it's a [`Answer::Unsigned`], or a non-negative [`Answer::Integer`].
+    pub fn as_unsigned(&self) -> Option<u64> {
+        match self {
+            Answer::Unsigned(value) => Some(*value),
+            Answer::Integer(value) => u64::try_from(*value).ok(),
+            _ => None,
+        }
+    }
+
+    /// Returns the value if it's a [`Answer::Index`].
+    pub fn as_index(&self) -> Option<usize> {
+        match self {
+            Answer::Index(value) => Some(*value),
+            _ => None,
+        }
+    }
+
+    /// Returns the value if it's a [`Answer::Indices`].
+    pub fn as_indices(&self) -> Option<&[usize]> {
+        match self {
+            Answer::Indices(value) => Some(value),
+            _ => None,
+        }
+    }
+}
+
+impl From<()> for Answer {
+    fn from(_: ()) -> Self {
+        Answer::Unit
+    }
+}
+
+impl From<bool> for Answer {
+    fn from(value: bool) -> Self {
+        Answer::Bool(value)
+    }
+}
+
+impl From<String> for Answer {
+    fn from(value: String) -> Self {
+        Answer::Text(value)
+    }
+}
+
+impl From<&str> for Answer {
+    fn from(value: &str) -> Self {
+        Answer::Text(value.to_string())
+    }
+}
+
+impl From<Vec<usize>> for Answer {
+    fn from(value: Vec<usize>) -> Self {
+        Answer::Indices(value)
+    }
+}
+
+macro_rules! impl_from_number {
+    ($($variant:ident($target:ty) => $($t:ty),*);*) => {
+        $($(
+            impl From<$t> for Answer {
+                fn from(value: $t) -> Self {
+                    Answer::$variant(<$target>::from(value))
+                }
+            }
+        )*)*
+    };
+}
+
+impl_from_number!(
+    Unsigned(u64) => u8, u16, u32, u64;
+    Integer(i64) => i8, i16, i32, i64;
+    Number(f64) => f32, f64
+);
+
+// The wider integers are converted to text when they don't fit in 64 bits,
+// because formats like TOML can't represent them
+macro_rules! impl_try_from_number {
+    ($($variant:ident($target:ty) => $($t:ty),*);*) => {
+        $($(
+            impl From<$t> for Answer {
+                fn from(value: $t) -> Self {
+                    <$target>::try_from(value)
+                        .map_or_else(|_| Answer::Text(value.to_string()), Answer::$variant)
+                }
+            }
+        )*)*
+    };
+}
+
+impl_try_from_number!(
+    Unsigned(u64) => u128, usize;
+    Integer(i64) => i128, isize
+);
+
+/// Missing answers, like a skipped prompt, are converted to [`Answer::Unit`].
+impl<T: Into<Answer>> From<Option<T>> for Answer {
+    fn from(value: Option<T>) -> Self {
+        value.map_or(Answer::Unit, Into::into)
+    }
+}
+
+/// Invalid answers, like a number that can't be parsed, are converted to [`Answer::Unit`].
+impl<T: Into<Answer>, E> From<Result<T, E>> for Answer {
+    fn from(value: Result<T, E>) -> Self {
+        value.map_or(Answer::Unit, Into::into)
+    }
+}
+
+/// Answers of several prompts, by the key set with their `key()` method.
+///
+/// The answers are saved by [`Answers::ask`], or [`Answers::record`] for prompts
+/// displayed in other ways. [`Password`](crate::Password) isn't [`Recordable`],
+/// to keep the secrets out of the collected answers.
+///
+/// With the `serde` feature, it's serialized as a map of [`Answer`].
+///
+/// # Examples
+///
+/// ```no_run
+/// use asky::{Answers, Confirm, Text};
+///
+/// # fn main() -> std::io::Result<()> {
+/// let mut answers = Answers::new();
+///
+/// let name = answers.ask(Text::new("Project name").key("name"))?;
+/// answers.ask(Confirm::new("Use git?").key("git"))?;
+///
+/// println!("Creating {name}");
+///
+/// assert!(answers.get("git").is_some());
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Debug, Clone, Default, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize), serde(transparent))]
+pub struct Answers {
+    entries: HashMap<String, Answer>,
+}
+
+impl Answers {
+    /// Create an empty container.
+    pub fn new() -> Self {
+        Answers::default()
+    }
+
+    /// Returns the answer saved with the key.
+    pub fn get(&self, key: &str) -> Option<&Answer> {
+        self.entries.get(key)
+    }
+
+    /// Save an answer, returning the previous answer of the key.
+    pub fn insert(&mut self, key: impl Into<String>, answer: impl Into<Answer>) -> Option<Answer> {
+        self.entries.insert(key.into(), answer.into())
+    }
+
+    /// Remove the answer saved with the key, returning it.
+    pub fn remove(&mut self, key: &str) -> Option<Answer> {
+        self.entries.remove(key)
+    }
+
+    /// Returns the number of answers.
+    pub fn len(&self) -> usize {
+        self.entries.len()
+    }
+
+    /// Returns whether there are no answers.
+    pub fn is_empty(&self) -> bool {
+        self.entries.is_empty()
+    }
+
+    /// Returns an iterator over the keys and answers, in arbitrary order.
+    pub fn iter(&self) -> impl Iterator<Item = (&str, &Answer)> {
+        self.entries
+            .iter()
+            .map(|(key, answer)| (key.as_str(), answer))
+    }
+
+    /// Display the prompt and save its answer by its key, returning the prompt output.
+    ///
+    /// The answer is not saved if the prompt doesn't have a key or it's not submitted.
+    pub fn ask<P: Recordable + ?Sized>(&mut self, prompt: &mut P) -> Result<P::Output, Error> {
+        let output = prompt.ask()?;
+        self.record(prompt);
+        Ok(output)
+    }
+
+    /// Save the last submitted answer of the prompt by its key, e.g. after `prompt_async()`.
+    ///
+    /// It does nothing for prompts without a key.
+    pub fn record<P: Recordable + ?Sized>(&mut self, prompt: &P) {
+        if let Some(key) = prompt.record_key() {
+            self.insert(key, prompt.submitted_answer());
+        }
+    }
+
+    /// Consume the container into the map of answers.
+    pub fn into_map(self) -> HashMap<String, Answer> {
+        self.entries
+    }
+}
+
+/// Prompts whose answer can be saved in [`Answers`].
+///
+/// It's implemented by the prompts with a `key()` method, see [`Answers::ask`].
+pub trait Recordable {
+    /// Value returned when the prompt is submitted.
+    type Output;
+
+    /// Returns the key set with the `key()` method of the prompt.
+    fn record_key(&self) -> Option<&str>;
+
+    /// Display the prompt and return the user answer, like its `prompt()` method.
+    fn ask(&mut self) -> Result<Self::Output, Error>;
+
+    /// Returns the last submitted answer.
+    fn submitted_answer(&self) -> Answer;
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{Confirm, Text};
+
+    #[test]
+    fn convert_values() {
+        assert_eq!(Answer::from(true).as_bool(), Some(true));
+        assert_eq!(Answer::from("foo").as_text(), Some("foo"));
+        assert_eq!(Answer::from(10_u8).as_number(), Some(10.0));
+        assert_eq!(Answer::from(u64::MAX).as_unsigned(), Some(u64::MAX));
+        assert_eq!(Answer::from(-2_i64).as_integer(), Some(-2));
+        assert_eq!(Answer::from(-2_i64).as_unsigned(), None);
+        assert_eq!(Answer::from(1.5_f32).as_integer(), None);
+        assert_eq!(Answer::Index(2).as_index(), Some(2));
+        assert_eq!(Answer::from(vec![0, 2]).as_indices(), Some(&[0, 2][..]));
+        assert_eq!(Answer::from(()).as_bool(), None);
+    }
+
+    #[test]
+    fn convert_wide_integers() {
+        assert_eq!(Answer::from(7_usize), Answer::Unsigned(7));
+        assert_eq!(Answer::from(-7_i128), Answer::Integer(-7));
+        assert_eq!(Answer::from(u128::MAX), Answer::Text(u128::MAX.to_string()));
+        assert_eq!(Answer::from(i128::MIN), Answer::Text(i128::MIN.to_string()));
+    }
+
+    #[test]
+    fn record_by_key() {
+        let mut answers = Answers::new();
+
+        answers.record(Text::new("Name").key("name").initial("foo"));
+        answers.record(Confirm::new("Ignored").initial(true));
+
+        assert_eq!(answers.len(), 1);
+        assert_eq!(answers.get("name"), Some(&Answer::from("foo")));
+
+        #[cfg(feature = "serde")]
+        assert_eq!(
+            serde_json::to_string(&answers).unwrap(),
+            r#"{"name":{"type":"text","value":"foo"}}"#
+        );
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn serialize_answers() {
+        let answers = [
+            (Answer::Unit, r#"{"type":"unit"}"#),
+            (Answer::Secret, r#"{"type":"secret"}"#),
+            (Answer::Bool(true), r#"{"type":"bool","value":true}"#),
+            (
+                Answer::from(u64::MAX),
+                r#"{"type":"unsigned","value":18446744073709551615}"#,
+            ),
+            (
+                Answer::Indices(vec![1]),
+                r#"{"type":"indices","value":[1]}"#,
+            ),
+        ];
+
+        for (answer, json) in answers {
+            assert_eq!(serde_json::to_string(&answer).unwrap(), json);
+            assert_eq!(serde_json::from_str::<Answer>(json).unwrap(), answer);
+        }
+    }
+}
@@ -0,0 +1,248 @@
+use std::{
+    collections::HashMap,
+    env,
+    sync::{Arc, RwLock},
+};
+
+use crate::prompts::select::SelectOption;
+
+use super::{error::Error, key_listener::Typeable};
+
+static SOURCE: RwLock<Option<Arc<dyn AnswerSource>>> = RwLock::new(None);
+
+/// Source of pre-seeded answers, to run the prompts without user input,
+/// like in scripts or CI where stdin is not a terminal.
+///
+/// The answers are looked up by the [`key`](crate::Text::key) of the prompt, or its
+/// [`name`](crate::Text::name), and they are parsed like the user input:
+///
+/// - [`Text`](crate::Text), [`Number`](crate::Number) and [`TypeToConfirm`](crate::TypeToConfirm)
+///   use the value as the input.
+/// - [`Confirm`](crate::Confirm) accepts `y`, `yes`, `true`, `1` and `n`, `no`, `false`, `0`.
+/// - [`Toggle`](crate::Toggle) and [`Select`](crate::Select) accept the title of an option.
+/// - [`MultiSelect`](crate::MultiSelect) accepts a list of titles separated by commas.
+/// - [`Percent`](crate::Percent) accepts a number between 0 and 100.
+///
+/// Invalid answers make the prompt return [`Error::InvalidInput`], e.g. when the validator fails.
+/// [`Password`](crate::Password) has its own non-interactive sources.
+pub trait AnswerSource: Send + Sync {
+    /// Returns the answer of the prompt with the key, if any.
+    fn answer(&self, key: &str) -> Option<String>;
+}
+
+impl AnswerSource for HashMap<String, String> {
+    fn answer(&self, key: &str) -> Option<String> {
+        self.get(key).cloned()
+    }
+}
+
+/// Answers from environment variables, named by the key of the prompt.
+///
+/// The key is uppercased and the characters that are not alphanumeric are replaced by `_`,
+/// after the prefix, e.g. `"db.host"` is read from `APP_DB_HOST` with the prefix `"APP_"`.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct EnvSource {
+    /// Prefix of the variable names.
+    pub prefix: String,
+}
+
+impl EnvSource {
+    /// Create a source reading the variables with the prefix.
+    pub fn new(prefix: impl Into<String>) -> Self {
+        EnvSource {
+            prefix: prefix.into(),
+        }
+    }
+
+    /// Returns the name of the variable of the key.
+    pub fn var_name(&self, key: &str) -> String {
+        let key: String = key
+            .chars()
+            .map(|c| match c.is_ascii_alphanumeric() {
+                true => c.to_ascii_uppercase(),
+                false => '_',
+            })
+            .collect();
+
+        format!("{}{}", self.prefix, key)
+    }
+}
+
+impl AnswerSource for EnvSource {
+    fn answer(&self, key: &str) -> Option<String> {
+        env::var(self.var_name(key)).ok()
+    }
+}
+
+/// Answers from command line flags, like `--name=foo` or `--name foo`.
+///
+/// A flag without value, like `--force`, is read as `true`.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct ArgsSource {
+    values: HashMap<String, String>,
+}
+
+impl ArgsSource {
+    /// Create a source with the flags of the process arguments.
+    pub fn new() -> Self {
+        Self::from_args(env::args().skip(1))
+    }
+
+    /// Create a source with the flags of the arguments, ignoring the positional ones.
+    pub fn from_args<I, S>(args: I) -> Self
+    where
+        I: IntoIterator<Item = S>,
+        S: Into<String>,
+    {
+        let mut values = HashMap::new();
+        let mut args = args.into_iter().map(Into::into).peekable();
+
+        while let Some(arg) = args.next() {
+            let Some(flag) = arg.strip_prefix("--") else {
+                continue;
+            };
+
+            let (key, value) = match flag.split_once('=') {
+                Some((key, value)) => (key.to_owned(), value.to_owned()),
+                None => {
+                    let value = args.next_if(|next| !next.starts_with("--"));
+                    (
+                        flag.to_owned(),
+                        value.unwrap_or_else(|| String::from("true")),
+                    )
+                }
+            };
+
+            values.insert(key, value);
+        }
+
+        ArgsSource { values }
+    }
+}
+
+impl AnswerSource for ArgsSource {
+    fn answer(&self, key: &str) -> Option<String> {
+        self.values.answer(key)
+    }
+}
+
+/// Set the source of pre-seeded answers, consulted by the prompts before listening for keys.
+///
+/// # Examples
+///
+/// ```no_run
+/// use asky::{set_answer_source, ArgsSource, Confirm, Text};
+///
+/// # fn main() -> std::io::Result<()> {
+/// // my-cli --name foo --git
+/// set_answer_source(ArgsSource::new());
+///
+/// let name = Text::new("Project name").key("name").prompt()?;
+/// let git = Confirm::new("Use git?").key("git").prompt()?;
+/// # Ok(())
+/// # }
+/// ```
+pub fn set_answer_source(source: impl AnswerSource + 'static) {
+    replace_source(Some(Arc::new(source)))
+}
+
+/// Remove the source set with [`set_answer_source`].
+pub fn clear_answer_source() {
+    replace_source(None)
+}
+
+fn replace_source(source: Option<Arc<dyn AnswerSource>>) {
+    match SOURCE.write() {
+        Ok(mut current) => *current = source,
+        Err(poisoned) => *poisoned.into_inner() = source,
+    }
+}
+
+/// Answer the prompt from the source, returns whether it was answered.
+pub(crate) fn fill(prompt: &mut impl Typeable) -> Result<bool, Error> {
+    let Some(key) = prompt.answer_key() else {
+        return Ok(false);
+    };
+
+    let source = match SOURCE.read() {
+        Ok(source) => source.clone(),
+        Err(poisoned) => poisoned.into_inner().clone(),
+    };
+
+    let Some(value) = source.and_then(|source| source.answer(key)) else {
+        return Ok(false);
+    };
+
+    match prompt.seed(&value) {
+        true => Ok(true),
+        false => Err(Error::InvalidInput(value)),
+    }
+}
+
+/// Parse the answer of a yes/no question.
+pub(crate) fn parse_bool(value: &str) -> Option<bool> {
+    match value.trim().to_lowercase().as_str() {
+        "y" | "yes" | "true" | "1" => Some(true),
+        "n" | "no" | "false" | "0" => Some(false),
+        _ => None,
+    }
+}
+
+/// Returns the index of the enabled option with the title.
+pub(crate) fn find_option<T>(options: &[SelectOption<T>], title: &str) -> Option<usize> {
+    options
+        .iter()
+        .position(|option| !option.disabled && option.get_title() == title)
+}
+
+/// Split a list of option titles separated by commas.
+pub(crate) fn split_titles(value: &str) -> impl Iterator<Item = &str> {
+    value
+        .split(',')
+        .map(str::trim)
+        .filter(|title| !title.is_empty())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{Confirm, MultiSelect, Text};
+
+    #[test]
+    fn seed_prompts() {
+        let args = ArgsSource::from_args(["build", "--name=foo", "--tags", "a, c", "--git"]);
+        let mut name = Text::new("Name");
+        let mut git = Confirm::new("Git?");
+        let mut tags = MultiSelect::new("Tags", ["a", "b", "c"]);
+
+        name.key("name").validate(|value| match value.len() {
+            0..=3 => Ok(()),
+            _ => Err("too long"),
+        });
+        git.name("git");
+        tags.key("tags");
+
+        assert_eq!(EnvSource::new("APP_").var_name("db.host"), "APP_DB_HOST");
+        assert_eq!(args.answer("build"), None);
+
+        for (key, value) in [("name", "foo"), ("git", "true"), ("tags", "a, c")] {
+            assert_eq!(args.answer(key).as_deref(), Some(value));
+        }
+
+        assert!(name.seed("foo"));
+        assert!(!name.seed("foobar"));
+        assert!(git.seed("yes") && git.active);
+        assert!(!git.seed("maybe"));
+        assert!(tags.seed("a, c"));
+
+        let active: Vec<bool> = tags.options.iter().map(|option| option.active).collect();
+        assert_eq!(active, [true, false, true]);
+
+        set_answer_source(HashMap::from([("name".to_string(), "bar".to_string())]));
+        let filled = fill(&mut name);
+        clear_answer_source();
+
+        assert!(filled.unwrap());
+        assert_eq!(name.input.value, "bar");
+    }
+}
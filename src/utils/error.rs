@@ -0,0 +1,89 @@
+use std::{error, fmt, io};
+
+/// Error returned by the prompts.
+///
+/// It can be converted into an [`io::Error`], so `?` works in functions returning [`io::Result`].
+#[derive(Debug)]
+pub enum Error {
+    /// The user cancelled the prompt with `Esc`, `Ctrl+C` or `Ctrl+D`.
+    ///
+    /// See [`AskySettings::exit_on_cancel`](crate::AskySettings::exit_on_cancel)
+    /// to exit the process instead.
+    Cancel,
+    /// The time limit of the prompt ran out, see [`Flow::time_limit`](crate::Flow::time_limit).
+    Timeout,
+    /// The submitted input is not valid, like a [`Number`](crate::Number) that can't be parsed.
+    ///
+    /// It contains the submitted input.
+    InvalidInput(String),
+    /// Error reading the keys or writing to the terminal.
+    Io(io::Error),
+}
+
+impl Error {
+    /// Returns whether the user cancelled the prompt.
+    pub fn is_cancel(&self) -> bool {
+        matches!(self, Error::Cancel)
+    }
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Error::Cancel => f.write_str("prompt cancelled by the user"),
+            Error::Timeout => f.write_str("prompt timed out"),
+            Error::InvalidInput(input) => write!(f, "invalid input: {:?}", input),
+            Error::Io(err) => err.fmt(f),
+        }
+    }
+}
+
+impl error::Error for Error {
+    fn source(&self) -> Option<&(dyn error::Error + 'static)> {
+        match self {
+            Error::Cancel | Error::Timeout | Error::InvalidInput(_) => None,
+            Error::Io(err) => Some(err),
+        }
+    }
+}
+
+impl From<io::Error> for Error {
+    fn from(err: io::Error) -> Self {
+        Error::Io(err)
+    }
+}
+
+impl From<Error> for io::Error {
+    fn from(err: Error) -> Self {
+        match err {
+            Error::Cancel => io::Error::new(io::ErrorKind::Interrupted, Error::Cancel),
+            Error::Timeout => io::Error::new(io::ErrorKind::TimedOut, Error::Timeout),
+            Error::InvalidInput(_) => io::Error::new(io::ErrorKind::InvalidInput, err),
+            Error::Io(err) => err,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn convert_io_errors() {
+        let err = io::Error::from(Error::Cancel);
+        assert_eq!(err.kind(), io::ErrorKind::Interrupted);
+        assert_eq!(err.to_string(), "prompt cancelled by the user");
+
+        let err = io::Error::from(Error::Timeout);
+        assert_eq!(err.kind(), io::ErrorKind::TimedOut);
+        assert_eq!(err.to_string(), "prompt timed out");
+
+        let err = io::Error::from(Error::InvalidInput(String::from("1x")));
+        assert_eq!(err.kind(), io::ErrorKind::InvalidInput);
+        assert_eq!(err.to_string(), r#"invalid input: "1x""#);
+
+        let err = Error::from(io::Error::from(io::ErrorKind::NotFound));
+        assert!(!err.is_cancel());
+        assert_eq!(io::Error::from(err).kind(), io::ErrorKind::NotFound);
+    }
+}
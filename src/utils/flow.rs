@@ -0,0 +1,547 @@
+use std::time::{Duration, Instant};
+#[cfg(feature = "autosave")]
+use std::{
+    fs, io,
+    path::{Path, PathBuf},
+};
+
+#[cfg(feature = "autosave")]
+use super::answer::Answers;
+use super::{answer::Answer, error::Error, renderer, theme, timer};
+
+type Ask<'a> = dyn FnMut(&FlowAnswers<'a>) -> Result<Answer, Error> + 'a;
+type Condition<'a> = dyn Fn(&FlowAnswers<'a>) -> bool + 'a;
+type Next<'a> = dyn Fn(&FlowAnswers<'a>) -> Option<&'a str> + 'a;
+
+struct Step<'a> {
+    key: &'a str,
+    ask: Box<Ask<'a>>,
+    condition: Option<Box<Condition<'a>>>,
+    next: Option<Box<Next<'a>>>,
+    time_limit: Option<Duration>,
+}
+
+impl<'a> Step<'a> {
+    /// Returns whether the step is asked, by its condition.
+    fn is_active(&self, answers: &FlowAnswers<'a>) -> bool {
+        match &self.condition {
+            Some(condition) => condition(answers),
+            None => true,
+        }
+    }
+}
+
+/// Answers collected by a [`Flow`], by the key of their step.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct FlowAnswers<'a> {
+    entries: Vec<(&'a str, Answer)>,
+    timed_out: Vec<&'a str>,
+}
+
+impl<'a> FlowAnswers<'a> {
+    /// Returns the answer of a step, if it was asked.
+    pub fn get(&self, key: &str) -> Option<&Answer> {
+        self.entries
+            .iter()
+            .find(|(entry_key, _)| *entry_key == key)
+            .map(|(_, answer)| answer)
+    }
+
+    /// Returns the answers, in the order they were first asked.
+    pub fn entries(&self) -> &[(&'a str, Answer)] {
+        &self.entries
+    }
+
+    /// Returns the keys of the steps that ran out of time, in the order they timed out.
+    ///
+    /// Their answer is [`Answer::Unit`], unless they were asked again and answered.
+    pub fn timed_out(&self) -> &[&'a str] {
+        &self.timed_out
+    }
+
+    /// Consume the answers into a list, in the order they were first asked.
+    pub fn into_entries(self) -> Vec<(&'a str, Answer)> {
+        self.entries
+    }
+
+    /// Set the answer of a step, replacing the previous one if the step is asked again.
+    fn insert(&mut self, key: &'a str, answer: Answer) {
+        self.timed_out.retain(|timed_out| *timed_out != key);
+
+        match self
+            .entries
+            .iter_mut()
+            .find(|(entry_key, _)| *entry_key == key)
+        {
+            Some((_, current)) => *current = answer,
+            None => self.entries.push((key, answer)),
+        }
+    }
+
+    /// Set the answer of a step that ran out of time.
+    fn insert_timed_out(&mut self, key: &'a str) {
+        self.insert(key, Answer::Unit);
+        self.timed_out.push(key);
+    }
+}
+
+/// Sequence of prompts where the next step can depend on the previous answers, like a wizard.
+///
+/// Each step is a closure that displays one or more prompts and returns an answer,
+/// saved by the key of the step. The steps run in order, unless:
+///
+/// - The step was added with [`Flow::step_if`] and its condition is `false`, then it's skipped.
+/// - The step has a [`Flow::next`] closure that returns the key of another step,
+///   then the flow continues from that step, even a previous one.
+///
+/// With [`Flow::progress`], the number of the step is displayed above its prompts.
+///
+/// With [`Flow::time_limit`] and [`Flow::total_time_limit`], the steps are timed, like in a quiz,
+/// and a countdown is displayed above their prompts.
+///
+/// With the `autosave` feature, [`Flow::autosave`] saves the answers to a file after each step,
+/// and [`Flow::resume_from`] continues an interrupted flow with the answers of that file.
+///
+/// # Examples
+///
+/// ```no_run
+/// use asky::{Answer, Confirm, Flow, Text};
+///
+/// # fn main() -> std::io::Result<()> {
+/// let answers = Flow::new()
+///     .step("name", |_| Text::new("What is your name?").prompt())
+///     .step("signup", |_| Confirm::new("Sign up for the newsletter?").prompt())
+///     .step_if(
+///         "email",
+///         |answers| answers.get("signup") == Some(&Answer::Bool(true)),
+///         |_| Text::new("Email").prompt(),
+///     )
+///     .run()?;
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Default)]
+pub struct Flow<'a> {
+    steps: Vec<Step<'a>>,
+    progress: bool,
+    total_time_limit: Option<Duration>,
+    #[cfg(feature = "autosave")]
+    autosave: Option<PathBuf>,
+    #[cfg(feature = "autosave")]
+    resume: Option<PathBuf>,
+}
+
+impl<'a> Flow<'a> {
+    /// Create an empty flow.
+    pub fn new() -> Self {
+        Flow::default()
+    }
+
+    /// Add a step, that receives the previous answers.
+    pub fn step<F, A>(&mut self, key: &'a str, ask: F) -> &mut Self
+    where
+        F: FnMut(&FlowAnswers<'a>) -> Result<A, Error> + 'a,
+        A: Into<Answer>,
+    {
+        self.push(key, ask, None)
+    }
+
+    /// Add a step that is only asked when the condition returns `true`.
+    pub fn step_if<C, F, A>(&mut self, key: &'a str, condition: C, ask: F) -> &mut Self
+    where
+        C: Fn(&FlowAnswers<'a>) -> bool + 'a,
+        F: FnMut(&FlowAnswers<'a>) -> Result<A, Error> + 'a,
+        A: Into<Answer>,
+    {
+        self.push(key, ask, Some(Box::new(condition)))
+    }
+
+    /// Set the step to continue with after the last added one, by its key.
+    ///
+    /// When the closure returns `None`, or the last step is skipped, the flow continues in order.
+    ///
+    /// # Panics
+    ///
+    /// Running the flow panics if the closure returns a key that is not in the flow.
+    pub fn next<N>(&mut self, next: N) -> &mut Self
+    where
+        N: Fn(&FlowAnswers<'a>) -> Option<&'a str> + 'a,
+    {
+        if let Some(step) = self.steps.last_mut() {
+            step.next = Some(Box::new(next));
+        }
+
+        self
+    }
+
+    /// Set whether to display `Question 3 of 7` above the prompts of each step,
+    /// below the header set with [`set_header`](crate::set_header).
+    ///
+    /// The total is updated with the steps that are skipped or asked again.
+    pub fn progress(&mut self, show: bool) -> &mut Self {
+        self.progress = show;
+        self
+    }
+
+    /// Set the time to answer the prompts of the last added step.
+    ///
+    /// When the time runs out, the prompt is removed, its answer is [`Answer::Unit`],
+    /// the key of the step is added to [`FlowAnswers::timed_out`], and the flow continues.
+    ///
+    /// Only sync prompts are timed, async ones never time out.
+    pub fn time_limit(&mut self, limit: Duration) -> &mut Self {
+        if let Some(step) = self.steps.last_mut() {
+            step.time_limit = Some(limit);
+        }
+
+        self
+    }
+
+    /// Set the time to answer all the steps, counting from [`Flow::run`].
+    ///
+    /// When the time runs out, the step being asked times out, like with [`Flow::time_limit`],
+    /// and the flow ends without asking the next steps.
+    pub fn total_time_limit(&mut self, limit: Duration) -> &mut Self {
+        self.total_time_limit = Some(limit);
+        self
+    }
+
+    /// Save the answers to a JSON file after each step, by the key of the step.
+    ///
+    /// The file is removed when the flow is completed, so it's only kept if the flow is
+    /// interrupted, e.g. by a cancelled prompt. Answers of sensitive prompts are not saved.
+    #[cfg(feature = "autosave")]
+    pub fn autosave(&mut self, path: impl Into<PathBuf>) -> &mut Self {
+        self.autosave = Some(path.into());
+        self
+    }
+
+    /// Continue with the answers saved by [`Flow::autosave`] in the file, if it exists.
+    ///
+    /// The steps with a saved answer are not asked, but their conditions and
+    /// [`Flow::next`] closures are evaluated with the saved answers, like if they were asked.
+    #[cfg(feature = "autosave")]
+    pub fn resume_from(&mut self, path: impl Into<PathBuf>) -> &mut Self {
+        self.resume = Some(path.into());
+        self
+    }
+
+    /// Run the steps and return their answers.
+    ///
+    /// Stops at the first error, like a cancelled prompt.
+    pub fn run(&mut self) -> Result<FlowAnswers<'a>, Error> {
+        let status = renderer::replace_flow_status(None);
+        let deadline = timer::replace_deadline(None);
+        let result = self.run_steps();
+
+        timer::replace_deadline(deadline);
+
+        #[cfg(feature = "autosave")]
+        let result = result.and_then(|answers| {
+            if let Some(path) = &self.autosave {
+                remove_file(path)?;
+            }
+
+            Ok(answers)
+        });
+
+        renderer::replace_flow_status(status);
+        result
+    }
+
+    fn run_steps(&mut self) -> Result<FlowAnswers<'a>, Error> {
+        let mut answers = FlowAnswers::default();
+        #[cfg(feature = "autosave")]
+        let mut saved = match &self.resume {
+            Some(path) => load_answers(path)?,
+            None => Answers::new(),
+        };
+        let mut asked = 0;
+        let mut index = 0;
+        let flow_deadline = self.total_time_limit.map(|limit| Instant::now() + limit);
+
+        while index < self.steps.len() {
+            if !self.steps[index].is_active(&answers) {
+                index += 1;
+                continue;
+            }
+
+            if self.progress {
+                let total = asked + self.active_steps(index, &answers);
+                let progress = theme::fmt_flow_progress(asked + 1, total);
+                renderer::replace_flow_status(Some(progress));
+            }
+
+            let step = &mut self.steps[index];
+            index += 1;
+            asked += 1;
+
+            if flow_deadline.is_some_and(|deadline| deadline <= Instant::now()) {
+                answers.insert_timed_out(step.key);
+                break;
+            }
+
+            let step_deadline = step.time_limit.map(|limit| Instant::now() + limit);
+            let deadline = [step_deadline, flow_deadline].into_iter().flatten().min();
+
+            #[cfg(feature = "autosave")]
+            let answer = match saved.remove(step.key) {
+                Some(answer) => Ok(answer),
+                None => ask_until(step, &answers, deadline),
+            };
+            #[cfg(not(feature = "autosave"))]
+            let answer = ask_until(step, &answers, deadline);
+
+            match answer {
+                Ok(answer) => answers.insert(step.key, answer),
+                Err(Error::Timeout) => answers.insert_timed_out(step.key),
+                Err(err) => return Err(err),
+            }
+
+            #[cfg(feature = "autosave")]
+            if let Some(path) = &self.autosave {
+                save_answers(path, &answers)?;
+            }
+
+            if flow_deadline.is_some_and(|deadline| deadline <= Instant::now()) {
+                break;
+            }
+
+            let next = step.next.as_ref().and_then(|next| next(&answers));
+
+            if let Some(key) = next {
+                index = self.position(key);
+            }
+        }
+
+        Ok(answers)
+    }
+
+    fn push<F, A>(
+        &mut self,
+        key: &'a str,
+        mut ask: F,
+        condition: Option<Box<Condition<'a>>>,
+    ) -> &mut Self
+    where
+        F: FnMut(&FlowAnswers<'a>) -> Result<A, Error> + 'a,
+        A: Into<Answer>,
+    {
+        self.steps.push(Step {
+            key,
+            ask: Box::new(move |answers| ask(answers).map(Into::into)),
+            condition,
+            next: None,
+            time_limit: None,
+        });
+        self
+    }
+
+    /// Returns the number of steps to ask from the index, with the current answers.
+    fn active_steps(&self, from: usize, answers: &FlowAnswers<'a>) -> usize {
+        self.steps[from..]
+            .iter()
+            .filter(|step| step.is_active(answers))
+            .count()
+    }
+
+    fn position(&self, key: &str) -> usize {
+        match self.steps.iter().position(|step| step.key == key) {
+            Some(index) => index,
+            None => panic!("the flow has no step with the key {:?}", key),
+        }
+    }
+}
+
+/// Ask the step with the deadline set for its prompts.
+fn ask_until<'a>(
+    step: &mut Step<'a>,
+    answers: &FlowAnswers<'a>,
+    deadline: Option<Instant>,
+) -> Result<Answer, Error> {
+    timer::replace_deadline(deadline);
+    let answer = (step.ask)(answers);
+    timer::replace_deadline(None);
+
+    answer
+}
+
+#[cfg(feature = "autosave")]
+fn load_answers(path: &Path) -> Result<Answers, Error> {
+    let content = match fs::read_to_string(path) {
+        Ok(content) => content,
+        Err(err) if err.kind() == io::ErrorKind::NotFound => return Ok(Answers::new()),
+        Err(err) => return Err(err.into()),
+    };
+
+    serde_json::from_str(&content)
+        .map_err(|err| io::Error::new(io::ErrorKind::InvalidData, err).into())
+}
+
+#[cfg(feature = "autosave")]
+fn save_answers(path: &Path, answers: &FlowAnswers) -> Result<(), Error> {
+    let mut saved = Answers::new();
+
+    for (key, answer) in answers.entries() {
+        if *answer != Answer::Secret {
+            saved.insert(*key, answer.clone());
+        }
+    }
+
+    fs::write(
+        path,
+        serde_json::to_string(&saved).map_err(io::Error::from)?,
+    )?;
+    Ok(())
+}
+
+#[cfg(feature = "autosave")]
+fn remove_file(path: &Path) -> Result<(), Error> {
+    match fs::remove_file(path) {
+        Err(err) if err.kind() != io::ErrorKind::NotFound => Err(err.into()),
+        _ => Ok(()),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::utils::layout;
+
+    #[test]
+    fn branch_on_answers() {
+        let mut tries = 0;
+        let mut flow = Flow::new();
+
+        flow.step("signup", |_| Ok(false))
+            .step_if(
+                "email",
+                |answers| answers.get("signup") == Some(&Answer::Bool(true)),
+                |_| Ok("foo@example.com"),
+            )
+            .step("code", move |_| {
+                tries += 1;
+                Ok(tries)
+            })
+            .next(|answers| match answers.get("code") {
+                Some(Answer::Integer(tries)) if *tries < 3 => Some("code"),
+                _ => None,
+            })
+            .step("done", |answers| Ok(answers.entries().len()));
+
+        let answers = flow.run().unwrap();
+
+        assert_eq!(answers.get("email"), None);
+        assert_eq!(
+            answers.into_entries(),
+            [
+                ("signup", Answer::Bool(false)),
+                ("code", Answer::Integer(3)),
+                ("done", Answer::Unsigned(2)),
+            ]
+        );
+    }
+
+    #[test]
+    fn display_progress() {
+        let mut flow = Flow::new();
+        let header = || renderer::displayed_header().map(|text| layout::strip_escape_codes(&text));
+        let step = move |_: &FlowAnswers| Ok(header());
+
+        flow.progress(true)
+            .step("tls", step)
+            .step_if("cert", |answers| answers.get("tls").is_none(), step)
+            .step("port", step);
+
+        let answers = flow.run().unwrap();
+
+        assert_eq!(renderer::replace_flow_status(None), None);
+        assert_eq!(
+            answers.into_entries(),
+            [
+                ("tls", Answer::from("Question 1 of 3")),
+                ("port", Answer::from("Question 2 of 2")),
+            ]
+        );
+    }
+
+    #[test]
+    fn time_out_steps() {
+        let header = || renderer::displayed_header().map(|text| layout::strip_escape_codes(&text));
+        let mut flow = Flow::new();
+
+        flow.step("name", |_| Err::<(), _>(Error::Timeout))
+            .time_limit(Duration::from_secs(60))
+            .step("age", move |_| Ok(header()))
+            .time_limit(Duration::from_secs(60))
+            .step("email", move |_| Ok(header()));
+
+        let answers = flow.run().unwrap();
+
+        assert_eq!(timer::remaining(), None);
+        assert_eq!(answers.timed_out(), ["name"]);
+        assert_eq!(
+            answers.into_entries(),
+            [
+                ("name", Answer::Unit),
+                ("age", Answer::from("Time left 1:00")),
+                ("email", Answer::Unit),
+            ]
+        );
+    }
+
+    #[test]
+    fn end_after_total_time_limit() {
+        let mut flow = Flow::new();
+
+        flow.total_time_limit(Duration::ZERO)
+            .step("name", |_| -> Result<(), _> { panic!("the time ran out") })
+            .step("age", |_| -> Result<(), _> { panic!("the time ran out") });
+
+        let answers = flow.run().unwrap();
+
+        assert_eq!(answers.timed_out(), ["name"]);
+        assert_eq!(answers.into_entries(), [("name", Answer::Unit)]);
+    }
+
+    #[test]
+    #[cfg(feature = "autosave")]
+    fn resume_autosaved_answers() {
+        let path = std::env::temp_dir().join(format!("asky_flow_{}.json", std::process::id()));
+        let mut flow = Flow::new();
+
+        flow.autosave(&path)
+            .step("name", |_| Ok("foo"))
+            .step("token", |_| Ok(Answer::Secret))
+            .step("port", |_| Err::<u16, _>(Error::Cancel));
+
+        assert!(matches!(flow.run(), Err(Error::Cancel)));
+        assert_eq!(
+            load_answers(&path).unwrap().into_map(),
+            [("name".to_string(), Answer::from("foo"))].into()
+        );
+
+        let mut flow = Flow::new();
+
+        flow.autosave(&path)
+            .resume_from(&path)
+            .step("name", |_| Ok("bar"))
+            .step("token", |_| Ok(Answer::Secret))
+            .step("port", |answers| {
+                assert_eq!(answers.get("name"), Some(&Answer::from("foo")));
+                Ok(8080u16)
+            });
+
+        let answers = flow.run().unwrap();
+
+        assert!(!path.exists());
+        assert_eq!(
+            answers.into_entries(),
+            [
+                ("name", Answer::from("foo")),
+                ("token", Answer::Secret),
+                ("port", Answer::Unsigned(8080)),
+            ]
+        );
+    }
+}
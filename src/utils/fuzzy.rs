@@ -0,0 +1,68 @@
+/// Fuzzy match a pattern against a text, ignoring the case.
+///
+/// All the characters of the pattern must appear in the text in the same order.
+/// Returns the score of the match, higher is better, and the char positions of the matched characters.
+pub fn fuzzy_match(pattern: &str, text: &str) -> Option<(i64, Vec<usize>)> {
+    let mut pattern = pattern.chars().peekable();
+    let mut positions: Vec<usize> = Vec::new();
+    let mut score = 0;
+    let mut prev_char = None;
+
+    for (i, c) in text.chars().enumerate() {
+        let Some(&p) = pattern.peek() else {
+            break;
+        };
+
+        if c.to_lowercase().eq(p.to_lowercase()) {
+            score += 1;
+
+            // consecutive matches, or gap since the last match (or the start)
+            match positions.last() {
+                Some(&last) if last + 1 == i => score += 5,
+                Some(&last) => score -= (i - last - 1).min(3) as i64,
+                None => score -= i.min(3) as i64,
+            }
+
+            // start of a word
+            if !prev_char.is_some_and(char::is_alphanumeric) {
+                score += 3;
+            }
+
+            positions.push(i);
+            pattern.next();
+        }
+
+        prev_char = Some(c);
+    }
+
+    match pattern.peek() {
+        Some(_) => None,
+        None => Some((score, positions)),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn match_subsequence() {
+        assert_eq!(fuzzy_match("", "foo"), Some((0, vec![])));
+        assert_eq!(fuzzy_match("rs", "Rust").map(|m| m.1), Some(vec![0, 2]));
+        assert_eq!(
+            fuzzy_match("JS", "javascript").map(|m| m.1),
+            Some(vec![0, 4])
+        );
+        assert_eq!(fuzzy_match("sr", "Rust"), None);
+        assert_eq!(fuzzy_match("rusty", "Rust"), None);
+    }
+
+    #[test]
+    fn prefer_contiguous_matches() {
+        let score = |pattern, text| fuzzy_match(pattern, text).unwrap().0;
+
+        assert!(score("sel", "select") > score("sel", "s_e_l"));
+        assert!(score("go", "Go") > score("go", "Kotlin Go"));
+        assert!(score("py", "Python") > score("py", "happy"));
+    }
+}
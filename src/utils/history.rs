@@ -0,0 +1,154 @@
+use std::{collections::VecDeque, io, path::PathBuf};
+
+use super::line_file;
+
+/// Previous answers of a [`Text`](crate::Text) prompt, recalled with `Up` and `Down`.
+///
+/// It keeps the latest `capacity` answers, dropping the oldest ones.
+/// Use [`History::load`] to persist the answers in a file, one per line.
+///
+/// # Examples
+///
+/// ```no_run
+/// use asky::{History, Text};
+///
+/// # fn main() -> std::io::Result<()> {
+/// let mut history = History::load(".commands_history", 100)?;
+///
+/// loop {
+///     let command = Text::new("Command").history(&mut history).prompt()?;
+///
+///     if command == "exit" {
+///         break;
+///     }
+/// }
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct History {
+    entries: VecDeque<String>,
+    capacity: usize,
+    path: Option<PathBuf>,
+}
+
+impl History {
+    /// Create an empty history that keeps up to `capacity` answers in memory.
+    pub fn new(capacity: usize) -> Self {
+        History {
+            entries: VecDeque::with_capacity(capacity),
+            capacity,
+            path: None,
+        }
+    }
+
+    /// Load the history from a file, that is updated with each new answer.
+    ///
+    /// The file is created with the first answer if it doesn't exist.
+    pub fn load(path: impl Into<PathBuf>, capacity: usize) -> io::Result<Self> {
+        let path = path.into();
+        let mut history = History::new(capacity);
+
+        for line in line_file::read_lines(&path)? {
+            history.add(&line);
+        }
+
+        history.path = Some(path);
+
+        Ok(history)
+    }
+
+    /// Returns the number of answers.
+    pub fn len(&self) -> usize {
+        self.entries.len()
+    }
+
+    /// Returns `true` if there are no answers.
+    pub fn is_empty(&self) -> bool {
+        self.entries.is_empty()
+    }
+
+    /// Returns the answers, from the oldest to the newest.
+    pub fn entries(&self) -> impl DoubleEndedIterator<Item = &str> {
+        self.entries.iter().map(String::as_str)
+    }
+
+    /// Add an answer, and save the history if it was loaded from a file.
+    ///
+    /// Empty answers and repeats of the last answer are ignored.
+    pub fn push(&mut self, entry: &str) -> io::Result<()> {
+        match self.add(entry) {
+            true => self.save(),
+            false => Ok(()),
+        }
+    }
+
+    /// Save the history to its file, if it was loaded from one.
+    pub fn save(&self) -> io::Result<()> {
+        let Some(path) = &self.path else {
+            return Ok(());
+        };
+
+        line_file::write_lines(path, &self.entries)
+    }
+
+    /// Returns the answer `offset` positions before the newest one.
+    pub(crate) fn recall(&self, offset: usize) -> Option<&str> {
+        self.entries().nth_back(offset)
+    }
+
+    /// Add an answer, returns whether the answers changed.
+    fn add(&mut self, entry: &str) -> bool {
+        // multiline answers would be split when loaded
+        let entry = entry.lines().next().unwrap_or_default();
+
+        if self.capacity == 0
+            || entry.trim().is_empty()
+            || self.entries.back().is_some_and(|last| last == entry)
+        {
+            return false;
+        }
+
+        if self.entries.len() == self.capacity {
+            self.entries.pop_front();
+        }
+
+        self.entries.push_back(entry.to_owned());
+
+        true
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn keep_latest_answers() {
+        let mut history = History::new(2);
+
+        for entry in ["foo", "foo", "", "bar", "baz"] {
+            history.push(entry).unwrap();
+        }
+
+        assert_eq!(history.entries().collect::<Vec<_>>(), ["bar", "baz"]);
+        assert_eq!(history.recall(0), Some("baz"));
+        assert_eq!(history.recall(1), Some("bar"));
+        assert_eq!(history.recall(2), None);
+    }
+
+    #[test]
+    fn persist_to_file() {
+        let path = std::env::temp_dir().join(format!("asky_history_{}", std::process::id()));
+        let mut history = History::load(&path, 2).unwrap();
+
+        history.push("foo").unwrap();
+        history.push("bar").unwrap();
+        history.push("baz").unwrap();
+
+        let loaded = History::load(&path, 2).unwrap();
+        std::fs::remove_file(&path).unwrap();
+
+        assert_eq!(loaded.entries().collect::<Vec<_>>(), ["bar", "baz"]);
+    }
+}
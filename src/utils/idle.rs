@@ -0,0 +1,96 @@
+use std::{
+    sync::{Arc, RwLock},
+    time::Duration,
+};
+
+use super::{renderer::Printable, state::PromptState};
+
+type Callback = dyn Fn(&PromptState) + Send + Sync;
+
+static IDLE: RwLock<Option<(Duration, Arc<Callback>)>> = RwLock::new(None);
+
+/// Set a function to call when the user hasn't pressed a key for `duration` in a prompt.
+///
+/// The prompt keeps waiting for keys, and it's drawn again after the call,
+/// so a hint set with [`set_header`](crate::set_header) is displayed right away.
+/// It's called once per idle period, the count restarts with each key.
+///
+/// Only sync prompts detect idle time, async ones never call it.
+///
+/// # Examples
+///
+/// ```no_run
+/// use std::time::Duration;
+/// use asky::{on_idle, set_header, Text};
+///
+/// # fn main() -> std::io::Result<()> {
+/// on_idle(Duration::from_secs(10), |_| set_header("Hint: press Esc to cancel"));
+///
+/// Text::new("What is your name?").prompt()?;
+/// # Ok(())
+/// # }
+/// ```
+pub fn on_idle<F>(duration: Duration, callback: F)
+where
+    F: Fn(&PromptState) + Send + Sync + 'static,
+{
+    replace_callback(Some((duration, Arc::new(callback))))
+}
+
+/// Remove the function set with [`on_idle`].
+pub fn clear_idle_callback() {
+    replace_callback(None)
+}
+
+fn replace_callback(callback: Option<(Duration, Arc<Callback>)>) {
+    match IDLE.write() {
+        Ok(mut current) => *current = callback,
+        Err(poisoned) => *poisoned.into_inner() = callback,
+    }
+}
+
+fn current() -> Option<(Duration, Arc<Callback>)> {
+    match IDLE.read() {
+        Ok(callback) => callback.clone(),
+        Err(poisoned) => poisoned.into_inner().clone(),
+    }
+}
+
+/// Returns the time to wait for a key before the prompt is idle, if a callback is set.
+pub(crate) fn timeout() -> Option<Duration> {
+    current().map(|(duration, _)| duration)
+}
+
+/// Call the idle callback with the state of the prompt, if both are available.
+pub(crate) fn notify(prompt: &impl Printable) {
+    if let (Some((_, callback)), Some(state)) = (current(), prompt.snapshot()) {
+        callback(&state);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::atomic::{AtomicUsize, Ordering};
+
+    use super::*;
+    use crate::Text;
+
+    #[test]
+    fn notify_idle_prompt() {
+        static CALLS: AtomicUsize = AtomicUsize::new(0);
+
+        on_idle(Duration::from_secs(5), |state| {
+            assert_eq!(state.message, "Name");
+            CALLS.fetch_add(1, Ordering::SeqCst);
+        });
+
+        assert_eq!(timeout(), Some(Duration::from_secs(5)));
+        notify(&Text::new("Name"));
+
+        clear_idle_callback();
+        notify(&Text::new("Name"));
+
+        assert_eq!(timeout(), None);
+        assert_eq!(CALLS.load(Ordering::SeqCst), 1);
+    }
+}
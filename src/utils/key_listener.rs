@@ -1,56 +1,363 @@
-use std::io;
+use std::{
+    sync::{Mutex, PoisonError},
+    time::Instant,
+};
+
+use crossterm::event::{Event, KeyCode, KeyEvent, KeyEventKind, KeyEventState, KeyModifiers};
 
-use crossterm::{
-    event::{read, Event, KeyCode, KeyEvent, KeyModifiers},
-    terminal,
+#[cfg(feature = "async")]
+use crossterm::event::EventStream;
+
+use super::{
+    answer_source,
+    error::Error,
+    idle, recording,
+    renderer::{self, CursorStyleGuard, DrawTime, KeyEnhancementGuard, Printable, Renderer},
+    settings::{Alert, AskySettings},
+    state, terminal_lock, timer,
 };
 
-use super::renderer::{Printable, Renderer};
+/// When the last prompt was answered or cancelled, or the alert was set before the first prompt,
+/// to emit the [`AskySettings::alert`].
+static LAST_PROMPT_END: Mutex<Option<Instant>> = Mutex::new(None);
 
 /// Trait used for the prompts to handle key events
 pub trait Typeable {
     /// Returns `true` if it should end to listen for more key events
     fn handle_key(&mut self, key: KeyEvent) -> bool;
+
+    /// Returns the key to look up a pre-seeded answer, see [`AnswerSource`].
+    ///
+    /// [`AnswerSource`]: super::answer_source::AnswerSource
+    fn answer_key(&self) -> Option<&str> {
+        None
+    }
+
+    /// Returns `true` if the typed characters must not be recorded, see [`record_keys`].
+    ///
+    /// [`record_keys`]: super::recording::record_keys
+    fn is_sensitive(&self) -> bool {
+        false
+    }
+
+    /// Returns `true` if the prompt handles modified keys that most terminals only report
+    /// with the keyboard enhancement protocol, like `Ctrl+Enter`.
+    fn needs_key_enhancement(&self) -> bool {
+        false
+    }
+
+    /// Apply a pre-seeded answer as if it was submitted, returns whether it's valid.
+    fn seed(&mut self, _value: &str) -> bool {
+        false
+    }
+
+    /// Prepare the prompt before displaying it, e.g. validating the initial value.
+    fn prepare(&mut self) {}
+}
+
+/// Map the navigation keys of the keyboard layout set in [`AskySettings`] to the QWERTY ones.
+///
+/// When the vim-like keys are disabled, the navigation keys are ignored. The QWERTY navigation
+/// keys are ignored too when they aren't navigation keys of the layout.
+///
+/// Must only be used by prompts without text input.
+pub fn navigation_key(code: KeyCode) -> KeyCode {
+    let settings = AskySettings::global();
+
+    match code {
+        KeyCode::Char(c)
+            if !settings.keybindings.vim_keys && settings.key_layout.is_navigation_key(c) =>
+        {
+            KeyCode::Null
+        }
+        KeyCode::Char(c) => settings
+            .key_layout
+            .to_qwerty(c)
+            .map_or(KeyCode::Null, KeyCode::Char),
+        _ => code,
+    }
 }
 
 /// Helper function to listen for key events and draw the prompt
-pub fn listen(prompt: &mut (impl Printable + Typeable), hide_cursor: bool) -> io::Result<()> {
+pub fn listen(prompt: &mut (impl Printable + Typeable), hide_cursor: bool) -> Result<(), Error> {
+    prompt.prepare();
+
+    if answer_source::fill(prompt)? {
+        return Ok(());
+    }
+
+    let _guard = terminal_lock::lock_terminal();
     let mut renderer = Renderer::new();
+    let _guards = start(prompt, &mut renderer, hide_cursor)?;
+
+    let mut submit = false;
+    let mut idle_at = idle_deadline();
 
-    prompt.draw(&mut renderer)?;
+    while !submit {
+        // wake up when the prompt is idle, or to update the countdown of the time limit
+        let timeout = [idle_at, timer::next_tick()]
+            .into_iter()
+            .flatten()
+            .min()
+            .map(|at| at.saturating_duration_since(Instant::now()));
 
-    if hide_cursor {
-        renderer.hide_cursor()?;
+        // raw mode to listen each key
+        renderer::set_raw_mode(true)?;
+        let event = recording::read_event(timeout, prompt.is_sensitive())?;
+        renderer::set_raw_mode(false)?;
+
+        let Some(event) = event else {
+            if timer::is_expired() {
+                return Err(handle_timeout(prompt, &mut renderer));
+            }
+
+            // notify once, until the next event
+            if idle_at.is_some_and(|at| at <= Instant::now()) {
+                idle_at = None;
+                idle::notify(prompt);
+            }
+
+            prompt.draw(&mut renderer)?;
+            continue;
+        };
+
+        idle_at = idle_deadline();
+        submit = handle_event(
+            prompt,
+            &mut renderer,
+            event,
+            AskySettings::global().exit_on_cancel,
+        )?;
     }
 
-    renderer.update_draw_time();
+    finish(prompt, &mut renderer, hide_cursor)
+}
+
+/// Async version of [`listen`], reading the keys from a terminal event stream.
+///
+/// The returned future is `Send` if the prompt is, e.g. when its closures are `Send`.
+#[cfg(feature = "async")]
+pub async fn listen_async(
+    prompt: &mut (impl Printable + Typeable),
+    hide_cursor: bool,
+) -> Result<(), Error> {
+    prompt.prepare();
+
+    if answer_source::fill(prompt)? {
+        return Ok(());
+    }
+
+    let _guard = terminal_lock::lock_terminal_async().await;
+    let mut renderer = Renderer::new();
+    let _guards = start(prompt, &mut renderer, hide_cursor)?;
 
+    let mut events = EventStream::new();
     let mut submit = false;
 
     while !submit {
         // raw mode to listen each key
-        terminal::enable_raw_mode()?;
-        let key = read()?;
-        terminal::disable_raw_mode()?;
+        renderer::set_raw_mode(true)?;
+        let event = recording::read_event_async(&mut events, prompt.is_sensitive()).await?;
+        renderer::set_raw_mode(false)?;
 
-        if let Event::Key(key) = key {
-            handle_abort(key, &mut renderer);
-            submit = prompt.handle_key(key);
-            prompt.draw(&mut renderer)?;
+        submit = handle_event(
+            prompt,
+            &mut renderer,
+            event,
+            AskySettings::global().exit_on_cancel,
+        )?;
+    }
+
+    finish(prompt, &mut renderer, hide_cursor)
+}
+
+/// Run the prompt with a script of events in another terminal, like the virtual one of the tests.
+///
+/// The answer sources are not consulted and the terminal is not set up, but the events are
+/// handled like in [`listen`]. Returns whether the prompt was submitted before the events ran out.
+pub(crate) fn listen_events<I>(
+    prompt: &mut (impl Printable + Typeable),
+    renderer: &mut Renderer,
+    events: I,
+    exit_on_cancel: bool,
+) -> Result<bool, Error>
+where
+    I: IntoIterator<Item = Event>,
+{
+    prompt.prepare();
+    draw_first(prompt, renderer)?;
+    renderer.update_draw_time();
+
+    for event in events {
+        if handle_event(prompt, renderer, event, exit_on_cancel)? {
+            finish(prompt, renderer, false)?;
+            return Ok(true);
         }
     }
 
+    Ok(false)
+}
+
+/// Returns when the prompt is idle, if an idle callback is set.
+fn idle_deadline() -> Option<Instant> {
+    idle::timeout().map(|timeout| Instant::now() + timeout)
+}
+
+/// Draw the first frame and set up the cursor and the keyboard.
+fn start(
+    prompt: &mut (impl Printable + Typeable),
+    renderer: &mut Renderer,
+    hide_cursor: bool,
+) -> Result<(Option<CursorStyleGuard>, Option<KeyEnhancementGuard>), Error> {
+    draw_first(prompt, renderer)?;
+
+    if hide_cursor {
+        renderer.hide_cursor()?;
+    }
+
+    let cursor_style = match (hide_cursor, AskySettings::global().cursor_style) {
+        (false, Some(style)) => Some(renderer.set_cursor_style(style)?),
+        _ => None,
+    };
+
+    let key_enhancement = match prompt.needs_key_enhancement() {
+        true => renderer.enable_key_enhancement()?,
+        false => None,
+    };
+
+    renderer.update_draw_time();
+
+    Ok((cursor_style, key_enhancement))
+}
+
+/// Draw the first frame and emit the alert, if the quiet period is over.
+fn draw_first(prompt: &impl Printable, renderer: &mut Renderer) -> Result<(), Error> {
+    prompt.draw(renderer)?;
+    state::notify(prompt);
+    alert_after_quiet_period(prompt, renderer)
+}
+
+/// Handle an event and draw the prompt again, returns whether the prompt was submitted.
+///
+/// When the prompt is cancelled, the process exits if `exit_on_cancel` is set.
+fn handle_event(
+    prompt: &mut (impl Printable + Typeable),
+    renderer: &mut Renderer,
+    event: Event,
+    exit_on_cancel: bool,
+) -> Result<bool, Error> {
+    // draw again to wrap the lines at the new width
+    if let Event::Resize(..) = event {
+        prompt.draw(renderer)?;
+        return Ok(false);
+    }
+
+    let Some(key) = normalize_key(event) else {
+        return Ok(false);
+    };
+
+    let key = AskySettings::global().keybindings.map_key(key);
+
+    if is_abort(key) {
+        return Err(handle_abort(prompt, renderer, exit_on_cancel));
+    }
+
+    let submit = prompt.handle_key(key);
+    prompt.draw(renderer)?;
+    state::notify(prompt);
+
+    Ok(submit)
+}
+
+/// Draw the last frame and restore the cursor.
+fn finish(
+    prompt: &mut impl Printable,
+    renderer: &mut Renderer,
+    hide_cursor: bool,
+) -> Result<(), Error> {
     renderer.update_draw_time();
+    mark_prompt_end();
 
     if hide_cursor {
         renderer.show_cursor()?;
     }
 
-    prompt.draw(&mut renderer)
+    Ok(prompt.draw(renderer)?)
+}
+
+/// Emit the alert set in [`AskySettings`] if no prompt was displayed for a while.
+fn alert_after_quiet_period(prompt: &impl Printable, renderer: &mut Renderer) -> Result<(), Error> {
+    let Some(alert) = quiet_period_alert(&AskySettings::global()) else {
+        return Ok(());
+    };
+
+    let message = prompt.snapshot().map(|state| state.message);
+    let message = message.as_deref().unwrap_or("Waiting for input");
+
+    Ok(renderer.alert(alert, message)?)
+}
+
+/// Returns the alert to emit if the quiet period of the settings is over.
+fn quiet_period_alert(settings: &AskySettings) -> Option<Alert> {
+    let last_end = *LAST_PROMPT_END
+        .lock()
+        .unwrap_or_else(PoisonError::into_inner);
+
+    match last_end {
+        Some(last_end) if last_end.elapsed() >= settings.alert_after => settings.alert,
+        _ => None,
+    }
+}
+
+/// Start counting the quiet period for the alert, if no prompt was displayed yet.
+pub(crate) fn start_quiet_period() {
+    LAST_PROMPT_END
+        .lock()
+        .unwrap_or_else(PoisonError::into_inner)
+        .get_or_insert_with(Instant::now);
+}
+
+fn mark_prompt_end() {
+    *LAST_PROMPT_END
+        .lock()
+        .unwrap_or_else(PoisonError::into_inner) = Some(Instant::now());
+}
+
+/// Normalize the key events that differ between terminals, so prompts behave the same.
+///
+/// - Key releases are ignored, some terminals report them.
+/// - Keypad keys are treated as the standard ones.
+/// - Line feed and carriage return (`Ctrl+J` and `Ctrl+M` in raw mode) are treated as `Enter`.
+pub(crate) fn normalize_key(event: Event) -> Option<KeyEvent> {
+    let Event::Key(mut key) = event else {
+        return None;
+    };
+
+    if key.kind == KeyEventKind::Release {
+        return None;
+    }
+
+    key.state.remove(KeyEventState::KEYPAD);
+
+    if let KeyCode::Char('j' | 'm' | '\n' | '\r') = key.code {
+        let is_newline = matches!(key.code, KeyCode::Char('\n' | '\r'))
+            || key.modifiers == KeyModifiers::CONTROL;
+
+        if is_newline {
+            key = KeyEvent::new_with_kind_and_state(
+                KeyCode::Enter,
+                KeyModifiers::NONE,
+                key.kind,
+                key.state,
+            );
+        }
+    }
+
+    Some(key)
 }
 
-fn handle_abort(ev: KeyEvent, renderer: &mut Renderer) {
-    let is_abort = matches!(
+pub(crate) fn is_abort(ev: KeyEvent) -> bool {
+    matches!(
         ev,
         KeyEvent {
             code: KeyCode::Esc,
@@ -60,10 +367,91 @@ fn handle_abort(ev: KeyEvent, renderer: &mut Renderer) {
             modifiers: KeyModifiers::CONTROL,
             ..
         }
-    );
+    )
+}
+
+/// Remove the prompt, or draw it as cancelled, and return [`Error::Cancel`].
+///
+/// Exit the process instead if `exit_on_cancel` is set.
+fn handle_abort(prompt: &impl Printable, renderer: &mut Renderer, exit_on_cancel: bool) -> Error {
+    renderer.show_cursor().ok();
+    mark_prompt_end();
 
-    if is_abort {
-        renderer.show_cursor().ok();
+    let result = match AskySettings::global().keep_cancelled {
+        true => {
+            renderer.draw_time = DrawTime::Cancelled;
+            prompt.draw(renderer)
+        }
+        false => renderer.clear(),
+    };
+
+    if exit_on_cancel {
+        renderer::reset_cursor_style().ok();
         std::process::exit(1)
     }
+
+    match result {
+        Ok(_) => Error::Cancel,
+        Err(err) => Error::Io(err),
+    }
+}
+
+/// Remove the prompt, or draw it as cancelled, and return [`Error::Timeout`].
+fn handle_timeout(prompt: &impl Printable, renderer: &mut Renderer) -> Error {
+    match handle_abort(prompt, renderer, false) {
+        Error::Cancel => Error::Timeout,
+        err => err,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+
+    use super::*;
+
+    #[test]
+    fn alert_first_prompt() {
+        let mut settings = AskySettings {
+            alert: Some(Alert::Bell),
+            alert_after: Duration::ZERO,
+            ..AskySettings::default()
+        };
+
+        start_quiet_period();
+        assert_eq!(quiet_period_alert(&settings), Some(Alert::Bell));
+
+        settings.alert_after = Duration::from_secs(3600);
+        assert_eq!(quiet_period_alert(&settings), None);
+    }
+
+    #[test]
+    fn normalize_keys() {
+        let keypad = KeyEvent::new_with_kind_and_state(
+            KeyCode::Char('5'),
+            KeyModifiers::NONE,
+            KeyEventKind::Press,
+            KeyEventState::KEYPAD,
+        );
+        let release = KeyEvent::new_with_kind(
+            KeyCode::Char('a'),
+            KeyModifiers::NONE,
+            KeyEventKind::Release,
+        );
+        let line_feed = KeyEvent::new(KeyCode::Char('j'), KeyModifiers::CONTROL);
+
+        assert_eq!(
+            normalize_key(Event::Key(keypad)),
+            Some(KeyEvent::from(KeyCode::Char('5')))
+        );
+        assert_eq!(normalize_key(Event::Key(release)), None);
+        assert_eq!(
+            normalize_key(Event::Key(line_feed)),
+            Some(KeyEvent::from(KeyCode::Enter))
+        );
+        assert_eq!(
+            normalize_key(Event::Key(KeyEvent::from(KeyCode::Char('j')))),
+            Some(KeyEvent::from(KeyCode::Char('j')))
+        );
+    }
 }
@@ -0,0 +1,120 @@
+use std::{collections::BTreeMap, io, path::PathBuf};
+
+use super::line_file;
+
+/// Last choices of named [`Select`](crate::Select) and [`MultiSelect`](crate::MultiSelect)
+/// prompts, to focus them again the next time.
+///
+/// The choices are saved by the prompt name and the option titles,
+/// use [`LastChoices::load`] to persist them in a file.
+///
+/// # Examples
+///
+/// ```no_run
+/// use asky::{LastChoices, Select};
+///
+/// # fn main() -> std::io::Result<()> {
+/// let mut choices = LastChoices::load(".last_choices")?;
+///
+/// let env = Select::new("Deploy to", ["staging", "production"])
+///     .name("deploy.env")
+///     .remember_last(&mut choices)
+///     .prompt()?;
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct LastChoices {
+    entries: BTreeMap<String, Vec<String>>,
+    path: Option<PathBuf>,
+}
+
+impl LastChoices {
+    /// Create an empty store, kept in memory.
+    pub fn new() -> Self {
+        LastChoices::default()
+    }
+
+    /// Load the choices from a file, that is updated with each new choice.
+    ///
+    /// The file is created with the first choice if it doesn't exist.
+    pub fn load(path: impl Into<PathBuf>) -> io::Result<Self> {
+        let path = path.into();
+        let mut choices = LastChoices::new();
+
+        for line in line_file::read_lines(&path)? {
+            let mut fields = line.split('\t').map(String::from);
+
+            if let Some(name) = fields.next().filter(|name| !name.is_empty()) {
+                choices.entries.insert(name, fields.collect());
+            }
+        }
+
+        choices.path = Some(path);
+
+        Ok(choices)
+    }
+
+    /// Returns the titles of the last chosen options of a prompt.
+    pub fn get(&self, name: &str) -> Option<&[String]> {
+        self.entries.get(name).map(Vec::as_slice)
+    }
+
+    /// Set the titles of the chosen options of a prompt, and save them if loaded from a file.
+    ///
+    /// Titles with tabs or line breaks are not remembered.
+    pub fn set(&mut self, name: &str, titles: Vec<String>) -> io::Result<()> {
+        let titles: Vec<String> = titles.into_iter().filter(|t| is_storable(t)).collect();
+
+        if !is_storable(name) || self.get(name) == Some(&titles) {
+            return Ok(());
+        }
+
+        self.entries.insert(name.to_owned(), titles);
+        self.save()
+    }
+
+    /// Save the choices to their file, if they were loaded from one.
+    pub fn save(&self) -> io::Result<()> {
+        let Some(path) = &self.path else {
+            return Ok(());
+        };
+
+        let lines = self
+            .entries
+            .iter()
+            .map(|(name, titles)| match titles.is_empty() {
+                true => name.clone(),
+                false => format!("{}\t{}", name, titles.join("\t")),
+            });
+
+        line_file::write_lines(path, lines)
+    }
+}
+
+fn is_storable(text: &str) -> bool {
+    !text.is_empty() && !text.contains(['\t', '\n', '\r'])
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn persist_to_file() {
+        let path = std::env::temp_dir().join(format!("asky_choices_{}", std::process::id()));
+        let mut choices = LastChoices::load(&path).unwrap();
+
+        choices.set("env", vec![String::from("staging")]).unwrap();
+        choices
+            .set("tags", vec![String::from("a"), String::from("b\tc")])
+            .unwrap();
+
+        let loaded = LastChoices::load(&path).unwrap();
+        std::fs::remove_file(&path).unwrap();
+
+        assert_eq!(loaded.get("env"), Some(&[String::from("staging")][..]));
+        assert_eq!(loaded.get("tags"), Some(&[String::from("a")][..]));
+        assert_eq!(loaded.get("other"), None);
+    }
+}
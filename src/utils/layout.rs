@@ -0,0 +1,151 @@
+use crossterm::terminal;
+use unicode_width::UnicodeWidthChar;
+
+use super::renderer;
+
+/// Layout information of the terminal, to help custom formatters render safely.
+///
+/// The renderer restores the cursor by counting the rows of each frame, including the lines
+/// wrapped by the terminal. Use [`LayoutContext::rows`] and [`LayoutContext::cursor_after`]
+/// in custom formatters to get the same numbers.
+///
+/// # Examples
+///
+/// ```no_run
+/// use asky::{LayoutContext, Text};
+///
+/// # fn main() -> std::io::Result<()> {
+/// Text::new("What is your name?")
+///     .format(|prompt, _draw_time| {
+///         let layout = LayoutContext::current();
+///         let head = format!("{}\n> ", prompt.message);
+///         let mut cursor = layout.cursor_after(&head);
+///
+///         cursor[0] += prompt.input.cursor_width();
+///
+///         (format!("{}{}", head, prompt.input.value), cursor)
+///     })
+///     .prompt()?;
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct LayoutContext {
+    /// Width of the terminal, in columns.
+    pub width: usize,
+    /// Rows used by the header above the prompt, see [`set_header`](crate::set_header).
+    ///
+    /// They are already added by the renderer, the cursor positions are relative to the prompt.
+    pub header_lines: usize,
+}
+
+impl LayoutContext {
+    /// Returns the layout of the current terminal.
+    pub fn current() -> Self {
+        let width = terminal::size()
+            .map(|(width, _)| width as usize)
+            .unwrap_or(80);
+
+        let mut layout = LayoutContext {
+            width,
+            header_lines: 0,
+        };
+
+        if let Some(header) = renderer::displayed_header() {
+            layout.header_lines = layout.rows(&header).max(1);
+        }
+
+        layout
+    }
+
+    /// Returns the number of rows the text takes when printed, including wrapped lines.
+    ///
+    /// Color and style escape codes are ignored.
+    pub fn rows(&self, text: &str) -> usize {
+        text.lines().map(|line| self.line_rows(line)).sum()
+    }
+
+    /// Returns the `[x, y]` position of the cursor after printing the text,
+    /// relative to the start of the text.
+    pub fn cursor_after(&self, text: &str) -> [usize; 2] {
+        let mut lines = text.split('\n');
+        let last = lines.next_back().unwrap_or_default();
+        let rows: usize = lines.map(|line| self.line_rows(line)).sum();
+        let width = visible_width(last);
+
+        match self.width {
+            0 => [width, rows],
+            w => [width % w, rows + width / w],
+        }
+    }
+
+    fn line_rows(&self, line: &str) -> usize {
+        let width = visible_width(line);
+
+        match (width, self.width) {
+            (0, _) | (_, 0) => 1,
+            (width, w) => width.div_ceil(w),
+        }
+    }
+}
+
+/// Width of the text in the terminal, without the escape codes.
+fn visible_width(text: &str) -> usize {
+    visible_chars(text).map(|c| c.width().unwrap_or(0)).sum()
+}
+
+/// Remove the color and style escape codes of the text.
+pub(crate) fn strip_escape_codes(text: &str) -> String {
+    visible_chars(text).collect()
+}
+
+fn visible_chars(text: &str) -> impl Iterator<Item = char> + '_ {
+    let mut chars = text.chars();
+
+    std::iter::from_fn(move || loop {
+        let c = chars.next()?;
+
+        if c != '\x1b' {
+            return Some(c);
+        }
+
+        // skip the escape sequence, until its final byte
+        if chars.next() == Some('[') {
+            chars.by_ref().find(|c| ('@'..='~').contains(c));
+        }
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const LAYOUT: LayoutContext = LayoutContext {
+        width: 10,
+        header_lines: 0,
+    };
+
+    #[test]
+    fn count_wrapped_rows() {
+        assert_eq!(LAYOUT.rows("foo\nbar"), 2);
+        assert_eq!(LAYOUT.rows("foo\n\nbar\n"), 3);
+        assert_eq!(LAYOUT.rows("0123456789"), 1);
+        assert_eq!(LAYOUT.rows("0123456789a\nfoo"), 3);
+        assert_eq!(LAYOUT.rows("\x1b[34m0123456789\x1b[0m"), 1);
+        assert_eq!(LAYOUT.rows("日本語日本語"), 2);
+    }
+
+    #[test]
+    fn get_cursor_after_text() {
+        assert_eq!(LAYOUT.cursor_after(""), [0, 0]);
+        assert_eq!(LAYOUT.cursor_after("foo\n> "), [2, 1]);
+        assert_eq!(LAYOUT.cursor_after("0123456789abc\n> "), [2, 2]);
+        assert_eq!(LAYOUT.cursor_after("\x1b[1m> \x1b[0m0123456789"), [2, 1]);
+    }
+
+    #[test]
+    fn remove_escape_codes() {
+        assert_eq!(strip_escape_codes("\x1b[34m›\x1b[0m foo"), "› foo");
+        assert_eq!(strip_escape_codes("foo\nbar"), "foo\nbar");
+    }
+}
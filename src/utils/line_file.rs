@@ -0,0 +1,48 @@
+use std::{
+    fmt::Display,
+    fs,
+    io::{self, Write},
+    path::Path,
+};
+
+/// Read the lines of a file, without any if it doesn't exist.
+pub(crate) fn read_lines(path: &Path) -> io::Result<Vec<String>> {
+    match fs::read_to_string(path) {
+        Ok(text) => Ok(text.lines().map(String::from).collect()),
+        Err(err) if err.kind() == io::ErrorKind::NotFound => Ok(Vec::new()),
+        Err(err) => Err(err),
+    }
+}
+
+/// Write the lines to a file, creating it or replacing its content.
+pub(crate) fn write_lines<I>(path: &Path, lines: I) -> io::Result<()>
+where
+    I: IntoIterator,
+    I::Item: Display,
+{
+    let mut file = io::BufWriter::new(fs::File::create(path)?);
+
+    for line in lines {
+        writeln!(file, "{}", line)?;
+    }
+
+    file.flush()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn read_written_lines() {
+        let path = std::env::temp_dir().join(format!("asky_lines_{}", std::process::id()));
+
+        assert!(read_lines(&path).unwrap().is_empty());
+
+        write_lines(&path, ["foo", "", "bar baz"]).unwrap();
+        let lines = read_lines(&path).unwrap();
+        fs::remove_file(&path).unwrap();
+
+        assert_eq!(lines, ["foo", "", "bar baz"]);
+    }
+}
@@ -1,4 +1,23 @@
+pub mod answer;
+pub mod answer_source;
+pub mod error;
+pub mod flow;
+pub mod fuzzy;
+pub mod history;
+pub mod idle;
 pub mod key_listener;
+pub mod last_choices;
+pub mod layout;
+pub mod line_file;
 pub mod num_like;
+pub mod recording;
 pub mod renderer;
+pub mod settings;
+pub mod state;
+pub mod summary;
+#[cfg(feature = "survey")]
+pub mod survey;
+pub mod terminal_lock;
 pub mod theme;
+pub mod timer;
+pub mod virtual_terminal;
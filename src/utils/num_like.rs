@@ -1,10 +1,12 @@
 use std::{fmt::Display, str::FromStr};
 
+use crate::Answer;
+
 /// A utility trait to allow only numbers in [`Number`] prompt.
 /// Also allows to custom handle they based on the type.
 ///
 /// [`Number`]: crate::Number
-pub trait NumLike: Default + Display + FromStr {
+pub trait NumLike: Default + Display + FromStr + PartialOrd + Clone {
     /// Check if it is a floating point number.
     fn is_float() -> bool {
         false
@@ -14,67 +16,163 @@ pub trait NumLike: Default + Display + FromStr {
     fn is_signed() -> bool {
         false
     }
-}
 
-impl NumLike for u8 {}
-impl NumLike for u16 {}
-impl NumLike for u32 {}
-impl NumLike for u64 {}
-impl NumLike for u128 {}
-impl NumLike for usize {}
+    /// Returns the default step to increment or decrement the number, `1` by default.
+    fn unit() -> Self {
+        "1".parse().unwrap_or_default()
+    }
 
-impl NumLike for i8 {
-    fn is_signed() -> bool {
-        true
+    /// Add `step` to the number, saturating at the bounds of the type.
+    ///
+    /// By default the number is not changed, so the arrow keys do nothing.
+    fn add_step(&self, _step: &Self) -> Self {
+        self.clone()
     }
-}
 
-impl NumLike for i16 {
-    fn is_signed() -> bool {
-        true
+    /// Subtract `step` from the number, saturating at the bounds of the type.
+    ///
+    /// By default the number is not changed, so the arrow keys do nothing.
+    fn sub_step(&self, _step: &Self) -> Self {
+        self.clone()
     }
-}
 
-impl NumLike for i32 {
-    fn is_signed() -> bool {
-        true
+    /// Returns the answer saved in [`Answers`], the text of the number by default.
+    ///
+    /// [`Answers`]: crate::Answers
+    fn to_answer(&self) -> Answer {
+        Answer::Text(self.to_string())
     }
-}
 
-impl NumLike for i64 {
-    fn is_signed() -> bool {
-        true
+    /// Parse a number in the given radix, without prefix. Only supported by integers.
+    fn from_str_radix(_src: &str, _radix: u32) -> Option<Self> {
+        None
     }
 }
 
-impl NumLike for i128 {
-    fn is_signed() -> bool {
-        true
-    }
+macro_rules! impl_int {
+    ($($ty:ty => $signed:literal),*) => {
+        $(
+            impl NumLike for $ty {
+                fn is_signed() -> bool {
+                    $signed
+                }
+
+                fn unit() -> Self {
+                    1
+                }
+
+                fn add_step(&self, step: &Self) -> Self {
+                    self.saturating_add(*step)
+                }
+
+                fn sub_step(&self, step: &Self) -> Self {
+                    self.saturating_sub(*step)
+                }
+
+                fn to_answer(&self) -> Answer {
+                    Answer::from(*self)
+                }
+
+                fn from_str_radix(src: &str, radix: u32) -> Option<Self> {
+                    <$ty>::from_str_radix(src, radix).ok()
+                }
+            }
+        )*
+    };
 }
 
-impl NumLike for isize {
-    fn is_signed() -> bool {
-        true
-    }
+macro_rules! impl_float {
+    ($($ty:ty),*) => {
+        $(
+            impl NumLike for $ty {
+                fn is_signed() -> bool {
+                    true
+                }
+
+                fn is_float() -> bool {
+                    true
+                }
+
+                fn unit() -> Self {
+                    1.0
+                }
+
+                fn to_answer(&self) -> Answer {
+                    Answer::from(*self)
+                }
+
+                fn add_step(&self, step: &Self) -> Self {
+                    round_to(self + step, decimals(self).max(decimals(step)))
+                }
+
+                fn sub_step(&self, step: &Self) -> Self {
+                    round_to(self - step, decimals(self).max(decimals(step)))
+                }
+            }
+        )*
+    };
 }
 
-impl NumLike for f32 {
-    fn is_signed() -> bool {
-        true
-    }
+/// Returns the number of decimal places of the shortest representation of the number.
+fn decimals(value: &impl Display) -> usize {
+    let value = value.to_string();
+    value.find('.').map_or(0, |dot| value.len() - dot - 1)
+}
 
-    fn is_float() -> bool {
-        true
-    }
+/// Round the number to the decimal places, to avoid errors like `0.1 + 0.2 = 0.30000000000000004`.
+fn round_to<T: Display + FromStr + Copy>(value: T, decimals: usize) -> T {
+    format!("{value:.decimals$}").parse().unwrap_or(value)
 }
 
-impl NumLike for f64 {
-    fn is_signed() -> bool {
-        true
+impl_int!(u8 => false, u16 => false, u32 => false, u64 => false, u128 => false, usize => false);
+impl_int!(i8 => true, i16 => true, i32 => true, i64 => true, i128 => true, isize => true);
+impl_float!(f32, f64);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn float_step_keeps_decimals() {
+        let mut value = 0.0_f64;
+
+        for _ in 0..3 {
+            value = value.add_step(&0.1);
+        }
+
+        assert_eq!(value, 0.3);
+        assert_eq!(0.05_f64.add_step(&0.1), 0.15);
+        assert_eq!(0.3_f32.sub_step(&0.1), 0.2);
+        assert_eq!(1e20_f64.add_step(&1.0), 1e20);
     }
 
-    fn is_float() -> bool {
-        true
+    #[test]
+    fn default_step() {
+        #[derive(Debug, Default, Clone, PartialEq, PartialOrd)]
+        struct Even(u32);
+
+        impl Display for Even {
+            fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                self.0.fmt(f)
+            }
+        }
+
+        impl FromStr for Even {
+            type Err = ();
+
+            fn from_str(s: &str) -> Result<Self, Self::Err> {
+                match s.parse() {
+                    Ok(n) if n % 2 == 0 => Ok(Even(n)),
+                    _ => Err(()),
+                }
+            }
+        }
+
+        impl NumLike for Even {}
+
+        assert_eq!(Even::unit(), Even(0));
+        assert_eq!(Even(4).to_answer(), Answer::from("4"));
+        assert_eq!(Even(2).add_step(&Even::unit()), Even(2));
+        assert_eq!(i8::unit(), 1);
     }
 }
@@ -0,0 +1,302 @@
+use std::{
+    collections::VecDeque,
+    fs::{self, File},
+    io::{self, BufWriter, Write},
+    path::Path,
+    sync::{Mutex, MutexGuard, PoisonError},
+    thread,
+    time::{Duration, Instant},
+};
+
+use crossterm::event::{self, Event, KeyCode, KeyEvent, KeyEventKind, KeyModifiers};
+
+#[cfg(feature = "async")]
+use crossterm::event::EventStream;
+#[cfg(feature = "async")]
+use futures_core::Stream;
+#[cfg(feature = "async")]
+use std::{future, pin::Pin};
+
+struct Recording {
+    file: BufWriter<File>,
+    last_event: Instant,
+}
+
+struct Replay {
+    events: VecDeque<(Duration, KeyEvent)>,
+    speed: f32,
+}
+
+struct Session {
+    recording: Option<Recording>,
+    replay: Option<Replay>,
+}
+
+static SESSION: Mutex<Session> = Mutex::new(Session {
+    recording: None,
+    replay: None,
+});
+
+fn lock_session() -> MutexGuard<'static, Session> {
+    SESSION.lock().unwrap_or_else(PoisonError::into_inner)
+}
+
+/// Start recording the key events of the following prompts into a file.
+///
+/// Each event is saved with the delay since the previous one, to be replayed with [`replay_keys`].
+/// The characters typed in [`Password`](crate::Password) and sensitive prompts, like
+/// [`Text::sensitive`](crate::Text::sensitive), are saved as `secret` and replayed as no key,
+/// so they must be typed again.
+/// The recording continues until [`stop_recording`] is called.
+pub fn record_keys(path: impl AsRef<Path>) -> io::Result<()> {
+    let file = BufWriter::new(File::create(path)?);
+
+    lock_session().recording = Some(Recording {
+        file,
+        last_event: Instant::now(),
+    });
+
+    Ok(())
+}
+
+/// Stop the recording started with [`record_keys`].
+pub fn stop_recording() -> io::Result<()> {
+    match lock_session().recording.take() {
+        Some(mut recording) => recording.file.flush(),
+        None => Ok(()),
+    }
+}
+
+/// Replay the key events recorded with [`record_keys`] in the following prompts.
+///
+/// `speed` multiplies the original pace, e.g. `2.0` replays twice as fast, and `0.0` without delays.
+/// When all the events are consumed, the prompts read the keys from the terminal again.
+///
+/// Async prompts replay the events without delays, to not block the runtime.
+pub fn replay_keys(path: impl AsRef<Path>, speed: f32) -> io::Result<()> {
+    let events = fs::read_to_string(path)?
+        .lines()
+        .filter(|line| !line.trim().is_empty())
+        .map(|line| {
+            parse_line(line).ok_or_else(|| {
+                io::Error::new(io::ErrorKind::InvalidData, format!("invalid event: {line}"))
+            })
+        })
+        .collect::<io::Result<_>>()?;
+
+    lock_session().replay = Some(Replay { events, speed });
+
+    Ok(())
+}
+
+/// Read the next event from the replay, or from the terminal.
+///
+/// Returns `None` if the terminal gets no event in `timeout`, replayed events are not affected.
+/// The characters are not recorded if `sensitive`.
+pub fn read_event(timeout: Option<Duration>, sensitive: bool) -> io::Result<Option<Event>> {
+    if let Some((delay, key)) = next_replayed() {
+        thread::sleep(delay);
+        return Ok(Some(Event::Key(key)));
+    }
+
+    if let Some(timeout) = timeout {
+        if !event::poll(timeout)? {
+            return Ok(None);
+        }
+    }
+
+    let event = event::read()?;
+    record_event(&event, sensitive)?;
+
+    Ok(Some(event))
+}
+
+/// Read the next event from the replay, or from the terminal event stream.
+#[cfg(feature = "async")]
+pub async fn read_event_async(events: &mut EventStream, sensitive: bool) -> io::Result<Event> {
+    if let Some((_, key)) = next_replayed() {
+        return Ok(Event::Key(key));
+    }
+
+    let event = future::poll_fn(|cx| Pin::new(&mut *events).poll_next(cx))
+        .await
+        .unwrap_or_else(|| Err(io::Error::from(io::ErrorKind::UnexpectedEof)))?;
+    record_event(&event, sensitive)?;
+
+    Ok(event)
+}
+
+fn record_event(event: &Event, sensitive: bool) -> io::Result<()> {
+    let Event::Key(mut key) = *event else {
+        return Ok(());
+    };
+
+    // the prompts ignore the releases, and the format doesn't keep the kind
+    if key.kind == KeyEventKind::Release {
+        return Ok(());
+    }
+
+    if let (KeyCode::Char(_), true) = (key.code, sensitive) {
+        key = KeyEvent::new(KeyCode::Null, KeyModifiers::NONE);
+    }
+
+    let mut session = lock_session();
+
+    if let Some(recording) = session.recording.as_mut() {
+        let delay = recording.last_event.elapsed();
+
+        recording.last_event = Instant::now();
+        writeln!(recording.file, "{}", format_line(delay, key))?;
+        recording.file.flush()?;
+    }
+
+    Ok(())
+}
+
+fn next_replayed() -> Option<(Duration, KeyEvent)> {
+    let mut session = lock_session();
+    let replay = session.replay.as_mut()?;
+
+    match replay.events.pop_front() {
+        Some((delay, key)) => {
+            let delay = match replay.speed > 0.0 {
+                true => delay.div_f32(replay.speed),
+                false => Duration::ZERO,
+            };
+
+            Some((delay, key))
+        }
+        None => {
+            session.replay = None;
+            None
+        }
+    }
+}
+
+// region: format
+
+fn format_line(delay: Duration, key: KeyEvent) -> String {
+    let code = match key.code {
+        KeyCode::Char(c) => format!("char:{}", c as u32),
+        KeyCode::F(n) => format!("f:{n}"),
+        KeyCode::Enter => String::from("enter"),
+        KeyCode::Backspace => String::from("backspace"),
+        KeyCode::Delete => String::from("delete"),
+        KeyCode::Left => String::from("left"),
+        KeyCode::Right => String::from("right"),
+        KeyCode::Up => String::from("up"),
+        KeyCode::Down => String::from("down"),
+        KeyCode::Home => String::from("home"),
+        KeyCode::End => String::from("end"),
+        KeyCode::PageUp => String::from("pageup"),
+        KeyCode::PageDown => String::from("pagedown"),
+        KeyCode::Tab => String::from("tab"),
+        KeyCode::BackTab => String::from("backtab"),
+        KeyCode::Insert => String::from("insert"),
+        KeyCode::Esc => String::from("esc"),
+        KeyCode::Null => String::from("secret"),
+        _ => String::from("unknown"),
+    };
+
+    format!("{} {} {}", delay.as_millis(), code, key.modifiers.bits())
+}
+
+fn parse_line(line: &str) -> Option<(Duration, KeyEvent)> {
+    let mut parts = line.split_whitespace();
+
+    let delay = Duration::from_millis(parts.next()?.parse().ok()?);
+    let code = match parts.next()? {
+        "enter" => KeyCode::Enter,
+        "backspace" => KeyCode::Backspace,
+        "delete" => KeyCode::Delete,
+        "left" => KeyCode::Left,
+        "right" => KeyCode::Right,
+        "up" => KeyCode::Up,
+        "down" => KeyCode::Down,
+        "home" => KeyCode::Home,
+        "end" => KeyCode::End,
+        "pageup" => KeyCode::PageUp,
+        "pagedown" => KeyCode::PageDown,
+        "tab" => KeyCode::Tab,
+        "backtab" => KeyCode::BackTab,
+        "insert" => KeyCode::Insert,
+        "esc" => KeyCode::Esc,
+        "secret" | "unknown" => KeyCode::Null,
+        code => match code.split_once(':')? {
+            ("char", c) => KeyCode::Char(char::from_u32(c.parse().ok()?)?),
+            ("f", n) => KeyCode::F(n.parse().ok()?),
+            _ => return None,
+        },
+    };
+    let modifiers = KeyModifiers::from_bits(parts.next()?.parse().ok()?)?;
+
+    Some((delay, KeyEvent::new(code, modifiers)))
+}
+
+// endregion: format
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::utils::key_listener::Typeable;
+
+    #[test]
+    fn format_and_parse_events() {
+        let events = [
+            KeyEvent::from(KeyCode::Char('a')),
+            KeyEvent::from(KeyCode::Char(' ')),
+            KeyEvent::from(KeyCode::Char('ñ')),
+            KeyEvent::from(KeyCode::Enter),
+            KeyEvent::from(KeyCode::F(2)),
+            KeyEvent::new(KeyCode::Char('w'), KeyModifiers::CONTROL),
+        ];
+
+        for key in events {
+            let delay = Duration::from_millis(120);
+            let line = format_line(delay, key);
+
+            assert_eq!(parse_line(&line), Some((delay, key)));
+        }
+    }
+
+    #[test]
+    fn hide_password_keys() {
+        let path = std::env::temp_dir().join(format!("asky_recording_{}", std::process::id()));
+        let password = crate::Password::new("Password");
+        let release = KeyEvent::new_with_kind(
+            KeyCode::Char('a'),
+            KeyModifiers::NONE,
+            KeyEventKind::Release,
+        );
+
+        record_keys(&path).unwrap();
+
+        for key in [
+            KeyEvent::from(KeyCode::Char('a')),
+            release,
+            KeyEvent::from(KeyCode::Char('b')),
+            KeyEvent::from(KeyCode::Enter),
+        ] {
+            record_event(&Event::Key(key), password.is_sensitive()).unwrap();
+        }
+
+        stop_recording().unwrap();
+
+        let recorded = fs::read_to_string(&path).unwrap();
+        fs::remove_file(&path).ok();
+
+        let codes: Vec<&str> = recorded
+            .lines()
+            .filter_map(|line| line.split_whitespace().nth(1))
+            .collect();
+
+        assert_eq!(codes, ["secret", "secret", "enter"]);
+    }
+
+    #[test]
+    fn reject_invalid_lines() {
+        assert_eq!(parse_line("foo enter 0"), None);
+        assert_eq!(parse_line("10 bar 0"), None);
+        assert_eq!(parse_line("10 char:x 0"), None);
+    }
+}
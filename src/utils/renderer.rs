@@ -1,9 +1,47 @@
-use std::io::{self, Write};
+use std::{
+    cell::RefCell,
+    io::{self, Write},
+    sync::{Mutex, MutexGuard, PoisonError, RwLock},
+};
 
-use crossterm::{cursor, execute, queue, style::Print, terminal};
+use crossterm::{
+    cursor,
+    event::{KeyboardEnhancementFlags, PopKeyboardEnhancementFlags, PushKeyboardEnhancementFlags},
+    execute, queue,
+    style::Print,
+    terminal,
+};
+
+use super::{
+    layout::{self, LayoutContext},
+    settings::{Alert, AskySettings},
+    state::PromptState,
+    theme, timer,
+};
+
+/// Output of the renderer, the real terminal or a virtual one in the tests.
+pub(crate) trait Terminal: Write {
+    /// Returns the layout used to count the rows of the printed text.
+    fn layout(&self) -> LayoutContext {
+        LayoutContext::current()
+    }
+}
+
+impl Terminal for io::Stdout {}
+
+impl Terminal for Box<dyn Terminal> {
+    fn layout(&self) -> LayoutContext {
+        (**self).layout()
+    }
+}
 
 pub trait Printable {
     fn draw(&self, renderer: &mut Renderer) -> io::Result<()>;
+
+    /// State of the prompt for the observer, see [`set_state_observer`](crate::set_state_observer).
+    fn snapshot(&self) -> Option<PromptState> {
+        None
+    }
 }
 
 /// Enum that indicates the current draw time to format closures.
@@ -16,18 +54,281 @@ pub enum DrawTime {
     Update,
     /// The last time that a prompt is displayed.
     Last,
+    /// The last time that a cancelled prompt is displayed,
+    /// only when [`AskySettings::keep_cancelled`] is set.
+    Cancelled,
+}
+
+impl DrawTime {
+    /// Returns `true` if it's the last time that the prompt is displayed, answered or cancelled.
+    pub fn is_final(self) -> bool {
+        matches!(self, DrawTime::Last | DrawTime::Cancelled)
+    }
+}
+
+static HEADER: RwLock<Option<String>> = RwLock::new(None);
+
+/// Set a header to display above the prompts, like `"Step 2/5 — Database configuration"`.
+///
+/// The header is displayed above each prompt until it is answered,
+/// then it's cleared with the prompt to only keep the answer.
+pub fn set_header(header: impl Into<String>) {
+    replace_header(Some(header.into()))
+}
+
+/// Remove the header set with [`set_header`].
+pub fn clear_header() {
+    replace_header(None)
+}
+
+fn replace_header(header: Option<String>) {
+    match HEADER.write() {
+        Ok(mut current) => *current = header,
+        Err(poisoned) => *poisoned.into_inner() = header,
+    }
+}
+
+pub(crate) fn get_header() -> Option<String> {
+    match HEADER.read() {
+        Ok(header) => header.clone(),
+        Err(poisoned) => poisoned.into_inner().clone(),
+    }
+}
+
+thread_local! {
+    /// Status of the flow running in this thread, like its progress, displayed below the header.
+    static FLOW_STATUS: RefCell<Option<String>> = const { RefCell::new(None) };
+}
+
+/// Replace the status of the flow running in this thread, returning the previous one.
+pub(crate) fn replace_flow_status(status: Option<String>) -> Option<String> {
+    FLOW_STATUS.replace(status)
+}
+
+/// Returns the header, the flow status and the countdown to display above the prompt, if any.
+pub(crate) fn displayed_header() -> Option<String> {
+    let countdown = timer::remaining().map(theme::fmt_countdown);
+    let lines: Vec<String> = [
+        get_header(),
+        FLOW_STATUS.with_borrow(Clone::clone),
+        countdown,
+    ]
+    .into_iter()
+    .flatten()
+    .collect();
+
+    (!lines.is_empty()).then(|| lines.join("\n"))
+}
+
+/// Last frame printed by the active prompt, used to redraw it after [`suspend`].
+struct Frame {
+    text: String,
+    cursor: Option<[usize; 2]>,
+}
+
+static ACTIVE_FRAME: Mutex<Option<Frame>> = Mutex::new(None);
+
+fn lock_frame() -> MutexGuard<'static, Option<Frame>> {
+    ACTIVE_FRAME.lock().unwrap_or_else(PoisonError::into_inner)
+}
+
+/// Temporarily clear the active prompt to run `f`, then draw the prompt again.
+///
+/// This allows to print messages (e.g. logs from a background thread) while a prompt
+/// is displayed, without corrupting it. If no prompt is active, `f` is just called.
+///
+/// **Note**: `f` must not display a prompt or call `suspend` again, or it will deadlock.
+///
+/// # Examples
+///
+/// ```no_run
+/// std::thread::spawn(|| {
+///     asky::suspend(|| println!("Download completed"));
+/// });
+/// ```
+pub fn suspend<R>(f: impl FnOnce() -> R) -> R {
+    let frame = lock_frame();
+
+    let Some(frame) = frame.as_ref() else {
+        return f();
+    };
+
+    let raw_mode = terminal::is_raw_mode_enabled().unwrap_or(false);
+
+    suspend_frame(&mut io::stdout(), frame, || {
+        if raw_mode {
+            let _ = terminal::disable_raw_mode();
+        }
+
+        let result = f();
+
+        if raw_mode {
+            let _ = terminal::enable_raw_mode();
+        }
+
+        result
+    })
+}
+
+/// Clear the frame to run `f`, then print it again below the output of `f`.
+///
+/// The terminal is not queried, e.g. for the cursor position, because the prompt thread
+/// could be waiting for events, and the answer would be read as an event.
+fn suspend_frame<R>(out: &mut impl Terminal, frame: &Frame, f: impl FnOnce() -> R) -> R {
+    // Terminal errors are ignored, the prompt will be fixed in the next draw
+    let _ = clear_text(out).and_then(|_| out.flush());
+
+    let result = f();
+
+    let _ = print_text(out, &frame.text, true).and_then(|_| match frame.cursor {
+        Some(cursor) => move_cursor(out, cursor),
+        None => Ok(()),
+    });
+    let _ = out.flush();
+
+    result
+}
+
+/// Enable or disable raw mode, synchronized with [`suspend`].
+pub fn set_raw_mode(enabled: bool) -> io::Result<()> {
+    let _frame = lock_frame();
+
+    match enabled {
+        true => terminal::enable_raw_mode(),
+        false => terminal::disable_raw_mode(),
+    }
+}
+
+fn clear_text(out: &mut impl Terminal) -> io::Result<()> {
+    queue!(
+        out,
+        cursor::RestorePosition,
+        terminal::Clear(terminal::ClearType::FromCursorDown),
+    )
+}
+
+/// Print the text, that must end with a newline, so the cursor is at the start of the next line.
+fn print_text(out: &mut impl Terminal, text: &str, save_position: bool) -> io::Result<()> {
+    debug_assert!(text.ends_with('\n'));
+    queue!(out, Print(text))?;
+
+    // Saved position is updated each draw because the text lines could be different
+    // between draws. The last draw is ignored to always set the cursor at the end
+    //
+    // The position is saved this way to ensure the correct position when the cursor is at
+    // the bottom of the terminal. Otherwise, the saved position will be the last row
+    // and when trying to restore, the next draw will be below the last row.
+    if save_position {
+        let text_lines = out.layout().rows(text) as u16;
+
+        queue!(
+            out,
+            cursor::MoveToPreviousLine(text_lines),
+            cursor::SavePosition,
+            cursor::MoveToNextLine(text_lines)
+        )?;
+    }
+
+    Ok(())
+}
+
+fn move_cursor(out: &mut impl Terminal, [x, y]: [usize; 2]) -> io::Result<()> {
+    queue!(out, cursor::RestorePosition)?;
+
+    if y > 0 {
+        queue!(out, cursor::MoveDown(y as u16))?;
+    }
+
+    if x > 0 {
+        queue!(out, cursor::MoveRight(x as u16))?;
+    }
+
+    Ok(())
+}
+
+/// Shape of the terminal cursor.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CursorShape {
+    /// `█` shape.
+    Block,
+    /// `_` shape.
+    Underline,
+    /// `|` shape.
+    Bar,
+}
+
+/// Style of the terminal cursor while editing text-like prompts.
+///
+/// See [`AskySettings::cursor_style`](crate::AskySettings::cursor_style).
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct CursorStyle {
+    /// Shape of the cursor.
+    pub shape: CursorShape,
+    /// Whether the cursor blinks.
+    pub blinking: bool,
+}
+
+/// Restore the cursor style set by the user when dropped.
+pub struct CursorStyleGuard {
+    _private: (),
+}
+
+impl Drop for CursorStyleGuard {
+    fn drop(&mut self) {
+        reset_cursor_style().ok();
+    }
+}
+
+/// Disable the keyboard enhancement protocol when dropped.
+pub struct KeyEnhancementGuard {
+    _private: (),
+}
+
+impl Drop for KeyEnhancementGuard {
+    fn drop(&mut self) {
+        execute!(io::stdout(), PopKeyboardEnhancementFlags).ok();
+    }
+}
+
+/// Restore the cursor style set by the user, terminals don't allow reading the current one.
+pub fn reset_cursor_style() -> io::Result<()> {
+    execute!(io::stdout(), cursor::SetCursorStyle::DefaultUserShape)
+}
+
+fn cursor_style_command(style: CursorStyle) -> cursor::SetCursorStyle {
+    match (style.shape, style.blinking) {
+        (CursorShape::Block, true) => cursor::SetCursorStyle::BlinkingBlock,
+        (CursorShape::Block, false) => cursor::SetCursorStyle::SteadyBlock,
+        (CursorShape::Underline, true) => cursor::SetCursorStyle::BlinkingUnderScore,
+        (CursorShape::Underline, false) => cursor::SetCursorStyle::SteadyUnderScore,
+        (CursorShape::Bar, true) => cursor::SetCursorStyle::BlinkingBar,
+        (CursorShape::Bar, false) => cursor::SetCursorStyle::SteadyBar,
+    }
 }
 
 pub struct Renderer {
     pub draw_time: DrawTime,
-    out: io::Stdout,
+    header_lines: usize,
+    out: Box<dyn Terminal>,
+    /// Whether the frames are saved to be redrawn after [`suspend`], only for the real terminal.
+    is_stdout: bool,
 }
 
 impl Renderer {
     pub fn new() -> Self {
+        Renderer {
+            is_stdout: true,
+            ..Self::with_terminal(io::stdout())
+        }
+    }
+
+    /// Create a renderer that draws to another terminal, like a virtual one.
+    pub(crate) fn with_terminal(out: impl Terminal + 'static) -> Self {
         Renderer {
             draw_time: DrawTime::First,
-            out: io::stdout(),
+            header_lines: 0,
+            out: Box::new(out),
+            is_stdout: false,
         }
     }
 
@@ -39,36 +340,52 @@ impl Renderer {
     }
 
     pub fn print(&mut self, mut text: String) -> io::Result<()> {
+        let mut frame = lock_frame();
+
         if self.draw_time != DrawTime::First {
-            queue!(
-                self.out,
-                cursor::RestorePosition,
-                terminal::Clear(terminal::ClearType::FromCursorDown),
-            )?;
+            clear_text(&mut self.out)?;
         }
 
         if !text.ends_with('\n') {
             text.push('\n')
         }
 
-        queue!(self.out, Print(&text))?;
+        if !AskySettings::global().theme.colors {
+            text = layout::strip_escape_codes(&text);
+        }
+
+        // The header is printed as part of the prompt to be cleared with it
+        self.header_lines = 0;
+
+        if !self.draw_time.is_final() {
+            if let Some(header) = displayed_header() {
+                self.header_lines = self.out.layout().rows(&header).max(1);
+                text = format!("{}\n{}", header, text);
+            }
+        }
+
+        let is_last = self.draw_time.is_final();
 
-        // Saved position is updated each draw because the text lines could be different
-        // between draws. The last draw is ignored to always set the cursor at the end
-        //
-        // The position is saved this way to ensure the correct position when the cursor is at
-        // the bottom of the terminal. Otherwise, the saved position will be the last row
-        // and when trying to restore, the next draw will be below the last row.
-        if self.draw_time != DrawTime::Last {
-            let (col, row) = cursor::position()?;
-            let text_lines = text.lines().count() as u16;
+        print_text(&mut self.out, &text, !is_last)?;
 
-            queue!(
-                self.out,
-                cursor::MoveToPreviousLine(text_lines),
-                cursor::SavePosition,
-                cursor::MoveTo(col, row)
-            )?;
+        if self.is_stdout {
+            *frame = match is_last {
+                true => None,
+                false => Some(Frame { text, cursor: None }),
+            };
+        }
+
+        self.out.flush()
+    }
+
+    /// Remove the prompt from the terminal, e.g. when it's cancelled.
+    pub fn clear(&mut self) -> io::Result<()> {
+        let mut frame = lock_frame();
+
+        clear_text(&mut self.out)?;
+
+        if self.is_stdout {
+            *frame = None;
         }
 
         self.out.flush()
@@ -77,21 +394,54 @@ impl Renderer {
     /// Utility function for line input
     /// Set initial position based on the position after drawing
     pub fn set_cursor(&mut self, [x, y]: [usize; 2]) -> io::Result<()> {
-        if self.draw_time == DrawTime::Last {
+        if self.draw_time.is_final() {
             return Ok(());
         }
 
-        queue!(self.out, cursor::RestorePosition)?;
+        let mut frame = lock_frame();
+        let cursor = [x, y + self.header_lines];
 
-        if y > 0 {
-            queue!(self.out, cursor::MoveDown(y as u16))?;
+        if let Some(frame) = frame.as_mut().filter(|_| self.is_stdout) {
+            frame.cursor = Some(cursor);
         }
 
-        if x > 0 {
-            queue!(self.out, cursor::MoveRight(x as u16))?;
+        move_cursor(&mut self.out, cursor)?;
+        self.out.flush()
+    }
+
+    /// Set the style of the cursor until the returned guard is dropped.
+    pub fn set_cursor_style(&mut self, style: CursorStyle) -> io::Result<CursorStyleGuard> {
+        execute!(self.out, cursor_style_command(style))?;
+        Ok(CursorStyleGuard { _private: () })
+    }
+
+    /// Enable the keyboard enhancement protocol until the returned guard is dropped,
+    /// so the terminal reports modified keys like `Ctrl+Enter`.
+    ///
+    /// Returns `None` if the terminal doesn't support it.
+    pub fn enable_key_enhancement(&mut self) -> io::Result<Option<KeyEnhancementGuard>> {
+        if !self.is_stdout || !terminal::supports_keyboard_enhancement().unwrap_or(false) {
+            return Ok(None);
         }
 
-        self.out.flush()
+        execute!(
+            self.out,
+            PushKeyboardEnhancementFlags(KeyboardEnhancementFlags::DISAMBIGUATE_ESCAPE_CODES)
+        )?;
+
+        Ok(Some(KeyEnhancementGuard { _private: () }))
+    }
+
+    /// Emit an alert to get the attention of the user, with the message for notifications.
+    pub fn alert(&mut self, alert: Alert, message: &str) -> io::Result<()> {
+        match alert {
+            Alert::Bell => execute!(self.out, Print("\x07")),
+            Alert::Notification => {
+                // control characters would end the sequence early
+                let message: String = message.chars().filter(|c| !c.is_control()).collect();
+                execute!(self.out, Print(format!("\x1b]9;{}\x07", message)))
+            }
+        }
     }
 
     pub fn hide_cursor(&mut self) -> io::Result<()> {
@@ -108,3 +458,179 @@ impl Default for Renderer {
         Self::new()
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crossterm::event::{KeyCode, KeyEvent};
+
+    use crate::{
+        utils::{key_listener::Typeable, virtual_terminal::VirtualTerminal},
+        Select,
+    };
+
+    struct Frame<'a>(&'a str, [usize; 2]);
+
+    impl Printable for Frame<'_> {
+        fn draw(&self, renderer: &mut Renderer) -> io::Result<()> {
+            renderer.print(self.0.to_string())?;
+            renderer.set_cursor(self.1)
+        }
+    }
+
+    fn draw(renderer: &mut Renderer, prompt: &impl Printable) {
+        prompt.draw(renderer).unwrap();
+        renderer.update_draw_time();
+    }
+
+    #[test]
+    fn redraw_below_previous_output() {
+        let mut term = VirtualTerminal::new(20, 6);
+        let mut renderer = Renderer::with_terminal(term.clone());
+
+        term.write_all(b"$ cargo run\n").unwrap();
+
+        draw(&mut renderer, &Frame("a\nb\nc", [1, 1]));
+        assert_eq!(term.lines()[..4], ["$ cargo run", "a", "b", "c"]);
+        assert_eq!(term.cursor(), [1, 2]);
+
+        draw(&mut renderer, &Frame("d", [1, 0]));
+        assert_eq!(term.lines()[..4], ["$ cargo run", "d", "", ""]);
+        assert_eq!(term.cursor(), [1, 1]);
+
+        draw(&mut renderer, &Frame("done", [1, 0]));
+        assert_eq!(term.lines()[..3], ["$ cargo run", "done", ""]);
+        assert_eq!(term.cursor(), [0, 2]);
+    }
+
+    #[test]
+    fn redraw_at_bottom_of_terminal() {
+        let mut term = VirtualTerminal::new(20, 3);
+        let mut renderer = Renderer::with_terminal(term.clone());
+
+        term.write_all(b"x\ny\n").unwrap();
+
+        draw(&mut renderer, &Frame("a\nb", [0, 1]));
+        assert_eq!(term.lines(), ["a", "b", ""]);
+        assert_eq!(term.cursor(), [0, 1]);
+
+        draw(&mut renderer, &Frame("c", [0, 0]));
+        assert_eq!(term.lines(), ["c", "", ""]);
+    }
+
+    #[test]
+    fn redraw_wrapped_lines() {
+        let term = VirtualTerminal::new(5, 6);
+        let mut renderer = Renderer::with_terminal(term.clone());
+
+        draw(&mut renderer, &Frame("abcdefgh\nijklm\nn", [2, 1]));
+        assert_eq!(term.lines()[..5], ["abcde", "fgh", "ijklm", "n", ""]);
+        assert_eq!(term.cursor(), [2, 1]);
+
+        draw(&mut renderer, &Frame("x", [0, 0]));
+        assert_eq!(term.lines(), ["x", "", "", "", "", ""]);
+    }
+
+    #[test]
+    fn redraw_after_resize() {
+        let mut term = VirtualTerminal::new(10, 5);
+        let mut renderer = Renderer::with_terminal(term.clone());
+
+        term.write_all(b"$ run\n").unwrap();
+
+        draw(&mut renderer, &Frame("abcdefgh\nij", [2, 1]));
+        assert_eq!(term.lines(), ["$ run", "abcdefgh", "ij", "", ""]);
+
+        term.resize(5, 5);
+        assert_eq!(term.lines(), ["$ run", "abcde", "ij", "", ""]);
+
+        draw(&mut renderer, &Frame("abcdefgh\nij", [2, 1]));
+        assert_eq!(term.lines(), ["$ run", "abcde", "fgh", "ij", ""]);
+        assert_eq!(term.cursor(), [2, 2]);
+
+        term.resize(5, 3);
+        assert_eq!(term.lines(), ["$ run", "abcde", "fgh"]);
+        assert_eq!(term.cursor(), [2, 2]);
+
+        draw(&mut renderer, &Frame("x", [0, 0]));
+        assert_eq!(term.lines(), ["$ run", "x", ""]);
+    }
+
+    #[test]
+    fn clip_rows_on_resize() {
+        let mut term = VirtualTerminal::new(6, 3);
+
+        term.write_all("a\nb日本\nc".as_bytes()).unwrap();
+        term.resize(4, 2);
+
+        assert_eq!(term.lines(), ["b日", "c"]);
+        assert_eq!(term.cursor(), [1, 1]);
+
+        term.resize(2, 2);
+        assert_eq!(term.lines(), ["b", "c"]);
+    }
+
+    #[test]
+    fn suspend_at_bottom_of_terminal() {
+        let mut term = VirtualTerminal::new(20, 4);
+        let mut renderer = Renderer::with_terminal(term.clone());
+
+        term.write_all(b"x\ny\n").unwrap();
+        draw(&mut renderer, &Frame("a\nb", [1, 1]));
+
+        let frame = super::Frame {
+            text: String::from("a\nb\n"),
+            cursor: Some([1, 1]),
+        };
+        let mut log = term.clone();
+
+        suspend_frame(&mut term, &frame, || {
+            log.write_all(b"log 1\nlog 2\n").unwrap()
+        });
+        assert_eq!(term.lines(), ["log 2", "a", "b", ""]);
+        assert_eq!(term.cursor(), [1, 2]);
+
+        draw(&mut renderer, &Frame("c", [0, 0]));
+        assert_eq!(term.lines(), ["log 2", "c", "", ""]);
+    }
+
+    #[test]
+    fn redraw_pages_of_options() {
+        let options = ["a", "b", "c", "d", "e", "f", "g"];
+        let mut prompt = Select::new("Letter", options);
+        prompt.items_per_page(3);
+
+        let term = VirtualTerminal::new(40, 10);
+        let mut renderer = Renderer::with_terminal(term.clone());
+
+        draw(&mut renderer, &prompt);
+
+        for _ in 0..5 {
+            prompt.handle_key(KeyEvent::from(KeyCode::Down));
+            prompt.draw(&mut renderer).unwrap();
+        }
+
+        let fresh = VirtualTerminal::new(40, 10);
+        draw(&mut Renderer::with_terminal(fresh.clone()), &prompt);
+
+        assert_eq!(term.lines(), fresh.lines());
+    }
+
+    #[test]
+    fn set_cursor_styles() {
+        let ansi = |shape, blinking| {
+            let mut ansi = String::new();
+            let command = cursor_style_command(CursorStyle { shape, blinking });
+
+            crossterm::Command::write_ansi(&command, &mut ansi).unwrap();
+            ansi
+        };
+
+        assert_eq!(ansi(CursorShape::Block, true), "\x1b[1 q");
+        assert_eq!(ansi(CursorShape::Block, false), "\x1b[2 q");
+        assert_eq!(ansi(CursorShape::Underline, true), "\x1b[3 q");
+        assert_eq!(ansi(CursorShape::Underline, false), "\x1b[4 q");
+        assert_eq!(ansi(CursorShape::Bar, true), "\x1b[5 q");
+        assert_eq!(ansi(CursorShape::Bar, false), "\x1b[6 q");
+    }
+}
@@ -0,0 +1,538 @@
+use std::{
+    env,
+    sync::{OnceLock, RwLock},
+    time::Duration,
+};
+
+use crossterm::event::{KeyCode, KeyEvent, KeyModifiers};
+
+use super::{key_listener, renderer::CursorStyle};
+
+static SETTINGS: RwLock<AskySettings> = RwLock::new(AskySettings::new());
+
+/// Global settings used by all the prompts.
+///
+/// # Examples
+///
+/// ```no_run
+/// use asky::{AskySettings, Layout};
+///
+/// AskySettings::set_global(AskySettings {
+///     layout: Layout::Columns { label_width: 20 },
+///     ..Default::default()
+/// });
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct AskySettings {
+    /// Layout used by the default formatters.
+    pub layout: Layout,
+    /// Keyboard layout used to map the navigation keys (`h`, `j`, `k`, `l`).
+    pub key_layout: KeyLayout,
+    /// Style of the cursor while editing text-like prompts, like [`Text`](crate::Text).
+    ///
+    /// The style set by the user is restored after the prompt.
+    /// By default (`None`), the cursor style is not changed.
+    pub cursor_style: Option<CursorStyle>,
+    /// Whether to exit the process when the user cancels a prompt, instead of returning
+    /// [`Error::Cancel`](crate::Error::Cancel).
+    pub exit_on_cancel: bool,
+    /// Whether to keep the cancelled prompts in the terminal, drawn with
+    /// [`DrawTime::Cancelled`](crate::DrawTime::Cancelled), instead of removing them.
+    pub keep_cancelled: bool,
+    /// Symbols and colors used by the default formatters.
+    pub theme: Theme,
+    /// Extra keys bound to the actions of the prompts.
+    pub keybindings: Keybindings,
+    /// Alert to emit when a prompt is shown after [`AskySettings::alert_after`] without prompts,
+    /// e.g. after a long build, so the user notices that the program is waiting.
+    ///
+    /// By default (`None`), there is no alert.
+    pub alert: Option<Alert>,
+    /// Time since the previous prompt was answered to emit the [`AskySettings::alert`].
+    ///
+    /// For the first prompt, the time is counted since the alert was set.
+    pub alert_after: Duration,
+}
+
+impl AskySettings {
+    const fn new() -> Self {
+        AskySettings {
+            layout: Layout::Stacked,
+            key_layout: KeyLayout::Qwerty,
+            cursor_style: None,
+            exit_on_cancel: false,
+            keep_cancelled: false,
+            theme: Theme::DEFAULT,
+            keybindings: Keybindings::new(),
+            alert: None,
+            alert_after: Duration::from_secs(10),
+        }
+    }
+
+    /// Returns a copy of the current global settings.
+    pub fn global() -> Self {
+        match SETTINGS.read() {
+            Ok(settings) => settings.clone(),
+            Err(poisoned) => poisoned.into_inner().clone(),
+        }
+    }
+
+    /// Replace the global settings used by the prompts displayed afterwards.
+    pub fn set_global(settings: AskySettings) {
+        if settings.alert.is_some() {
+            key_listener::start_quiet_period();
+        }
+
+        match SETTINGS.write() {
+            Ok(mut current) => *current = settings,
+            Err(poisoned) => *poisoned.into_inner() = settings,
+        }
+    }
+}
+
+impl Default for AskySettings {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Alert to get the attention of the user, see [`AskySettings::alert`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Alert {
+    /// Ring the terminal bell (`BEL`).
+    Bell,
+    /// Send a desktop notification with the prompt message (`OSC 9`).
+    ///
+    /// Supported by terminals like iTerm2, Windows Terminal or kitty, the others ignore it.
+    Notification,
+}
+
+/// Layout of the prompts rendered by the default formatters.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum Layout {
+    /// Message in its own line, with the input below it.
+    #[default]
+    Stacked,
+    /// Message in a fixed-width label column, with the input at its right.
+    ///
+    /// Useful for long wizards, where the inputs of successive prompts are aligned vertically.
+    Columns {
+        /// Minimum width of the label column, in characters.
+        label_width: usize,
+    },
+}
+
+/// Symbols and colors used by the default formatters.
+///
+/// Use one of the presets, or create a custom theme from them.
+///
+/// # Examples
+///
+/// ```no_run
+/// use asky::{AskySettings, GlyphSet, Theme};
+///
+/// AskySettings::set_global(AskySettings {
+///     theme: Theme {
+///         glyphs: Some(GlyphSet {
+///             input: "❯",
+///             ..GlyphSet::ASCII
+///         }),
+///         ..Theme::PLAIN
+///     },
+///     ..Default::default()
+/// });
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Theme {
+    /// Symbols used by the prompts.
+    ///
+    /// By default (`None`), they are picked by the terminal support, see [`GlyphSet::detect`].
+    pub glyphs: Option<GlyphSet>,
+    /// Whether the prompts are displayed with colors.
+    pub colors: bool,
+}
+
+impl Theme {
+    /// Default theme, with colors and the symbols supported by the terminal.
+    pub const DEFAULT: Theme = Theme {
+        glyphs: None,
+        colors: true,
+    };
+
+    /// Plain theme, with ASCII symbols and without colors.
+    ///
+    /// Useful for terminals with limited support, or for screen readers.
+    pub const PLAIN: Theme = Theme {
+        glyphs: Some(GlyphSet::ASCII),
+        colors: false,
+    };
+
+    /// Theme with emoji symbols.
+    pub const EMOJI: Theme = Theme {
+        glyphs: Some(GlyphSet::EMOJI),
+        colors: true,
+    };
+
+    /// Returns the symbols of the theme, detecting them if they are not set.
+    pub fn glyphs(&self) -> GlyphSet {
+        self.glyphs.unwrap_or_else(GlyphSet::detect)
+    }
+}
+
+impl Default for Theme {
+    fn default() -> Self {
+        Theme::DEFAULT
+    }
+}
+
+/// Symbols used by the default formatters, independent of the colors.
+///
+/// Swap them to use other icons, like the ones of [Nerd Fonts](https://www.nerdfonts.com).
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct GlyphSet {
+    /// Symbol before the message of a prompt.
+    pub message: &'static str,
+    /// Symbol before the message of an answered prompt.
+    ///
+    /// It's displayed in yellow when the default value was submitted.
+    pub answered: &'static str,
+    /// Symbol before the message of a cancelled prompt, see [`AskySettings::keep_cancelled`].
+    pub cancelled: &'static str,
+    /// Symbol before the user input, pointing to it.
+    pub input: &'static str,
+    /// Radio of a selected option, in [`Select`](crate::Select).
+    pub radio_on: &'static str,
+    /// Radio of an unselected option, in [`Select`](crate::Select).
+    pub radio_off: &'static str,
+    /// Checkbox of a selected option, in [`MultiSelect`](crate::MultiSelect).
+    pub checkbox_on: &'static str,
+    /// Checkbox of an unselected option, in [`MultiSelect`](crate::MultiSelect).
+    pub checkbox_off: &'static str,
+    /// Checkbox of a selected option with the focus, in [`MultiSelect`](crate::MultiSelect).
+    pub checkbox_focused: &'static str,
+    /// Dot of each page, below the options of paginated prompts.
+    pub page_dot: &'static str,
+    /// Symbol at the end of a truncated text.
+    pub ellipsis: &'static str,
+    /// Symbol repeated to draw a separator, see [`SelectOption::separator`](crate::SelectOption::separator).
+    pub separator: &'static str,
+    /// Symbols around the focused button, in [`Toggle`](crate::Toggle) and [`Confirm`](crate::Confirm).
+    ///
+    /// The other buttons are padded with spaces of the same width.
+    pub toggle_brackets: [&'static str; 2],
+}
+
+impl GlyphSet {
+    /// Unicode symbols, supported by most terminals.
+    pub const UNICODE: GlyphSet = GlyphSet {
+        message: "▣",
+        answered: "■",
+        cancelled: "✖",
+        input: "›",
+        radio_on: "●",
+        radio_off: "○",
+        checkbox_on: "●",
+        checkbox_off: "○",
+        checkbox_focused: "◉",
+        page_dot: "•",
+        ellipsis: "…",
+        separator: "─",
+        toggle_brackets: [" ", " "],
+    };
+
+    /// ASCII symbols, for terminals without Unicode support.
+    pub const ASCII: GlyphSet = GlyphSet {
+        message: "?",
+        answered: "+",
+        cancelled: "x",
+        input: ">",
+        radio_on: "*",
+        radio_off: "-",
+        checkbox_on: "*",
+        checkbox_off: "-",
+        checkbox_focused: "#",
+        page_dot: ".",
+        ellipsis: "~",
+        separator: "-",
+        toggle_brackets: ["[", "]"],
+    };
+
+    /// Emoji symbols.
+    pub const EMOJI: GlyphSet = GlyphSet {
+        message: "💬",
+        answered: "✅",
+        cancelled: "❌",
+        input: "👉",
+        radio_on: "🔵",
+        radio_off: "⚪",
+        checkbox_on: "🔵",
+        checkbox_off: "⚪",
+        checkbox_focused: "🔘",
+        page_dot: "•",
+        ellipsis: "…",
+        separator: "─",
+        toggle_brackets: [" ", " "],
+    };
+
+    /// [Nerd Fonts](https://www.nerdfonts.com) icons, the terminal must use a patched font.
+    pub const NERD_FONT: GlyphSet = GlyphSet {
+        message: "\u{f059}",
+        answered: "\u{f058}",
+        cancelled: "\u{f057}",
+        input: "\u{f054}",
+        radio_on: "\u{f192}",
+        radio_off: "\u{f10c}",
+        checkbox_on: "\u{f14a}",
+        checkbox_off: "\u{f096}",
+        checkbox_focused: "\u{f046}",
+        page_dot: "\u{f111}",
+        ellipsis: "…",
+        separator: "─",
+        toggle_brackets: [" ", " "],
+    };
+
+    /// Pick [`GlyphSet::UNICODE`] or [`GlyphSet::ASCII`] by the support of the terminal.
+    ///
+    /// The Linux console and dumb terminals use ASCII symbols, and the others use Unicode
+    /// ones if the locale is UTF-8, like `LANG=en_US.UTF-8`. Windows terminals always use Unicode.
+    /// Nerd Fonts can't be detected.
+    pub fn detect() -> GlyphSet {
+        static DETECTED: OnceLock<GlyphSet> = OnceLock::new();
+
+        *DETECTED.get_or_init(|| {
+            let term = env::var("TERM").unwrap_or_default();
+
+            if matches!(term.as_str(), "linux" | "dumb") {
+                return GlyphSet::ASCII;
+            }
+
+            if cfg!(windows) {
+                return GlyphSet::UNICODE;
+            }
+
+            match is_utf8_locale() {
+                true => GlyphSet::UNICODE,
+                false => GlyphSet::ASCII,
+            }
+        })
+    }
+}
+
+/// Check the locale variables by precedence, like the C library does.
+fn is_utf8_locale() -> bool {
+    let locale = ["LC_ALL", "LC_CTYPE", "LANG"]
+        .into_iter()
+        .find_map(|name| env::var(name).ok().filter(|value| !value.is_empty()))
+        .unwrap_or_default()
+        .to_uppercase();
+
+    locale.contains("UTF-8") || locale.contains("UTF8")
+}
+
+/// Keyboard layout used to map the vim-like navigation keys.
+///
+/// The navigation keys are mapped by their physical position, so the keys
+/// at the `h`, `j`, `k`, `l` position of a QWERTY keyboard always move left, down, up and right.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum KeyLayout {
+    /// QWERTY layout, navigation with `h`, `j`, `k`, `l`.
+    #[default]
+    Qwerty,
+    /// AZERTY layout, navigation with `h`, `j`, `k`, `l`.
+    Azerty,
+    /// Dvorak layout, navigation with `d`, `h`, `t`, `n`.
+    Dvorak,
+    /// Colemak layout, navigation with `h`, `n`, `e`, `i`.
+    Colemak,
+}
+
+impl KeyLayout {
+    /// Navigation keys for left, down, up and right.
+    fn navigation_keys(&self) -> [char; 4] {
+        match self {
+            KeyLayout::Qwerty | KeyLayout::Azerty => ['h', 'j', 'k', 'l'],
+            KeyLayout::Dvorak => ['d', 'h', 't', 'n'],
+            KeyLayout::Colemak => ['h', 'n', 'e', 'i'],
+        }
+    }
+
+    /// Whether the key is a navigation key of the layout, in any case.
+    pub(crate) fn is_navigation_key(self, c: char) -> bool {
+        self.navigation_keys().contains(&c.to_ascii_lowercase())
+    }
+
+    /// Map a navigation key of the layout to its QWERTY equivalent, keeping the case.
+    ///
+    /// Returns `None` for the QWERTY navigation keys that are not navigation keys of the layout,
+    /// so they don't move like in QWERTY.
+    pub(crate) fn to_qwerty(self, c: char) -> Option<char> {
+        let qwerty = KeyLayout::Qwerty.navigation_keys();
+        let lowercase = c.to_ascii_lowercase();
+        let position = self
+            .navigation_keys()
+            .iter()
+            .position(|key| *key == lowercase);
+
+        match position {
+            Some(i) if c.is_ascii_uppercase() => Some(qwerty[i].to_ascii_uppercase()),
+            Some(i) => Some(qwerty[i]),
+            None if qwerty.contains(&lowercase) => None,
+            None => Some(c),
+        }
+    }
+}
+
+/// Action of the prompts that can be bound to other keys with [`Keybindings`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Action {
+    /// Submit the prompt, like `Enter`.
+    Submit,
+    /// Cancel the prompt, like `Esc`.
+    Cancel,
+    /// Move up, like `Up`.
+    MoveUp,
+    /// Move down, like `Down`.
+    MoveDown,
+    /// Move left, like `Left`.
+    MoveLeft,
+    /// Move right, like `Right`.
+    MoveRight,
+    /// Toggle the focused option of a [`MultiSelect`](crate::MultiSelect), like `Space`.
+    ToggleItem,
+}
+
+impl Action {
+    /// Default key of the action, handled by the prompts.
+    fn key(self) -> KeyCode {
+        match self {
+            Action::Submit => KeyCode::Enter,
+            Action::Cancel => KeyCode::Esc,
+            Action::MoveUp => KeyCode::Up,
+            Action::MoveDown => KeyCode::Down,
+            Action::MoveLeft => KeyCode::Left,
+            Action::MoveRight => KeyCode::Right,
+            Action::ToggleItem => KeyCode::Char(' '),
+        }
+    }
+}
+
+/// Extra keys bound to the actions of the prompts, in addition to the default ones.
+///
+/// **Note**: The bindings apply to all the prompts, so binding a printable key like `Space`
+/// also prevents typing it in text prompts.
+///
+/// # Examples
+///
+/// ```no_run
+/// use asky::{Action, AskySettings, Keybindings};
+/// use crossterm::event::{KeyCode, KeyEvent, KeyModifiers};
+///
+/// AskySettings::set_global(AskySettings {
+///     keybindings: Keybindings::new()
+///         .bind(KeyEvent::new(KeyCode::Char('s'), KeyModifiers::CONTROL), Action::Submit)
+///         .vim_keys(false),
+///     ..Default::default()
+/// });
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Keybindings {
+    /// Keys bound to actions.
+    pub bindings: Vec<(KeyEvent, Action)>,
+    /// Whether the vim-like keys (`h`, `j`, `k`, `l`) move the focus in prompts without
+    /// text input, like [`Select`](crate::Select).
+    pub vim_keys: bool,
+}
+
+impl Keybindings {
+    /// Create keybindings without extra keys.
+    pub const fn new() -> Self {
+        Keybindings {
+            bindings: Vec::new(),
+            vim_keys: true,
+        }
+    }
+
+    /// Bind a key to an action, like `Ctrl+S` to submit.
+    pub fn bind(mut self, key: impl Into<KeyEvent>, action: Action) -> Self {
+        self.bindings.push((key.into(), action));
+        self
+    }
+
+    /// Set whether the vim-like keys move the focus.
+    pub fn vim_keys(mut self, vim_keys: bool) -> Self {
+        self.vim_keys = vim_keys;
+        self
+    }
+
+    /// Map a bound key to the default key of its action.
+    pub(crate) fn map_key(&self, key: KeyEvent) -> KeyEvent {
+        let action = self
+            .bindings
+            .iter()
+            .find(|(bound, _)| bound.code == key.code && bound.modifiers == key.modifiers)
+            .map(|(_, action)| action);
+
+        match action {
+            Some(action) => KeyEvent::new_with_kind_and_state(
+                action.key(),
+                KeyModifiers::NONE,
+                key.kind,
+                key.state,
+            ),
+            None => key,
+        }
+    }
+}
+
+impl Default for Keybindings {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn map_navigation_keys() {
+        assert_eq!(KeyLayout::Qwerty.to_qwerty('j'), Some('j'));
+        assert_eq!(KeyLayout::Dvorak.to_qwerty('h'), Some('j'));
+        assert_eq!(KeyLayout::Dvorak.to_qwerty('T'), Some('K'));
+        assert_eq!(KeyLayout::Dvorak.to_qwerty('j'), None);
+        assert_eq!(KeyLayout::Dvorak.to_qwerty('L'), None);
+        assert_eq!(KeyLayout::Colemak.to_qwerty('i'), Some('l'));
+        assert_eq!(KeyLayout::Colemak.to_qwerty('h'), Some('h'));
+        assert_eq!(KeyLayout::Colemak.to_qwerty('x'), Some('x'));
+    }
+
+    #[test]
+    fn pick_theme_glyphs() {
+        assert_eq!(Theme::PLAIN.glyphs(), GlyphSet::ASCII);
+        assert_eq!(Theme::EMOJI.glyphs(), GlyphSet::EMOJI);
+        assert!([GlyphSet::UNICODE, GlyphSet::ASCII].contains(&Theme::DEFAULT.glyphs()));
+    }
+
+    #[test]
+    fn map_bound_keys() {
+        let keybindings = Keybindings::new()
+            .bind(KeyCode::Char(' '), Action::Submit)
+            .bind(
+                KeyEvent::new(KeyCode::Char('n'), KeyModifiers::CONTROL),
+                Action::MoveDown,
+            );
+
+        assert_eq!(
+            keybindings.map_key(KeyEvent::from(KeyCode::Char(' '))),
+            KeyEvent::from(KeyCode::Enter)
+        );
+        assert_eq!(
+            keybindings.map_key(KeyEvent::new(KeyCode::Char('n'), KeyModifiers::CONTROL)),
+            KeyEvent::from(KeyCode::Down)
+        );
+        assert_eq!(
+            keybindings.map_key(KeyEvent::from(KeyCode::Char('n'))),
+            KeyEvent::from(KeyCode::Char('n'))
+        );
+    }
+}
@@ -0,0 +1,162 @@
+use std::{
+    borrow::Cow,
+    sync::{Arc, RwLock},
+};
+
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+use crate::prompts::select::SelectOption;
+
+use super::renderer::Printable;
+
+type Observer = dyn Fn(&PromptState) + Send + Sync;
+
+static OBSERVER: RwLock<Option<Arc<Observer>>> = RwLock::new(None);
+
+/// Snapshot of the state of a prompt, returned by the `state()` method of the prompts.
+///
+/// It can be used to mirror a running prompt in another surface, like a web dashboard,
+/// see [`set_state_observer`]. Serializable with the `serde` feature.
+#[derive(Debug, Clone, Default, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct PromptState {
+    /// Message of the prompt.
+    pub message: String,
+    /// Stable identifier of the prompt, if it was set.
+    pub name: Option<String>,
+    /// Current value, as displayed. Passwords and sensitive answers are masked.
+    pub value: String,
+    /// Index of the focused option, for prompts with options.
+    pub focused: Option<usize>,
+    /// Options of the prompt, like in [`Select`](crate::Select) or [`Toggle`](crate::Toggle).
+    pub options: Vec<OptionState>,
+    /// Message of the validation error, if the input is invalid.
+    pub error: Option<String>,
+}
+
+/// State of an option, in a [`PromptState`].
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct OptionState {
+    /// Title of the option.
+    pub title: String,
+    /// Whether the option is selected.
+    pub active: bool,
+    /// Whether the option can't be chosen.
+    pub disabled: bool,
+}
+
+impl PromptState {
+    pub(crate) fn new(message: &str, name: Option<&str>, value: impl Into<String>) -> Self {
+        PromptState {
+            message: message.to_owned(),
+            name: name.map(String::from),
+            value: value.into(),
+            ..Default::default()
+        }
+    }
+
+    pub(crate) fn with_error(mut self, result: &Result<(), Cow<str>>) -> Self {
+        self.error = result.as_ref().err().map(|err| err.to_string());
+        self
+    }
+
+    pub(crate) fn with_titles(mut self, titles: &[&str], focused: usize) -> Self {
+        self.options = titles
+            .iter()
+            .map(|title| OptionState {
+                title: title.to_string(),
+                ..Default::default()
+            })
+            .collect();
+        self.focused = Some(focused);
+        self
+    }
+
+    pub(crate) fn with_options<T>(
+        mut self,
+        options: &[SelectOption<T>],
+        focused: Option<usize>,
+    ) -> Self {
+        self.options = options
+            .iter()
+            .map(|option| OptionState {
+                title: option.get_title().to_owned(),
+                active: option.active,
+                disabled: option.disabled,
+            })
+            .collect();
+        self.focused = focused;
+        self
+    }
+}
+
+/// Set a function to call with the state of the running prompt, each time it's drawn.
+///
+/// # Examples
+///
+/// ```no_run
+/// use asky::{set_state_observer, Text};
+///
+/// # fn main() -> std::io::Result<()> {
+/// set_state_observer(|state| eprintln!("{}: {}", state.message, state.value));
+///
+/// Text::new("What is your name?").prompt()?;
+/// # Ok(())
+/// # }
+/// ```
+pub fn set_state_observer<F>(observer: F)
+where
+    F: Fn(&PromptState) + Send + Sync + 'static,
+{
+    replace_observer(Some(Arc::new(observer)))
+}
+
+/// Remove the function set with [`set_state_observer`].
+pub fn clear_state_observer() {
+    replace_observer(None)
+}
+
+fn replace_observer(observer: Option<Arc<Observer>>) {
+    match OBSERVER.write() {
+        Ok(mut current) => *current = observer,
+        Err(poisoned) => *poisoned.into_inner() = observer,
+    }
+}
+
+/// Call the observer with the state of the prompt, if both are available.
+pub(crate) fn notify(prompt: &impl Printable) {
+    let observer = match OBSERVER.read() {
+        Ok(observer) => observer.clone(),
+        Err(poisoned) => poisoned.into_inner().clone(),
+    };
+
+    if let (Some(observer), Some(state)) = (observer, prompt.snapshot()) {
+        observer(&state);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{Password, Select};
+
+    #[test]
+    fn snapshot_prompts() {
+        let mut select = Select::new("Letter", ["a", "b"]);
+        select.name("letter").selected(1);
+
+        let state = select.state();
+        assert_eq!(state.name.as_deref(), Some("letter"));
+        assert_eq!((state.value.as_str(), state.focused), ("b", Some(1)));
+        assert_eq!(state.options.len(), 2);
+
+        let mut password = Password::new("Password");
+        password.initial("hunter2");
+        password.validator_result = Err("invalid".into());
+
+        let state = password.state();
+        assert_eq!(state.value, "*******");
+        assert_eq!(state.error.as_deref(), Some("invalid"));
+    }
+}
@@ -0,0 +1,173 @@
+use std::{fmt, io};
+
+use super::{answer::Answer, layout, settings::AskySettings, theme};
+
+/// Entry of a [`Summary`].
+///
+/// The secret answers are redacted in the [`Debug`](fmt::Debug) output too.
+#[derive(Clone, PartialEq)]
+pub struct SummaryEntry<'a> {
+    /// Name of the answer, like `"Username"`.
+    pub key: &'a str,
+    /// Collected answer.
+    pub answer: Answer,
+    /// Whether the answer is masked, like passwords or tokens.
+    pub secret: bool,
+}
+
+impl fmt::Debug for SummaryEntry<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let mut entry = f.debug_struct("SummaryEntry");
+
+        entry.field("key", &self.key);
+
+        match self.secret {
+            true => entry.field("answer", &format_args!("<redacted>")),
+            false => entry.field("answer", &self.answer),
+        };
+
+        entry.field("secret", &self.secret).finish()
+    }
+}
+
+/// Recap of the collected answers, to print at the end of a flow.
+///
+/// The keys are aligned and the secret answers are masked.
+///
+/// # Examples
+///
+/// ```no_run
+/// use asky::{Password, Summary, Text};
+///
+/// # fn main() -> std::io::Result<()> {
+/// let username = Text::new("Username").prompt()?;
+/// let password = Password::new("Password").prompt()?;
+///
+/// Summary::new()
+///     .title("Account created")
+///     .add("Username", username)
+///     .secret("Password", password)
+///     .print()?;
+/// # Ok(())
+/// # }
+/// ```
+///
+/// This will prints
+///
+/// ```bash
+/// ■ Account created
+///   Username  admin
+///   Password  ••••••••
+/// ```
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct Summary<'a> {
+    /// Title displayed above the answers.
+    pub title: Option<&'a str>,
+    /// Answers to display, in order.
+    pub entries: Vec<SummaryEntry<'a>>,
+}
+
+impl<'a> Summary<'a> {
+    /// Create an empty summary.
+    pub fn new() -> Self {
+        Summary::default()
+    }
+
+    /// Set the title displayed above the answers.
+    pub fn title(&mut self, title: &'a str) -> &mut Self {
+        self.title = Some(title);
+        self
+    }
+
+    /// Add an answer.
+    pub fn add(&mut self, key: &'a str, answer: impl Into<Answer>) -> &mut Self {
+        self.push(key, answer.into(), false)
+    }
+
+    /// Add an answer that is masked, like a password.
+    pub fn secret(&mut self, key: &'a str, answer: impl Into<Answer>) -> &mut Self {
+        self.push(key, answer.into(), true)
+    }
+
+    /// Print the summary to the standard output.
+    pub fn print(&self) -> io::Result<()> {
+        use io::Write;
+
+        let mut out = io::stdout();
+
+        writeln!(out, "{}", self)?;
+        out.flush()
+    }
+
+    fn push(&mut self, key: &'a str, answer: Answer, secret: bool) -> &mut Self {
+        self.entries.push(SummaryEntry {
+            key,
+            answer,
+            secret,
+        });
+        self
+    }
+}
+
+impl fmt::Display for Summary<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let text = theme::fmt_summary(self);
+
+        match AskySettings::global().theme.colors {
+            true => f.write_str(&text),
+            false => f.write_str(&layout::strip_escape_codes(&text)),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn collect_entries() {
+        let mut summary = Summary::new();
+
+        summary
+            .title("Done")
+            .add("Name", "foo")
+            .secret("Token", "hunter2");
+
+        assert_eq!(summary.title, Some("Done"));
+        assert_eq!(
+            summary.entries[1],
+            SummaryEntry {
+                key: "Token",
+                answer: Answer::from("hunter2"),
+                secret: true,
+            }
+        );
+    }
+
+    #[test]
+    fn mask_secrets() {
+        let mut summary = Summary::new();
+
+        summary.add("Name", "foo").secret("Token", "hunter2");
+
+        let text = summary.to_string();
+
+        assert!(text.contains("foo"));
+        assert!(!text.contains("hunter2"));
+        assert_eq!(text.lines().count(), 2);
+
+        let debug = format!("{summary:?}");
+
+        assert!(debug.contains("foo"));
+        assert!(!debug.contains("hunter2"));
+    }
+
+    #[test]
+    fn record_skipped_answers() {
+        let mut summary = Summary::new();
+
+        summary.add("Email", None::<&str>).add("Name", Some("foo"));
+
+        assert_eq!(summary.to_string(), "  Email  skipped\n  Name   foo");
+    }
+}
@@ -0,0 +1,308 @@
+//! Surveys defined as data, like a configuration file, instead of code.
+
+use serde::Deserialize;
+use serde_json::{Map, Value};
+
+use crate::{validators, Confirm, MultiSelect, Number, Password, Select, Text};
+
+use super::error::Error;
+
+/// List of questions that can be deserialized from any format supported by serde,
+/// like JSON, TOML or YAML, and asked with [`Survey::run`].
+///
+/// Each question has a `key`, a `message` and a `type`, which is one of `text`, `password`,
+/// `number`, `confirm`, `select` or `multi_select`. The other fields depend on the type,
+/// see [`QuestionKind`].
+///
+/// # Examples
+///
+/// ```no_run
+/// use asky::Survey;
+///
+/// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+/// let survey: Survey = serde_json::from_str(
+///     r#"{
+///         "questions": [
+///             { "key": "name", "message": "Project name", "type": "text", "validate": { "required": true } },
+///             { "key": "port", "message": "Port", "type": "number", "default": 8080, "min": 1 },
+///             { "key": "license", "message": "License", "type": "select", "options": ["MIT", "Apache-2.0"] }
+///         ]
+///     }"#,
+/// )?;
+///
+/// let answers = survey.run()?;
+/// println!("{}", serde_json::Value::Object(answers));
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Debug, Clone, Default, PartialEq, Deserialize)]
+pub struct Survey {
+    /// Questions, in the order they are asked.
+    pub questions: Vec<Question>,
+}
+
+/// Question of a [`Survey`].
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+pub struct Question {
+    /// Key of the answer, also used as the [name](crate::Text::name) of the prompt.
+    pub key: String,
+    /// Message of the prompt.
+    pub message: String,
+    /// Type of the prompt and its options, from the `type` field.
+    #[serde(flatten)]
+    pub kind: QuestionKind,
+}
+
+/// Type of a [`Question`], with the options of its prompt.
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum QuestionKind {
+    /// [`Text`] prompt, answered with a string.
+    Text {
+        /// Value used when the input is empty.
+        default: Option<String>,
+        /// Hint displayed when the input is empty.
+        placeholder: Option<String>,
+        /// Rules that the value must follow.
+        #[serde(default)]
+        validate: Validation,
+    },
+    /// [`Password`] prompt, answered with a string.
+    Password {
+        /// Rules that the value must follow.
+        #[serde(default)]
+        validate: Validation,
+    },
+    /// [`Number`] prompt, answered with a number.
+    Number {
+        /// Value used when the input is empty.
+        default: Option<f64>,
+        /// Minimum allowed value.
+        min: Option<f64>,
+        /// Maximum allowed value.
+        max: Option<f64>,
+    },
+    /// [`Confirm`] prompt, answered with a boolean.
+    Confirm {
+        /// Initial value, `false` by default.
+        #[serde(default)]
+        default: bool,
+    },
+    /// [`Select`] prompt, answered with the chosen option.
+    Select {
+        /// Options to choose from.
+        options: Vec<String>,
+        /// Index of the initially focused option.
+        default: Option<usize>,
+    },
+    /// [`MultiSelect`] prompt, answered with an array of the chosen options.
+    MultiSelect {
+        /// Options to choose from.
+        options: Vec<String>,
+        /// Indices of the initially selected options.
+        #[serde(default)]
+        default: Vec<usize>,
+        /// Minimum number of options to choose.
+        min: Option<usize>,
+        /// Maximum number of options to choose.
+        max: Option<usize>,
+    },
+}
+
+/// Rules for the value of text questions, all of them optional.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Deserialize)]
+#[serde(default)]
+pub struct Validation {
+    /// Value must not be empty or only whitespace.
+    pub required: bool,
+    /// Minimum number of characters.
+    pub min_len: Option<usize>,
+    /// Maximum number of characters.
+    pub max_len: Option<usize>,
+    /// Value must look like an email address.
+    pub email: bool,
+    /// Value must be an absolute URL.
+    pub url: bool,
+    /// Message displayed when a rule fails, instead of the message of the rule.
+    pub message: Option<String>,
+}
+
+impl Survey {
+    /// Ask the questions and return the answers by their key.
+    ///
+    /// Stops at the first error, like a cancelled prompt.
+    pub fn run(&self) -> Result<Map<String, Value>, Error> {
+        let mut answers = Map::new();
+
+        for question in &self.questions {
+            answers.insert(question.key.clone(), question.ask()?);
+        }
+
+        Ok(answers)
+    }
+}
+
+impl Question {
+    /// Display the prompt of the question and return the answer.
+    pub fn ask(&self) -> Result<Value, Error> {
+        let message = self.message.as_str();
+        let key = self.key.as_str();
+
+        match &self.kind {
+            QuestionKind::Text {
+                default,
+                placeholder,
+                validate,
+            } => {
+                let mut prompt = Text::new(message);
+                prompt.name(key).validate(|value| validate.check(value));
+
+                if let Some(default) = default {
+                    prompt.default(default);
+                }
+
+                if let Some(placeholder) = placeholder {
+                    prompt.placeholder(placeholder);
+                }
+
+                prompt.prompt().map(Value::from)
+            }
+            QuestionKind::Password { validate } => Password::new(message)
+                .name(key)
+                .validate(|value| validate.check(value))
+                .prompt()
+                .map(Value::from),
+            QuestionKind::Number { default, min, max } => {
+                let mut prompt = Number::<f64>::new(message);
+                prompt.name(key);
+
+                if let Some(default) = default {
+                    prompt.default(*default);
+                }
+
+                if let Some(min) = min {
+                    prompt.min(*min);
+                }
+
+                if let Some(max) = max {
+                    prompt.max(*max);
+                }
+
+                prompt.prompt().map(Value::from)
+            }
+            QuestionKind::Confirm { default } => Confirm::new(message)
+                .name(key)
+                .initial(*default)
+                .prompt()
+                .map(Value::from),
+            QuestionKind::Select { options, default } => {
+                let mut prompt = Select::new(message, options.iter().map(String::as_str));
+                prompt.name(key);
+
+                if let Some(default) = default {
+                    prompt.selected(*default);
+                }
+
+                prompt.prompt().map(Value::from)
+            }
+            QuestionKind::MultiSelect {
+                options,
+                default,
+                min,
+                max,
+            } => {
+                let mut prompt = MultiSelect::new(message, options.iter().map(String::as_str));
+                prompt.name(key).selected(default);
+
+                if let Some(min) = min {
+                    prompt.min(*min);
+                }
+
+                if let Some(max) = max {
+                    prompt.max(*max);
+                }
+
+                prompt.prompt().map(Value::from)
+            }
+        }
+    }
+}
+
+impl Validation {
+    /// Check the value against the rules, returning the message of the first that fails.
+    pub fn check(&self, value: &str) -> Result<(), String> {
+        let min_len = self.min_len.unwrap_or(0);
+        let max_len = self.max_len.unwrap_or(usize::MAX);
+
+        let error = if self.required && validators::non_empty("")(value).is_err() {
+            "This field is required".to_string()
+        } else if validators::min_len(min_len, "")(value).is_err() {
+            format!("Must have at least {} characters", min_len)
+        } else if validators::max_len(max_len, "")(value).is_err() {
+            format!("Must have at most {} characters", max_len)
+        } else if self.email && validators::email("")(value).is_err() {
+            "Please enter a valid email".to_string()
+        } else if self.url && validators::url("")(value).is_err() {
+            "Please enter a valid URL".to_string()
+        } else {
+            return Ok(());
+        };
+
+        Err(self.message.clone().unwrap_or(error))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parse_questions() {
+        let survey: Survey = serde_json::from_str(
+            r#"{
+                "questions": [
+                    { "key": "name", "message": "Name", "type": "text", "validate": { "required": true, "max_len": 3 } },
+                    { "key": "port", "message": "Port", "type": "number", "default": 8080, "min": 1 },
+                    { "key": "tags", "message": "Tags", "type": "multi_select", "options": ["a", "b"] }
+                ]
+            }"#,
+        )
+        .unwrap();
+
+        let [name, port, tags] = survey.questions.as_slice() else {
+            panic!("expected 3 questions");
+        };
+
+        let QuestionKind::Text { validate, .. } = &name.kind else {
+            panic!("expected a text question");
+        };
+
+        assert_eq!(
+            validate.check(""),
+            Err("This field is required".to_string())
+        );
+        assert_eq!(
+            validate.check("abcd"),
+            Err("Must have at most 3 characters".to_string())
+        );
+        assert_eq!(validate.check("abc"), Ok(()));
+
+        assert_eq!(
+            port.kind,
+            QuestionKind::Number {
+                default: Some(8080.0),
+                min: Some(1.0),
+                max: None,
+            }
+        );
+        assert_eq!(
+            tags.kind,
+            QuestionKind::MultiSelect {
+                options: vec!["a".into(), "b".into()],
+                default: vec![],
+                min: None,
+                max: None,
+            }
+        );
+    }
+}
@@ -0,0 +1,330 @@
+use std::{
+    cell::Cell,
+    future::Future,
+    pin::Pin,
+    sync::{Condvar, Mutex, MutexGuard, PoisonError},
+    task::{Context, Poll, Waker},
+    thread::{self, ThreadId},
+};
+
+/// Owner of the lock, the lock is reentrant for the same owner.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Owner {
+    /// Thread that called [`lock_terminal`].
+    Thread(ThreadId),
+    /// Task that awaited [`lock_terminal_async`], by the id of the future.
+    Task(u64),
+}
+
+struct LockState {
+    owner: Option<Owner>,
+    depth: usize,
+    next_id: u64,
+    /// Waker of each pending future, by its id.
+    waiters: Vec<(u64, Waker)>,
+}
+
+static STATE: Mutex<LockState> = Mutex::new(LockState {
+    owner: None,
+    depth: 0,
+    next_id: 0,
+    waiters: Vec::new(),
+});
+
+static RELEASED: Condvar = Condvar::new();
+
+thread_local! {
+    /// Task owning the lock while a [`with_terminal_lock`] future is polled in this thread.
+    static SCOPE: Cell<Option<u64>> = const { Cell::new(None) };
+}
+
+// The state is always left consistent, so a poisoned lock can be used safely
+fn lock_state() -> MutexGuard<'static, LockState> {
+    STATE.lock().unwrap_or_else(PoisonError::into_inner)
+}
+
+/// Returns the owner of the current thread, the task of the scope being polled if any.
+fn current_owner() -> Owner {
+    match SCOPE.get() {
+        Some(id) => Owner::Task(id),
+        None => Owner::Thread(thread::current().id()),
+    }
+}
+
+/// Try to take the lock for the owner, it's reentrant.
+fn try_acquire(state: &mut LockState, owner: Owner) -> bool {
+    match state.owner {
+        Some(current) if current != owner => false,
+        _ => {
+            state.owner = Some(owner);
+            state.depth += 1;
+            true
+        }
+    }
+}
+
+/// Guard that gives exclusive access to the terminal for displaying prompts.
+///
+/// The lock is released when the guard is dropped, even while panicking.
+#[must_use = "the terminal is unlocked when the guard is dropped"]
+pub struct TerminalGuard {
+    _private: (),
+}
+
+impl TerminalGuard {
+    fn new() -> Self {
+        TerminalGuard { _private: () }
+    }
+}
+
+impl Drop for TerminalGuard {
+    fn drop(&mut self) {
+        let mut state = lock_state();
+
+        state.depth -= 1;
+
+        if state.depth == 0 {
+            state.owner = None;
+            state
+                .waiters
+                .iter()
+                .for_each(|(_, waker)| waker.wake_by_ref());
+            RELEASED.notify_all();
+        }
+    }
+}
+
+/// Lock the terminal, blocking the current thread until it's available.
+///
+/// All the prompts take this lock while they are displayed, so prompts from
+/// different threads never interleave. Holding the guard allows to display several
+/// prompts in a row without other threads in between, as the lock is reentrant.
+///
+/// # Examples
+///
+/// ```no_run
+/// use asky::{Password, Text};
+///
+/// # fn main() -> std::io::Result<()> {
+/// let _guard = asky::lock_terminal();
+///
+/// let user = Text::new("Username:").prompt()?;
+/// let password = Password::new("Password:").prompt()?;
+/// # Ok(())
+/// # }
+/// ```
+pub fn lock_terminal() -> TerminalGuard {
+    let owner = current_owner();
+    let mut state = lock_state();
+
+    while !try_acquire(&mut state, owner) {
+        state = RELEASED.wait(state).unwrap_or_else(PoisonError::into_inner);
+    }
+
+    TerminalGuard::new()
+}
+
+/// Lock the terminal if it's available, without blocking.
+///
+/// See [`lock_terminal`].
+pub fn try_lock_terminal() -> Option<TerminalGuard> {
+    match try_acquire(&mut lock_state(), current_owner()) {
+        true => Some(TerminalGuard::new()),
+        false => None,
+    }
+}
+
+/// Lock the terminal without blocking the async executor.
+///
+/// The returned future resolves when the terminal is available, like an async mutex, so two
+/// tasks never hold it at the same time, even in the same thread. The guard can be sent to
+/// other threads.
+///
+/// It's not reentrant, awaiting a prompt while holding the guard waits forever.
+/// Use [`with_terminal_lock`] to display several prompts in a row instead.
+pub fn lock_terminal_async() -> TerminalLockFuture {
+    TerminalLockFuture { id: None }
+}
+
+/// Future returned by [`lock_terminal_async`].
+#[must_use = "futures do nothing unless you `.await` or poll them"]
+pub struct TerminalLockFuture {
+    id: Option<u64>,
+}
+
+impl TerminalLockFuture {
+    /// Returns the id of the future, assigned on the first poll.
+    fn id(&mut self, state: &mut LockState) -> u64 {
+        *self.id.get_or_insert_with(|| {
+            state.next_id += 1;
+            state.next_id
+        })
+    }
+}
+
+impl Future for TerminalLockFuture {
+    type Output = TerminalGuard;
+
+    fn poll(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        let mut state = lock_state();
+        let id = self.id(&mut state);
+        let owner = SCOPE.get().map_or(Owner::Task(id), Owner::Task);
+
+        if try_acquire(&mut state, owner) {
+            state.waiters.retain(|(waiter, _)| *waiter != id);
+            return Poll::Ready(TerminalGuard::new());
+        }
+
+        match state.waiters.iter_mut().find(|(waiter, _)| *waiter == id) {
+            Some((_, waker)) => waker.clone_from(cx.waker()),
+            None => state.waiters.push((id, cx.waker().clone())),
+        }
+
+        Poll::Pending
+    }
+}
+
+impl Drop for TerminalLockFuture {
+    fn drop(&mut self) {
+        if let Some(id) = self.id {
+            lock_state().waiters.retain(|(waiter, _)| *waiter != id);
+        }
+    }
+}
+
+/// Run a future holding the terminal lock, so its prompts are displayed in a row
+/// without other tasks or threads in between.
+///
+/// The prompts awaited by the future take the lock again, as it's reentrant inside the future.
+///
+/// # Examples
+///
+/// ```no_run
+/// use asky::{Password, Text};
+///
+/// # fn main() {}
+/// # #[cfg(feature = "async")]
+/// # async fn login() -> std::io::Result<()> {
+/// let (user, password) = asky::with_terminal_lock(async {
+///     let user = Text::new("Username:").prompt_async().await?;
+///     let password = Password::new("Password:").prompt_async().await?;
+///     Ok::<_, asky::Error>((user, password))
+/// })
+/// .await?;
+/// # Ok(())
+/// # }
+/// ```
+pub fn with_terminal_lock<F: Future>(future: F) -> TerminalScope<F> {
+    TerminalScope {
+        lock: lock_terminal_async(),
+        guard: None,
+        future: Box::pin(future),
+    }
+}
+
+/// Future returned by [`with_terminal_lock`].
+#[must_use = "futures do nothing unless you `.await` or poll them"]
+pub struct TerminalScope<F> {
+    lock: TerminalLockFuture,
+    guard: Option<TerminalGuard>,
+    future: Pin<Box<F>>,
+}
+
+impl<F: Future> Future for TerminalScope<F> {
+    type Output = F::Output;
+
+    fn poll(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        let this = &mut *self;
+
+        if this.guard.is_none() {
+            match Pin::new(&mut this.lock).poll(cx) {
+                Poll::Ready(guard) => this.guard = Some(guard),
+                Poll::Pending => return Poll::Pending,
+            }
+        }
+
+        // the lock future got its id when it was polled
+        let previous = SCOPE.replace(this.lock.id);
+        let result = this.future.as_mut().poll(cx);
+        SCOPE.set(previous);
+
+        if result.is_ready() {
+            this.guard = None;
+        }
+
+        result
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// The lock is global, so the tests must not run at the same time.
+    static SERIAL: Mutex<()> = Mutex::new(());
+
+    fn poll<F: Future + Unpin>(future: &mut F) -> Poll<F::Output> {
+        Pin::new(future).poll(&mut Context::from_waker(Waker::noop()))
+    }
+
+    #[test]
+    fn reentrant_lock() {
+        let _serial = SERIAL.lock().unwrap_or_else(PoisonError::into_inner);
+        let _outer = lock_terminal();
+        let inner = try_lock_terminal();
+
+        assert!(inner.is_some());
+    }
+
+    #[test]
+    fn exclusive_between_threads() {
+        let _serial = SERIAL.lock().unwrap_or_else(PoisonError::into_inner);
+        let guard = lock_terminal();
+        let locked_in_thread = thread::spawn(|| try_lock_terminal().is_some())
+            .join()
+            .unwrap();
+
+        assert!(!locked_in_thread);
+
+        drop(guard);
+
+        let locked_in_thread = thread::spawn(|| {
+            let _guard = lock_terminal();
+            true
+        })
+        .join()
+        .unwrap();
+
+        assert!(locked_in_thread);
+    }
+
+    #[test]
+    fn exclusive_between_tasks() {
+        fn assert_send<T: Send>(_: &T) {}
+
+        let _serial = SERIAL.lock().unwrap_or_else(PoisonError::into_inner);
+        let mut first = lock_terminal_async();
+        let mut second = lock_terminal_async();
+
+        let Poll::Ready(guard) = poll(&mut first) else {
+            panic!("the terminal should be available");
+        };
+
+        assert_send(&guard);
+        assert!(poll(&mut second).is_pending());
+        assert!(poll(&mut second).is_pending());
+        assert_eq!(lock_state().waiters.len(), 1);
+
+        drop(guard);
+        assert!(poll(&mut second).is_ready());
+        assert!(lock_state().waiters.is_empty());
+
+        let mut scope = with_terminal_lock(async {
+            let _prompt = lock_terminal_async().await;
+            let _nested = lock_terminal();
+        });
+
+        assert!(poll(&mut scope).is_ready());
+        assert!(try_lock_terminal().is_some());
+    }
+}
@@ -1,71 +1,110 @@
-use colored::Colorize;
+use std::{borrow::Cow, time::Duration};
+
+use colored::{ColoredString, Colorize};
+use crossterm::terminal;
+use unicode_width::{UnicodeWidthChar, UnicodeWidthStr};
 
 use crate::prompts::{
     confirm::Confirm,
     multi_select::MultiSelect,
     number::Number,
-    password::Password,
+    password::{Password, PasswordVisibility, Strength},
+    percent::Percent,
     select::{Select, SelectInput, SelectOption},
     text::Text,
     toggle::Toggle,
+    type_to_confirm::TypeToConfirm,
 };
 
-use super::{num_like::NumLike, renderer::DrawTime};
+use super::{
+    answer::Answer,
+    fuzzy,
+    num_like::NumLike,
+    renderer::DrawTime,
+    settings::{AskySettings, GlyphSet, Layout},
+    summary::Summary,
+};
 
 pub fn fmt_confirm(prompt: &Confirm, draw_time: DrawTime) -> String {
-    let options = ["No", "Yes"];
+    let options = ["No", "Yes", "Never", "Always"];
+    let options = &options[..if prompt.with_policy { 4 } else { 2 }];
+    let focused = prompt.active as usize + 2 * prompt.apply_to_rest as usize;
 
-    if draw_time == DrawTime::Last {
-        return fmt_last_message(prompt.message, options[prompt.active as usize]);
+    if draw_time.is_final() {
+        return fmt_last_message(draw_time, prompt.message, options[focused])
+            + &fmt_quiz_feedback(draw_time, prompt.is_correct(), prompt.explanation);
     }
 
-    [
-        fmt_message(prompt.message),
-        fmt_toggle_options(options, prompt.active),
-    ]
-    .join("\n")
+    let message = fmt_message(prompt.message);
+    let options = fmt_button_options(options, focused, prompt.initial_active as usize);
+
+    let Some(preview) = prompt.preview else {
+        return fmt_head_and_input(message, options);
+    };
+
+    let preview = fmt_preview(preview, prompt.preview_offset, prompt.preview_height);
+
+    // keep the preview above the options
+    match AskySettings::global().layout {
+        Layout::Stacked => [message, preview, options].join("\n"),
+        Layout::Columns { .. } => [preview, fmt_head_and_input(message, options)].join("\n"),
+    }
 }
 
 pub fn fmt_toggle(prompt: &Toggle, draw_time: DrawTime) -> String {
-    if draw_time == DrawTime::Last {
-        return fmt_last_message(prompt.message, prompt.options[prompt.active as usize]);
+    if draw_time.is_final() {
+        return fmt_last_message(
+            draw_time,
+            prompt.message,
+            prompt.options[prompt.active as usize],
+        ) + &fmt_quiz_feedback(draw_time, prompt.is_correct(), prompt.explanation);
     }
 
-    [
+    fmt_head_and_input(
         fmt_message(prompt.message),
-        fmt_toggle_options(prompt.options, prompt.active),
-    ]
-    .join("\n")
+        fmt_toggle_options(prompt.options, prompt.active, prompt.initial_active),
+    )
 }
 
 pub fn fmt_select<T>(prompt: &Select<T>, draw_time: DrawTime) -> String {
-    if draw_time == DrawTime::Last {
-        return fmt_last_message(prompt.message, &prompt.options[prompt.input.focused].title);
+    if draw_time.is_final() {
+        let answer = match (prompt.multi_pick, prompt.input.focused_index()) {
+            (false, Some(focused)) => prompt
+                .report(focused)
+                .unwrap_or_else(|| prompt.options[focused].get_title().to_string()),
+            _ => fmt_active_titles(&prompt.options),
+        };
+
+        return fmt_last_message(draw_time, prompt.message, &answer)
+            + &fmt_quiz_feedback(draw_time, prompt.is_correct(), prompt.explanation);
     }
 
-    [
-        fmt_message(prompt.message),
-        fmt_select_page_options(&prompt.options, &prompt.input, false),
-        fmt_select_pagination(prompt.input.get_page(), prompt.input.count_pages()),
-    ]
-    .join("\n")
+    let mut lines = vec![fmt_message(prompt.message)];
+
+    if prompt.filterable {
+        lines.push(fmt_select_filter(&prompt.input));
+    }
+
+    lines.push(fmt_select_page_options(
+        &prompt.options,
+        &prompt.input,
+        false,
+    ));
+    lines.push(fmt_select_pagination(
+        prompt.input.get_page(),
+        prompt.input.count_pages(),
+    ));
+
+    lines.join("\n")
 }
 
 pub fn fmt_multi_select<T>(prompt: &MultiSelect<T>, draw_time: DrawTime) -> String {
-    if draw_time == DrawTime::Last {
-        return fmt_last_message(
-            prompt.message,
-            &format!(
-                "[{}]",
-                prompt
-                    .options
-                    .iter()
-                    .filter(|opt| opt.active)
-                    .map(|opt| opt.title.as_str())
-                    .collect::<Vec<_>>()
-                    .join(", "),
-            ),
-        );
+    if draw_time.is_final() {
+        let answer = prompt
+            .report()
+            .unwrap_or_else(|| fmt_active_titles(&prompt.options));
+
+        return fmt_last_message(draw_time, prompt.message, &answer);
     }
 
     [
@@ -77,128 +116,471 @@ pub fn fmt_multi_select<T>(prompt: &MultiSelect<T>, draw_time: DrawTime) -> Stri
 }
 
 pub fn fmt_text(prompt: &Text, draw_time: DrawTime) -> (String, [usize; 2]) {
-    if draw_time == DrawTime::Last {
+    let adornments = (prompt.prefix, prompt.suffix);
+
+    if draw_time.is_final() {
+        let (answer, defaulted) = get_line_answer(&prompt.input.value, prompt.default_value);
+        let answer = prompt
+            .report(answer)
+            .unwrap_or_else(|| match prompt.sensitive {
+                true => glyphs().ellipsis.to_string(),
+                false => fmt_adorned_answer(answer, adornments),
+            });
+
         return (
-            fmt_last_message(prompt.message, &prompt.input.value),
+            fmt_last_line_message(draw_time, prompt.message, &answer, defaulted),
             [0, 0],
         );
     }
 
-    (
-        [
-            fmt_line_message(prompt.message, &prompt.default_value),
-            fmt_line_input(
-                &prompt.input.value,
-                &prompt.placeholder,
-                &prompt.validator_result,
-                false,
-            ),
-            fmt_line_validator(&prompt.validator_result),
-        ]
-        .join("\n"),
-        get_cursor_position(prompt.input.col),
+    fmt_line_prompt(
+        prompt.message,
+        &prompt.default_value,
+        fmt_line_input(
+            &prompt.input.value,
+            &prompt.placeholder,
+            &prompt.validator_result,
+            false,
+            adornments,
+        ),
+        (&prompt.validator_result, prompt.validator_edited),
+        prompt.input.cursor_width() + prompt.prefix.map_or(0, |prefix| prefix.width()),
     )
 }
 
 pub fn fmt_password(prompt: &Password, draw_time: DrawTime) -> (String, [usize; 2]) {
-    if draw_time == DrawTime::Last {
-        return (fmt_last_message(prompt.message, "…"), [0, 0]);
+    if draw_time.is_final() {
+        let (_, defaulted) = get_line_answer(&prompt.input.value, prompt.default_value);
+
+        return (
+            fmt_last_line_message(draw_time, prompt.message, glyphs().ellipsis, defaulted),
+            [0, 0],
+        );
     }
 
-    let text = match prompt.hidden {
-        true => String::new(),
-        false => "*".repeat(prompt.input.value.len()),
+    let (text, cursor_col, indicator) = match prompt.visibility {
+        PasswordVisibility::Hidden => (String::new(), 0, Some(" [hidden]")),
+        PasswordVisibility::Masked => ("*".repeat(prompt.input.len()), prompt.input.col, None),
+        PasswordVisibility::Visible => (
+            prompt.input.value.clone(),
+            prompt.input.cursor_width(),
+            Some(" [visible]"),
+        ),
+    };
+    let message = match prompt.confirming {
+        true => prompt.confirm_message.unwrap_or(prompt.message),
+        false => prompt.message,
     };
 
-    let cursor_col = if prompt.hidden { 0 } else { prompt.input.col };
+    let mut input = fmt_line_input(
+        &text,
+        &prompt.placeholder,
+        &prompt.validator_result,
+        false,
+        (None, indicator),
+    );
 
-    (
-        [
-            fmt_line_message(prompt.message, &prompt.default_value),
-            fmt_line_input(&text, &prompt.placeholder, &prompt.validator_result, false),
-            fmt_line_validator(&prompt.validator_result),
-        ]
-        .join("\n"),
-        get_cursor_position(cursor_col),
+    // the repeated entry is only compared, and the strength would reveal a hidden input
+    let hidden = prompt.visibility == PasswordVisibility::Hidden;
+
+    if let (Some(strength), false, false) = (prompt.get_strength(), prompt.confirming, hidden) {
+        input = format!("{}\n{}", input, fmt_password_strength(strength));
+    }
+
+    fmt_line_prompt(
+        message,
+        &prompt.default_value,
+        input,
+        (&prompt.validator_result, prompt.validator_edited),
+        cursor_col,
     )
 }
 
 pub fn fmt_number<T: NumLike>(prompt: &Number<T>, draw_time: DrawTime) -> (String, [usize; 2]) {
-    if draw_time == DrawTime::Last {
+    let adornments = (prompt.prefix, prompt.suffix);
+
+    if draw_time.is_final() {
+        let (answer, defaulted) =
+            get_line_answer(&prompt.input.value, prompt.default_value.as_deref());
+        let answer = prompt.report().unwrap_or_else(|| match prompt.sensitive {
+            true => glyphs().ellipsis.to_string(),
+            false => fmt_adorned_answer(answer, adornments),
+        });
+
         return (
-            fmt_last_message(prompt.message, &prompt.input.value),
+            fmt_last_line_message(draw_time, prompt.message, &answer, defaulted),
             [0, 0],
         );
     }
 
-    (
-        [
-            fmt_line_message(prompt.message, &prompt.default_value.as_deref()),
-            fmt_line_input(
-                &prompt.input.value,
-                &prompt.placeholder,
-                &prompt.validator_result,
-                true,
-            ),
-            fmt_line_validator(&prompt.validator_result),
-        ]
-        .join("\n"),
-        get_cursor_position(prompt.input.col),
+    fmt_line_prompt(
+        prompt.message,
+        &prompt.default_value.as_deref(),
+        fmt_line_input(
+            &prompt.input.value,
+            &prompt.placeholder,
+            &prompt.validator_result,
+            true,
+            adornments,
+        ),
+        (&prompt.validator_result, prompt.validator_edited),
+        prompt.input.cursor_width() + prompt.prefix.map_or(0, |prefix| prefix.width()),
+    )
+}
+
+pub fn fmt_type_to_confirm(prompt: &TypeToConfirm, draw_time: DrawTime) -> (String, [usize; 2]) {
+    if draw_time.is_final() {
+        let answer = match prompt.is_match() {
+            true => prompt.input.value.normal(),
+            false => prompt.input.value.red().strikethrough(),
+        };
+
+        return (
+            fmt_last_message(draw_time, prompt.message, &answer.to_string()),
+            [0, 0],
+        );
+    }
+
+    // split the input by the matched prefix
+    let matched = prompt.matched_len();
+    let split = prompt
+        .input
+        .value
+        .char_indices()
+        .nth(matched)
+        .map_or(prompt.input.value.len(), |(i, _)| i);
+    let (valid, invalid) = prompt.input.value.split_at(split);
+    let input = format!("{}{}", valid.green(), invalid.red());
+
+    fmt_line_prompt(
+        prompt.message,
+        &None,
+        fmt_line_input(&input, &None, &Ok(()), false, (None, None)),
+        (&Ok(()), false),
+        prompt.input.cursor_width(),
+    )
+}
+
+pub fn fmt_percent(prompt: &Percent, draw_time: DrawTime) -> String {
+    let value = format!("{}%", prompt.value);
+
+    if draw_time.is_final() {
+        return fmt_last_message(draw_time, prompt.message, &value);
+    }
+
+    fmt_head_and_input(
+        fmt_message(prompt.message),
+        format!(
+            "{} {} {}",
+            glyphs().input.blue(),
+            fmt_percent_bar(prompt.value),
+            value.yellow()
+        ),
     )
 }
 
 // region: general
 
+pub fn fmt_form_title(title: &str) -> String {
+    title.bold().to_string()
+}
+
+pub fn fmt_flow_progress(step: usize, total: usize) -> String {
+    format!("Question {} of {}", step, total)
+        .bright_black()
+        .to_string()
+}
+
+pub fn fmt_countdown(remaining: Duration) -> String {
+    // round up, so the countdown ends when the time runs out
+    let secs = remaining.as_nanos().div_ceil(1_000_000_000);
+
+    format!("Time left {}:{:02}", secs / 60, secs % 60)
+        .bright_black()
+        .to_string()
+}
+
+pub fn fmt_form_skipped(message: &str) -> String {
+    format!(
+        "{} {} {}",
+        glyphs().answered.bright_black(),
+        fmt_label(message),
+        "skipped".bright_black()
+    )
+}
+
 fn fmt_message(message: &str) -> String {
-    format!("{} {}", "▣".blue(), message)
+    format!("{} {}", glyphs().message.blue(), fmt_label(message))
+}
+
+fn fmt_last_message(draw_time: DrawTime, message: &str, answer: &str) -> String {
+    if draw_time == DrawTime::Cancelled {
+        return format!(
+            "{} {}",
+            glyphs().cancelled.red(),
+            fmt_label(message).bright_black()
+        );
+    }
+
+    format!(
+        "{} {} {}",
+        glyphs().answered.green(),
+        fmt_label(message),
+        answer.purple()
+    )
+}
+
+/// Same as [`fmt_last_message`], but the symbol is yellow when the default value was submitted.
+fn fmt_last_line_message(
+    draw_time: DrawTime,
+    message: &str,
+    answer: &str,
+    defaulted: bool,
+) -> String {
+    if draw_time == DrawTime::Cancelled || !defaulted {
+        return fmt_last_message(draw_time, message, answer);
+    }
+
+    format!(
+        "{} {} {}",
+        glyphs().answered.yellow(),
+        fmt_label(message),
+        answer.purple()
+    )
+}
+
+/// Submitted value of a line input, and whether it's the default one.
+fn get_line_answer<'a>(value: &'a str, default_value: Option<&'a str>) -> (&'a str, bool) {
+    match (value, default_value) {
+        ("", Some(default_value)) => (default_value, true),
+        _ => (value, false),
+    }
 }
 
-fn fmt_last_message(message: &str, answer: &str) -> String {
-    format!("{} {} {}", "■".green(), message, answer.purple())
+/// Feedback lines after the answer, when the prompt has a correct answer.
+fn fmt_quiz_feedback(
+    draw_time: DrawTime,
+    is_correct: Option<bool>,
+    explanation: Option<&str>,
+) -> String {
+    let feedback = match is_correct {
+        _ if draw_time == DrawTime::Cancelled => return String::new(),
+        None => return String::new(),
+        Some(true) => format!("{}", "✔ Correct".green()),
+        Some(false) => format!("{}", "✖ Incorrect".red()),
+    };
+
+    match explanation {
+        Some(explanation) => format!("\n  {}\n  {}", feedback, explanation.bright_black()),
+        None => format!("\n  {}", feedback),
+    }
+}
+
+fn glyphs() -> GlyphSet {
+    AskySettings::global().theme.glyphs()
+}
+
+/// Pad the message to the label width when using [`Layout::Columns`].
+fn fmt_label(message: &str) -> String {
+    match AskySettings::global().layout {
+        Layout::Stacked => message.to_string(),
+        Layout::Columns { label_width } => pad_to_width(message, label_width),
+    }
+}
+
+/// Pad the text with spaces to the width, counting the columns of wide chars like CJK or emoji.
+fn pad_to_width(text: &str, width: usize) -> String {
+    let padding = width.saturating_sub(text.width());
+    format!("{}{}", text, " ".repeat(padding))
+}
+
+/// Join the message line with the input line, based on the layout.
+fn fmt_head_and_input(head: String, input: String) -> String {
+    match AskySettings::global().layout {
+        Layout::Stacked => [head, input].join("\n"),
+        Layout::Columns { .. } => format!("{} {}", head, input),
+    }
 }
 
 // endregion: general
 
 // region: toggle
 
-fn fmt_toggle_options(options: [&str; 2], active: bool) -> String {
-    let fmt_option = |opt, active| {
-        let opt = format!(" {} ", opt);
-        match active {
-            true => opt.black().on_blue(),
-            false => opt.white().on_bright_black(),
-        }
+fn fmt_toggle_options(options: [&str; 2], active: bool, initial_active: bool) -> String {
+    fmt_button_options(&options, active as usize, initial_active as usize)
+}
+
+fn fmt_button_options(options: &[&str], focused: usize, initial: usize) -> String {
+    let [open, close] = glyphs().toggle_brackets;
+    let padding = [" ".repeat(open.width()), " ".repeat(close.width())];
+
+    options
+        .iter()
+        .enumerate()
+        .map(|(i, opt)| {
+            let opt = match i == focused {
+                true => format!("{}{}{}", open, opt, close).black().on_blue(),
+                false => format!("{}{}{}", padding[0], opt, padding[1])
+                    .white()
+                    .on_bright_black(),
+            };
+
+            // mark the default option
+            match i == initial {
+                true => opt.underline(),
+                false => opt,
+            }
+            .to_string()
+        })
+        .collect::<Vec<_>>()
+        .join("  ")
+}
+
+fn fmt_preview(preview: &str, offset: usize, height: usize) -> String {
+    let total = preview.lines().count();
+    let mut lines: Vec<String> = preview
+        .lines()
+        .skip(offset)
+        .take(height)
+        .map(|line| format!("{} {}", "│".bright_black(), line))
+        .collect();
+
+    if total > height {
+        let end = (offset + height).min(total);
+        let position = format!("{}-{} of {} · ↑↓ to scroll", offset + 1, end, total);
+
+        lines.push(format!("  {}", position.bright_black()));
+    }
+
+    lines.join("\n")
+}
+
+// endregion: toggle
+
+// region: percent
+
+fn fmt_percent_bar(value: u8) -> String {
+    const WIDTH: usize = 20;
+    let filled = (value as usize * WIDTH + 50) / 100;
+
+    format!(
+        "{}{}",
+        "■".repeat(filled).blue(),
+        "■".repeat(WIDTH - filled).bright_black()
+    )
+}
+
+// endregion: percent
+
+// region: password
+
+fn fmt_password_strength(strength: Strength) -> String {
+    let (filled, label) = match strength {
+        Strength::Weak => (1, "Weak"),
+        Strength::Medium => (2, "Medium"),
+        Strength::Strong => (3, "Strong"),
+    };
+
+    let bar = "■".repeat(filled * 4);
+    let (bar, label) = match strength {
+        Strength::Weak => (bar.red(), label.red()),
+        Strength::Medium => (bar.yellow(), label.yellow()),
+        Strength::Strong => (bar.green(), label.green()),
     };
 
     format!(
-        "{}  {}",
-        fmt_option(options[0], !active),
-        fmt_option(options[1], active)
+        "  {}{} {}",
+        bar,
+        "■".repeat((3 - filled) * 4).bright_black(),
+        label
     )
 }
 
-// endregion: toggle
+// endregion: password
 
 // region: line
 
+fn fmt_line_prompt(
+    message: &str,
+    default_value: &Option<&str>,
+    input: String,
+    (validator_result, validator_edited): (&Result<(), Cow<str>>, bool),
+    cursor_col: usize,
+) -> (String, [usize; 2]) {
+    match AskySettings::global().layout {
+        Layout::Stacked => (
+            [
+                fmt_line_message(message, default_value),
+                input,
+                fmt_line_validator(validator_result, validator_edited),
+            ]
+            .join("\n"),
+            get_cursor_position(cursor_col),
+        ),
+        Layout::Columns { label_width } => fmt_columns_line_prompt(
+            message,
+            default_value,
+            input,
+            (validator_result, validator_edited),
+            cursor_col,
+            label_width,
+        ),
+    }
+}
+
+/// Line prompt with the message in a label column of [`Layout::Columns`] and the input at its right.
+fn fmt_columns_line_prompt(
+    message: &str,
+    default_value: &Option<&str>,
+    input: String,
+    (validator_result, validator_edited): (&Result<(), Cow<str>>, bool),
+    cursor_col: usize,
+    label_width: usize,
+) -> (String, [usize; 2]) {
+    let lines: Vec<String> = [
+        format!(
+            "{} {} {}",
+            glyphs().message.blue(),
+            pad_to_width(message, label_width),
+            input
+        ),
+        fmt_line_default(default_value).to_string(),
+        fmt_line_validator(validator_result, validator_edited),
+    ]
+    .into_iter()
+    .filter(|line| !line.is_empty())
+    .collect();
+
+    // icon + space + label + space
+    let label_len = glyphs().message.width() + 1 + message.width().max(label_width) + 1;
+
+    (
+        lines.join("\n"),
+        [label_len + get_cursor_position(cursor_col)[0], 0],
+    )
+}
+
 fn fmt_line_message(msg: &str, default_value: &Option<&str>) -> String {
-    let value = match default_value {
+    format!("{} {}", fmt_message(msg), fmt_line_default(default_value))
+}
+
+fn fmt_line_default(default_value: &Option<&str>) -> ColoredString {
+    match default_value {
         Some(value) => format!("Default: {}", value).bright_black(),
         None => "".normal(),
-    };
-
-    format!("{} {}", fmt_message(msg), value)
+    }
 }
 
 fn fmt_line_input(
     input: &str,
     placeholder: &Option<&str>,
-    validator_result: &Result<(), &str>,
+    validator_result: &Result<(), Cow<str>>,
     is_number: bool,
+    (prefix, suffix): (Option<&str>, Option<&str>),
 ) -> String {
-    let prefix = match validator_result {
-        Ok(_) => "›".blue(),
-        Err(_) => "›".red(),
+    let icon = match validator_result {
+        Ok(_) => glyphs().input.blue(),
+        Err(_) => glyphs().input.red(),
     };
 
     let input = match (input.is_empty(), is_number) {
@@ -207,18 +589,35 @@ fn fmt_line_input(
         (false, false) => input.normal(),
     };
 
-    format!("{} {}", prefix, input)
+    format!(
+        "{} {}{}{}",
+        icon,
+        prefix.unwrap_or_default().bright_black(),
+        input,
+        suffix.unwrap_or_default().bright_black()
+    )
 }
 
-fn fmt_line_validator(validator_result: &Result<(), &str>) -> String {
-    match validator_result {
-        Ok(_) => String::new(),
-        Err(e) => format!("{}", e.red()),
+fn fmt_adorned_answer(answer: &str, (prefix, suffix): (Option<&str>, Option<&str>)) -> String {
+    format!(
+        "{}{}{}",
+        prefix.unwrap_or_default(),
+        answer,
+        suffix.unwrap_or_default()
+    )
+}
+
+fn fmt_line_validator(validator_result: &Result<(), Cow<str>>, edited: bool) -> String {
+    match (validator_result, edited) {
+        (Ok(_), _) => String::new(),
+        (Err(e), false) => format!("{}", e.red()),
+        (Err(e), true) => format!("{}", e.red().dimmed()),
     }
 }
 
 fn get_cursor_position(cursor_col: usize) -> [usize; 2] {
-    let x = 2 + cursor_col;
+    // icon + space + input
+    let x = glyphs().input.width() + 1 + cursor_col;
     let y = 1;
 
     [x, y]
@@ -249,26 +648,53 @@ fn fmt_select_page_options<T>(
     let total = input.total_items;
 
     let page_len = items_per_page.min(total);
-    let page_start = input.get_page() * items_per_page;
-    let page_end = (page_start + page_len).min(total);
     let page_focused = input.focused % items_per_page;
 
-    let mut page_options: Vec<String> = options[page_start..page_end]
-        .iter()
+    let mut page_options: Vec<String> = input
+        .visible_range()
         .enumerate()
-        .map(|(i, option)| fmt_select_option(option, page_focused == i, is_multiple))
+        .map(|(i, position)| {
+            let option = &options[input.option_index(position)];
+
+            match option.separator {
+                true => fmt_select_separator(option),
+                false => fmt_select_option(option, page_focused == i, is_multiple, &input.filter),
+            }
+        })
         .collect();
 
     page_options.resize(page_len, String::new());
     page_options.join("\n")
 }
 
+fn fmt_active_titles<T>(options: &[SelectOption<T>]) -> String {
+    format!(
+        "[{}]",
+        options
+            .iter()
+            .filter(|opt| opt.active)
+            .map(|opt| opt.get_title())
+            .collect::<Vec<_>>()
+            .join(", "),
+    )
+}
+
+fn fmt_select_filter(input: &SelectInput) -> String {
+    let filter = match (input.filter.is_empty(), input.total_items) {
+        (true, _) => "Type to filter".bright_black().to_string(),
+        (false, 0) => format!("{} {}", input.filter, "· No matches".bright_black()),
+        (false, _) => input.filter.clone(),
+    };
+
+    format!("{} {}", glyphs().input.blue(), filter)
+}
+
 fn fmt_select_pagination(page: usize, pages: usize) -> String {
-    if pages == 1 {
+    if pages <= 1 {
         return String::new();
     }
 
-    let icon = "•";
+    let icon = glyphs().page_dot;
 
     format!(
         "\n  {}{}{}",
@@ -278,12 +704,27 @@ fn fmt_select_pagination(page: usize, pages: usize) -> String {
     )
 }
 
-fn fmt_select_option<T>(option: &SelectOption<T>, focused: bool, multiple: bool) -> String {
+fn fmt_select_separator<T>(option: &SelectOption<T>) -> String {
+    let title = match option.get_title() {
+        "" => glyphs().separator.repeat(16),
+        title => title.to_string(),
+    };
+
+    format!("  {}", title.bright_black())
+}
+
+fn fmt_select_option<T>(
+    option: &SelectOption<T>,
+    focused: bool,
+    multiple: bool,
+    filter: &str,
+) -> String {
+    let glyphs = glyphs();
     let prefix = if multiple {
         let prefix = match (option.active, focused) {
-            (true, true) => "◉",
-            (true, false) => "●",
-            _ => "○",
+            (true, true) => glyphs.checkbox_focused,
+            (true, false) => glyphs.checkbox_on,
+            _ => glyphs.checkbox_off,
         };
 
         match (focused, option.active, option.disabled) {
@@ -293,28 +734,220 @@ fn fmt_select_option<T>(option: &SelectOption<T>, focused: bool, multiple: bool)
             (false, false, _) => prefix.bright_black(),
         }
     } else {
-        match (focused, option.disabled) {
-            (false, _) => "○".bright_black(),
-            (true, true) => "○".red(),
-            (true, false) => "●".blue(),
+        // active options are the picked ones in multi-pick mode
+        match (focused, option.disabled, option.active) {
+            (false, _, true) => glyphs.radio_on.normal(),
+            (false, _, false) => glyphs.radio_off.bright_black(),
+            (true, true, _) => glyphs.radio_off.red(),
+            (true, false, _) => glyphs.radio_on.blue(),
         }
     };
 
-    let title = &option.title;
-    let title = match (option.disabled, focused) {
-        (true, _) => title.bright_black().strikethrough(),
-        (false, true) => title.blue(),
-        (false, false) => title.normal(),
+    let description = match (focused, option.disabled, option.description) {
+        (true, true, _) => match option.disabled_reason {
+            Some(reason) => format!(" · (Disabled: {})", reason),
+            None => String::from(" · (Disabled)"),
+        },
+        (true, false, Some(description)) => format!(" · {}", description),
+        _ => String::new(),
     };
 
-    let make_description = |s: &str| format!(" · {}", s).bright_black();
-    let description = match (focused, option.disabled, option.description) {
-        (true, true, _) => make_description("(Disabled)"),
-        (true, false, Some(description)) => make_description(description),
-        _ => "".normal(),
+    let title = option.get_title();
+    let (title, annotation) = match &option.annotation {
+        Some(text) => {
+            // prefix + space + title + space + description + space + annotation
+            let used = prefix.width() + 2 + description.width() + 1 + text.width();
+            align_annotation(title, text, used, terminal_width().saturating_sub(1))
+        }
+        None => (title.to_string(), String::new()),
+    };
+
+    let paint = |text: &str| match (option.disabled, focused) {
+        (true, _) => text.bright_black().strikethrough(),
+        (false, true) => text.blue(),
+        (false, false) => text.normal(),
     };
 
-    format!("{} {} {}", prefix, title, description)
+    // highlight the characters matched by the filter
+    let title = match fuzzy::fuzzy_match(filter, &title) {
+        Some((_, positions)) if !filter.is_empty() => title
+            .chars()
+            .enumerate()
+            .map(|(i, c)| match positions.contains(&i) {
+                true => paint(&c.to_string()).bold().underline().to_string(),
+                false => paint(&c.to_string()).to_string(),
+            })
+            .collect(),
+        _ => paint(&title).to_string(),
+    };
+
+    format!(
+        "{} {} {}{}",
+        prefix,
+        title,
+        description.bright_black(),
+        annotation.bright_black()
+    )
+}
+
+fn terminal_width() -> usize {
+    terminal::size()
+        .map(|(width, _)| width as usize)
+        .unwrap_or(80)
+}
+
+/// Truncate the title and pad the annotation, so the annotation ends at the width limit.
+///
+/// `used` is the width of the line without the title and the padding.
+fn align_annotation(title: &str, annotation: &str, used: usize, limit: usize) -> (String, String) {
+    let title = truncate_to_width(title, limit.saturating_sub(used));
+    let padding = limit.saturating_sub(used + title.width());
+
+    (title, format!("{}{}", " ".repeat(padding + 1), annotation))
+}
+
+/// Cut the text to fit in the given width, adding an ellipsis if truncated.
+fn truncate_to_width(text: &str, max_width: usize) -> String {
+    if text.width() <= max_width {
+        return text.to_string();
+    }
+
+    if max_width == 0 {
+        return String::new();
+    }
+
+    let ellipsis = glyphs().ellipsis;
+    let mut truncated = String::new();
+    let mut width = 0;
+
+    for c in text.chars() {
+        let char_width = c.width().unwrap_or(0);
+
+        // keep space for the ellipsis
+        if width + char_width > max_width.saturating_sub(ellipsis.width()) {
+            truncated.push_str(ellipsis);
+            break;
+        }
+
+        width += char_width;
+        truncated.push(c);
+    }
+
+    truncated
 }
 
 // endregion: select
+
+// region: summary
+
+pub fn fmt_summary(summary: &Summary) -> String {
+    let key_width = summary
+        .entries
+        .iter()
+        .map(|entry| entry.key.width())
+        .max()
+        .unwrap_or_default();
+
+    let entries = summary.entries.iter().map(|entry| {
+        let answer = match (entry.secret, &entry.answer) {
+            (_, Answer::Unit) => "skipped".bright_black(),
+            (true, _) => "••••••••".purple(),
+            (false, answer) => fmt_summary_answer(answer).purple(),
+        };
+        let padding = " ".repeat(key_width - entry.key.width());
+
+        format!("  {}{}  {}", entry.key, padding, answer)
+    });
+
+    summary
+        .title
+        .map(|title| format!("{} {}", glyphs().answered.green(), title.bold()))
+        .into_iter()
+        .chain(entries)
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+fn fmt_summary_answer(answer: &Answer) -> String {
+    match answer {
+        Answer::Unit => String::from("skipped"),
+        Answer::Bool(true) => String::from("Yes"),
+        Answer::Bool(false) => String::from("No"),
+        Answer::Text(text) => text.clone(),
+        Answer::Number(number) => number.to_string(),
+        Answer::Integer(number) => number.to_string(),
+        Answer::Unsigned(number) => number.to_string(),
+        Answer::Index(index) => index.to_string(),
+        Answer::Secret => "••••••••".to_string(),
+        Answer::Indices(indices) => indices
+            .iter()
+            .map(ToString::to_string)
+            .collect::<Vec<_>>()
+            .join(", "),
+    }
+}
+
+// endregion: summary
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::utils::layout;
+
+    #[test]
+    fn truncate_wide_text() {
+        let ellipsis = glyphs().ellipsis;
+
+        assert_eq!(truncate_to_width("foo", 3), "foo");
+        assert_eq!(truncate_to_width("foo", 0), "");
+        assert_eq!(truncate_to_width("foobar", 4), format!("foo{}", ellipsis));
+        assert_eq!(truncate_to_width("日本語", 6), "日本語");
+        // a wide char that doesn't fit is not cut in half
+        assert_eq!(truncate_to_width("日本語", 5), format!("日本{}", ellipsis));
+        assert_eq!(truncate_to_width("日本語", 4), format!("日{}", ellipsis));
+
+        for max_width in 0..8 {
+            assert!(truncate_to_width("日本語テキスト", max_width).width() <= max_width);
+        }
+    }
+
+    #[test]
+    fn align_annotations() {
+        for title in ["Project", "日本語のプロジェクト", "🦀 crab"] {
+            for limit in 10..30 {
+                let (truncated, annotation) = align_annotation(title, "1.2.0", 10, limit);
+                // prefix, spaces and description, the rest of `used`
+                let line = format!("    {}{}", truncated, annotation);
+
+                assert!(annotation.ends_with(" 1.2.0"));
+                assert_eq!(line.width(), limit);
+            }
+
+            // too narrow for the title, the annotation is still displayed
+            let (truncated, annotation) = align_annotation(title, "1.2.0", 10, 5);
+            assert_eq!((truncated.as_str(), annotation.as_str()), ("", " 1.2.0"));
+        }
+    }
+
+    #[test]
+    fn columns_with_wide_labels() {
+        for (message, label_width) in [("名前", 8), ("📧 email", 4), ("Name", 8)] {
+            let input = fmt_line_input("foo", &None, &Ok(()), false, (None, None));
+            let (text, cursor) =
+                fmt_columns_line_prompt(message, &None, input, (&Ok(()), false), 3, label_width);
+            let text = layout::strip_escape_codes(&text);
+
+            let label = format!("{} {} ", glyphs().message, message);
+            let input = format!("{} foo", glyphs().input);
+
+            assert!(text.starts_with(&label), "{:?}", text);
+            assert!(text.ends_with(&input), "{:?}", text);
+            // the inputs are aligned at the label width, or after a longer label
+            assert_eq!(
+                text.width() - input.width(),
+                glyphs().message.width() + 1 + message.width().max(label_width) + 1
+            );
+            assert_eq!(cursor, [text.width(), 0]);
+        }
+    }
+}
@@ -0,0 +1,69 @@
+use std::{
+    cell::Cell,
+    time::{Duration, Instant},
+};
+
+thread_local! {
+    /// When the prompt running in this thread times out, set by a [`Flow`](crate::Flow).
+    static DEADLINE: Cell<Option<Instant>> = const { Cell::new(None) };
+}
+
+/// Replace the deadline of the prompts running in this thread, returning the previous one.
+pub(crate) fn replace_deadline(deadline: Option<Instant>) -> Option<Instant> {
+    DEADLINE.replace(deadline)
+}
+
+/// Returns the time left until the deadline, if one is set.
+pub(crate) fn remaining() -> Option<Duration> {
+    DEADLINE
+        .get()
+        .map(|deadline| deadline.saturating_duration_since(Instant::now()))
+}
+
+/// Returns whether the deadline has passed.
+pub(crate) fn is_expired() -> bool {
+    remaining() == Some(Duration::ZERO)
+}
+
+/// Returns when the countdown changes its displayed seconds, or the deadline passes.
+pub(crate) fn next_tick() -> Option<Instant> {
+    let deadline = DEADLINE.get()?;
+    let remaining = deadline.saturating_duration_since(Instant::now());
+
+    Some(deadline - whole_seconds_after_tick(remaining))
+}
+
+/// Returns the whole seconds left after the next tick, the countdown rounds the seconds up.
+fn whole_seconds_after_tick(remaining: Duration) -> Duration {
+    let nanos = remaining.as_nanos().saturating_sub(1);
+    Duration::from_secs((nanos / 1_000_000_000) as u64)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn tick_each_second() {
+        let secs = |millis| whole_seconds_after_tick(Duration::from_millis(millis)).as_secs();
+
+        assert_eq!(secs(4300), 4);
+        assert_eq!(secs(4000), 3);
+        assert_eq!(secs(999), 0);
+        assert_eq!(secs(0), 0);
+    }
+
+    #[test]
+    fn expire_after_deadline() {
+        assert_eq!(replace_deadline(Some(Instant::now())), None);
+        assert!(is_expired());
+        assert!(next_tick().is_some_and(|tick| tick <= Instant::now()));
+
+        replace_deadline(Some(Instant::now() + Duration::from_secs(60)));
+        assert!(!is_expired());
+        assert!(remaining().is_some_and(|left| left > Duration::from_secs(59)));
+
+        replace_deadline(None);
+        assert_eq!((remaining(), next_tick()), (None, None));
+    }
+}
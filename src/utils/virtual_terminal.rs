@@ -0,0 +1,459 @@
+//! Virtual terminal to test the renderer, interpreting the escape sequences that it emits.
+
+use std::{
+    fmt,
+    io::{self, Write},
+    sync::{Arc, Mutex, MutexGuard, PoisonError},
+};
+
+use unicode_width::UnicodeWidthChar;
+
+use super::{layout::LayoutContext, renderer::Terminal};
+
+const COLOR_NAMES: [&str; 16] = [
+    "black",
+    "red",
+    "green",
+    "yellow",
+    "blue",
+    "magenta",
+    "cyan",
+    "white",
+    "bright_black",
+    "bright_red",
+    "bright_green",
+    "bright_yellow",
+    "bright_blue",
+    "bright_magenta",
+    "bright_cyan",
+    "bright_white",
+];
+
+/// Color of the text or its background, set by the escape sequences.
+///
+/// Displayed by name for the 16 standard colors, like `bright_red`,
+/// by index for the rest of the palette, and as `#rrggbb` for true colors.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Color {
+    /// Color of the 256-color palette, the first 16 are the standard ones, from black to bright white.
+    Indexed(u8),
+    /// True color.
+    Rgb(u8, u8, u8),
+}
+
+impl fmt::Display for Color {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match *self {
+            Color::Indexed(n) if n < 16 => f.write_str(COLOR_NAMES[n as usize]),
+            Color::Indexed(n) => write!(f, "{n}"),
+            Color::Rgb(r, g, b) => write!(f, "#{r:02x}{g:02x}{b:02x}"),
+        }
+    }
+}
+
+/// Colors and attributes of the text, set by the escape sequences.
+///
+/// Displayed as a list of the changes from the plain style, like `fg:red,bold`.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub struct Style {
+    /// Color of the text, `None` for the default one.
+    pub foreground: Option<Color>,
+    /// Color of the background, `None` for the default one.
+    pub background: Option<Color>,
+    /// Bold or increased intensity.
+    pub bold: bool,
+    /// Dim or decreased intensity.
+    pub dim: bool,
+    /// Italic.
+    pub italic: bool,
+    /// Underlined.
+    pub underlined: bool,
+    /// Foreground and background colors swapped.
+    pub reversed: bool,
+    /// Hidden text.
+    pub hidden: bool,
+    /// Crossed out.
+    pub strikethrough: bool,
+}
+
+impl Style {
+    const PLAIN: Style = Style {
+        foreground: None,
+        background: None,
+        bold: false,
+        dim: false,
+        italic: false,
+        underlined: false,
+        reversed: false,
+        hidden: false,
+        strikethrough: false,
+    };
+
+    /// Returns `true` if it's the default style, without colors nor attributes.
+    pub fn is_plain(&self) -> bool {
+        *self == Style::PLAIN
+    }
+
+    /// Apply the parameters of a Select Graphic Rendition sequence, like `1;31` of `\x1b[1;31m`.
+    fn apply(&mut self, params: &str) {
+        let mut params = params
+            .split([';', ':'])
+            .map(|n| n.parse::<u8>().unwrap_or(0));
+
+        while let Some(n) = params.next() {
+            match n {
+                0 => *self = Style::PLAIN,
+                1 => self.bold = true,
+                2 => self.dim = true,
+                3 => self.italic = true,
+                4 => self.underlined = true,
+                7 => self.reversed = true,
+                8 => self.hidden = true,
+                9 => self.strikethrough = true,
+                22 => (self.bold, self.dim) = (false, false),
+                23 => self.italic = false,
+                24 => self.underlined = false,
+                27 => self.reversed = false,
+                28 => self.hidden = false,
+                29 => self.strikethrough = false,
+                30..=37 => self.foreground = Some(Color::Indexed(n - 30)),
+                38 => self.foreground = extended_color(&mut params),
+                39 => self.foreground = None,
+                40..=47 => self.background = Some(Color::Indexed(n - 40)),
+                48 => self.background = extended_color(&mut params),
+                49 => self.background = None,
+                90..=97 => self.foreground = Some(Color::Indexed(n - 90 + 8)),
+                100..=107 => self.background = Some(Color::Indexed(n - 100 + 8)),
+                // blinking, fonts, etc.
+                _ => (),
+            }
+        }
+    }
+}
+
+impl fmt::Display for Style {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let colors = [("fg", self.foreground), ("bg", self.background)]
+            .into_iter()
+            .filter_map(|(name, color)| Some(format!("{}:{}", name, color?)));
+        let attributes = [
+            ("bold", self.bold),
+            ("dim", self.dim),
+            ("italic", self.italic),
+            ("underlined", self.underlined),
+            ("reversed", self.reversed),
+            ("hidden", self.hidden),
+            ("strikethrough", self.strikethrough),
+        ]
+        .into_iter()
+        .filter(|(_, active)| *active)
+        .map(|(name, _)| name.to_string());
+
+        f.write_str(&colors.chain(attributes).collect::<Vec<_>>().join(","))
+    }
+}
+
+/// Returns the color of the parameters after `38` or `48`, like `5;196` or `2;255;0;0`.
+fn extended_color(params: &mut impl Iterator<Item = u8>) -> Option<Color> {
+    match params.next()? {
+        5 => Some(Color::Indexed(params.next()?)),
+        2 => Some(Color::Rgb(params.next()?, params.next()?, params.next()?)),
+        _ => None,
+    }
+}
+
+/// Text printed with the same style.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Span {
+    /// Printed text.
+    pub text: String,
+    /// Style of the text.
+    pub style: Style,
+}
+
+#[derive(Debug, Clone, Copy)]
+struct Cell {
+    c: char,
+    style: Style,
+}
+
+impl Cell {
+    const BLANK: Cell = Cell {
+        c: ' ',
+        style: Style::PLAIN,
+    };
+}
+
+#[derive(Debug)]
+struct Screen {
+    width: usize,
+    cells: Vec<Vec<Cell>>,
+    cursor: [usize; 2],
+    saved: [usize; 2],
+    style: Style,
+    pending: Vec<u8>,
+}
+
+/// Terminal of a fixed size that keeps the printed text in memory.
+///
+/// It's cheap to clone, the clones share the same screen,
+/// so a test can read the screen of a terminal owned by a renderer.
+#[derive(Debug, Clone)]
+pub struct VirtualTerminal {
+    screen: Arc<Mutex<Screen>>,
+}
+
+impl VirtualTerminal {
+    pub fn new(width: usize, height: usize) -> Self {
+        let screen = Screen {
+            width,
+            cells: vec![vec![Cell::BLANK; width]; height],
+            cursor: [0, 0],
+            saved: [0, 0],
+            style: Style::PLAIN,
+            pending: Vec::new(),
+        };
+
+        VirtualTerminal {
+            screen: Arc::new(Mutex::new(screen)),
+        }
+    }
+
+    /// Change the size of the screen, like when the window of the terminal is resized.
+    ///
+    /// The text is not reflowed, the rows are clipped or filled with spaces at the right,
+    /// and the top rows are removed if the cursor would be below the last one.
+    pub fn resize(&self, width: usize, height: usize) {
+        self.lock().resize(width, height);
+    }
+
+    /// Returns the rows of the screen, without trailing spaces.
+    pub fn lines(&self) -> Vec<String> {
+        self.lock()
+            .cells
+            .iter()
+            .map(|row| row.iter().map(|cell| cell.c).filter(|c| *c != '\0'))
+            .map(|row| row.collect::<String>().trim_end().to_string())
+            .collect()
+    }
+
+    /// Returns the rows of the screen split by their style, without trailing plain spaces.
+    pub fn styled_lines(&self) -> Vec<Vec<Span>> {
+        self.lock()
+            .cells
+            .iter()
+            .map(|row| {
+                let mut spans: Vec<Span> = Vec::new();
+
+                for cell in row.iter().filter(|cell| cell.c != '\0') {
+                    match spans.last_mut() {
+                        Some(span) if span.style == cell.style => span.text.push(cell.c),
+                        _ => spans.push(Span {
+                            text: cell.c.to_string(),
+                            style: cell.style,
+                        }),
+                    }
+                }
+
+                while let Some(span) = spans.last_mut().filter(|span| span.style.is_plain()) {
+                    span.text.truncate(span.text.trim_end().len());
+
+                    match span.text.is_empty() {
+                        true => spans.pop(),
+                        false => break,
+                    };
+                }
+
+                spans
+            })
+            .collect()
+    }
+
+    /// Returns the `[x, y]` position of the cursor.
+    pub fn cursor(&self) -> [usize; 2] {
+        self.lock().cursor
+    }
+
+    fn lock(&self) -> MutexGuard<'_, Screen> {
+        self.screen.lock().unwrap_or_else(PoisonError::into_inner)
+    }
+}
+
+impl Write for VirtualTerminal {
+    fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+        let mut screen = self.lock();
+
+        screen.pending.extend_from_slice(buf);
+        screen.process();
+
+        Ok(buf.len())
+    }
+
+    fn flush(&mut self) -> io::Result<()> {
+        Ok(())
+    }
+}
+
+impl Terminal for VirtualTerminal {
+    fn layout(&self) -> LayoutContext {
+        LayoutContext {
+            width: self.lock().width,
+            header_lines: 0,
+        }
+    }
+}
+
+impl Screen {
+    /// Interpret the complete characters and sequences of the pending output.
+    ///
+    /// The output can be split anywhere, e.g. in the middle of a sequence,
+    /// so the incomplete end is kept for the next write.
+    fn process(&mut self) {
+        let valid_len = match std::str::from_utf8(&self.pending) {
+            Ok(text) => text.len(),
+            Err(err) => err.valid_up_to(),
+        };
+
+        let text = String::from_utf8(self.pending[..valid_len].to_vec()).unwrap();
+        let mut rest = text.as_str();
+
+        while let Some(c) = rest.chars().next() {
+            if c != '\x1b' {
+                self.print(c);
+                rest = &rest[c.len_utf8()..];
+                continue;
+            }
+
+            let Some(len) = sequence_len(rest) else {
+                break;
+            };
+
+            self.execute(&rest[1..len]);
+            rest = &rest[len..];
+        }
+
+        let consumed = valid_len - rest.len();
+        self.pending.drain(..consumed);
+    }
+
+    fn resize(&mut self, width: usize, height: usize) {
+        for row in &mut self.cells {
+            // a wide char cut in half is not displayed
+            if row.get(width).is_some_and(|cell| cell.c == '\0') {
+                row[width - 1] = Cell::BLANK;
+            }
+
+            row.resize(width, Cell::BLANK);
+        }
+
+        let hidden_rows = (self.cursor[1] + 1).saturating_sub(height);
+        self.cells.drain(..hidden_rows.min(self.cells.len()));
+        self.cells.resize(height, vec![Cell::BLANK; width]);
+
+        self.width = width;
+        self.cursor = [
+            self.cursor[0].min(width.saturating_sub(1)),
+            self.cursor[1] - hidden_rows,
+        ];
+        self.saved = [
+            self.saved[0].min(width.saturating_sub(1)),
+            self.saved[1].min(height.saturating_sub(1)),
+        ];
+    }
+
+    fn print(&mut self, c: char) {
+        let height = self.cells.len();
+
+        match c {
+            '\n' => {
+                self.cursor = [0, self.cursor[1] + 1];
+            }
+            '\r' => self.cursor[0] = 0,
+            _ => {
+                let width = c.width().unwrap_or(0);
+
+                if width == 0 {
+                    return;
+                }
+
+                // wrap at the end of the line
+                if self.cursor[0] + width > self.width {
+                    self.cursor = [0, self.cursor[1] + 1];
+                }
+
+                self.scroll_to_cursor();
+
+                let [x, y] = self.cursor;
+                let style = self.style;
+                self.cells[y][x] = Cell { c, style };
+
+                if width == 2 {
+                    self.cells[y][x + 1] = Cell { c: '\0', style };
+                }
+
+                self.cursor[0] += width;
+            }
+        }
+
+        if self.cursor[1] >= height {
+            self.scroll_to_cursor();
+        }
+    }
+
+    /// Scroll the screen up until the cursor is in the last row, like real terminals.
+    ///
+    /// The saved position is not updated, it's an absolute position.
+    fn scroll_to_cursor(&mut self) {
+        while self.cursor[1] >= self.cells.len() {
+            self.cells.remove(0);
+            self.cells.push(vec![Cell::BLANK; self.width]);
+            self.cursor[1] -= 1;
+        }
+    }
+
+    /// Execute a sequence, without the escape character, like `[2J` or `7`.
+    fn execute(&mut self, sequence: &str) {
+        let sequence = sequence.strip_prefix('[').unwrap_or(sequence);
+        let (params, command) = sequence.split_at(sequence.len().saturating_sub(1));
+        let numbers: Vec<usize> = params.split(';').map(|n| n.parse().unwrap_or(1)).collect();
+        let n = numbers.first().copied().unwrap_or(1);
+        let [x, y] = self.cursor;
+
+        match command {
+            "7" => self.saved = self.cursor,
+            "8" => self.cursor = self.saved,
+            "A" => self.cursor[1] = y.saturating_sub(n),
+            "B" => self.cursor[1] = (y + n).min(self.cells.len() - 1),
+            "C" => self.cursor[0] = (x + n).min(self.width - 1),
+            "D" => self.cursor[0] = x.saturating_sub(n),
+            "E" => self.cursor = [0, (y + n).min(self.cells.len() - 1)],
+            "F" => self.cursor = [0, y.saturating_sub(n)],
+            "H" => {
+                let row = numbers.first().copied().unwrap_or(1);
+                let col = numbers.get(1).copied().unwrap_or(1);
+                self.cursor = [col.saturating_sub(1), row.saturating_sub(1)];
+            }
+            "J" => {
+                self.cells[y][x..].fill(Cell::BLANK);
+                self.cells[y + 1..]
+                    .iter_mut()
+                    .for_each(|row| row.fill(Cell::BLANK));
+            }
+            "m" => self.style.apply(params),
+            // cursor visibility and style
+            _ => (),
+        }
+    }
+}
+
+/// Returns the length of the escape sequence at the start of the text, if it's complete.
+fn sequence_len(text: &str) -> Option<usize> {
+    let mut chars = text.char_indices().skip(1);
+
+    match chars.next()? {
+        (_, '[') => chars
+            .find(|(_, c)| ('@'..='~').contains(c))
+            .map(|(i, c)| i + c.len_utf8()),
+        (i, c) => Some(i + c.len_utf8()),
+    }
+}
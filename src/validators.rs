@@ -0,0 +1,201 @@
+//! Reusable validators for the `validate()` methods of the prompts.
+//!
+//! Each validator receives the error message to display when the value is invalid.
+//! Validators of [`Text`] and [`Password`] can be chained with [`Validator::and`],
+//! the first error found is displayed.
+//!
+//! # Examples
+//!
+//! ```no_run
+//! use asky::validators::{self, Validator};
+//! use asky::{Number, Text};
+//!
+//! # fn main() -> std::io::Result<()> {
+//! let email = Text::new("Email")
+//!     .validate(validators::non_empty("Email is required").and(validators::email("Invalid email")))
+//!     .prompt()?;
+//!
+//! let port = Number::<u16>::new("Port")
+//!     .validate(validators::range(1024.., "Must be 1024 or higher"))
+//!     .prompt()?;
+//! # Ok(())
+//! # }
+//! ```
+//!
+//! [`Text`]: crate::Text
+//! [`Password`]: crate::Password
+
+use std::{ops::RangeBounds, str::FromStr};
+
+/// Validator for the text input of [`Text`](crate::Text) and [`Password`](crate::Password).
+///
+/// Implemented by any `Fn(&str) -> Result<(), &str>` closure.
+pub trait Validator<'a>: Fn(&str) -> Result<(), &'a str> + Sized + 'a {
+    /// Chain another validator, which runs only if this one passes.
+    fn and<V: Validator<'a>>(self, other: V) -> impl Fn(&str) -> Result<(), &'a str> + 'a {
+        move |value| self(value).and_then(|_| other(value))
+    }
+}
+
+impl<'a, F> Validator<'a> for F where F: Fn(&str) -> Result<(), &'a str> + 'a {}
+
+/// Value must not be empty or only whitespace.
+pub fn non_empty<'a>(message: &'a str) -> impl Fn(&str) -> Result<(), &'a str> + 'a {
+    move |value| match value.trim().is_empty() {
+        true => Err(message),
+        false => Ok(()),
+    }
+}
+
+/// Value must have at least `min` characters.
+pub fn min_len<'a>(min: usize, message: &'a str) -> impl Fn(&str) -> Result<(), &'a str> + 'a {
+    move |value| match value.chars().count() < min {
+        true => Err(message),
+        false => Ok(()),
+    }
+}
+
+/// Value must have at most `max` characters.
+pub fn max_len<'a>(max: usize, message: &'a str) -> impl Fn(&str) -> Result<(), &'a str> + 'a {
+    move |value| match value.chars().count() > max {
+        true => Err(message),
+        false => Ok(()),
+    }
+}
+
+/// Value must look like an email address, like `"user@example.com"`.
+///
+/// This is a basic check of the format, it doesn't verify that the address exists.
+pub fn email<'a>(message: &'a str) -> impl Fn(&str) -> Result<(), &'a str> + 'a {
+    move |value| match is_email(value) {
+        true => Ok(()),
+        false => Err(message),
+    }
+}
+
+/// Value must be an absolute URL with a scheme and a host, like `"https://example.com"`.
+pub fn url<'a>(message: &'a str) -> impl Fn(&str) -> Result<(), &'a str> + 'a {
+    move |value| match is_url(value) {
+        true => Ok(()),
+        false => Err(message),
+    }
+}
+
+/// Value must be an integer inside the range.
+///
+/// Use [`range`] to validate a [`Number`](crate::Number) prompt.
+pub fn int_range<'a, R>(range: R, message: &'a str) -> impl Fn(&str) -> Result<(), &'a str> + 'a
+where
+    R: RangeBounds<i64> + 'a,
+{
+    move |value| match value.trim().parse::<i64>() {
+        Ok(n) if range.contains(&n) => Ok(()),
+        _ => Err(message),
+    }
+}
+
+/// Value must match the regular expression.
+///
+/// The expression is not anchored, use `^` and `$` to match the whole value.
+#[cfg(feature = "regex")]
+pub fn regex<'a>(
+    regex: regex::Regex,
+    message: &'a str,
+) -> impl Fn(&str) -> Result<(), &'a str> + 'a {
+    move |value| match regex.is_match(value) {
+        true => Ok(()),
+        false => Err(message),
+    }
+}
+
+/// Number must be inside the range, for [`Number`](crate::Number) prompts.
+///
+/// Invalid numbers also return the message.
+pub fn range<'a, T, R>(
+    range: R,
+    message: &'a str,
+) -> impl Fn(&str, Result<T, T::Err>) -> Result<(), &'a str> + 'a
+where
+    T: FromStr + PartialOrd + 'a,
+    R: RangeBounds<T> + 'a,
+{
+    move |_, value| match value {
+        Ok(n) if range.contains(&n) => Ok(()),
+        _ => Err(message),
+    }
+}
+
+fn is_email(value: &str) -> bool {
+    let Some((local, domain)) = value.split_once('@') else {
+        return false;
+    };
+
+    !local.is_empty()
+        && !domain.contains('@')
+        && !value.chars().any(char::is_whitespace)
+        && domain.split('.').count() > 1
+        && domain.split('.').all(|label| !label.is_empty())
+}
+
+fn is_url(value: &str) -> bool {
+    let Some((scheme, rest)) = value.split_once("://") else {
+        return false;
+    };
+
+    let valid_scheme = scheme.starts_with(|c: char| c.is_ascii_alphabetic())
+        && scheme
+            .chars()
+            .all(|c| c.is_ascii_alphanumeric() || matches!(c, '+' | '-' | '.'));
+    let host = rest.split(['/', '?', '#']).next().unwrap_or_default();
+
+    valid_scheme && !host.is_empty() && !value.chars().any(char::is_whitespace)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn validate_text() {
+        assert_eq!(non_empty("err")("  "), Err("err"));
+        assert_eq!(non_empty("err")("foo"), Ok(()));
+        assert_eq!(min_len(3, "err")("日本"), Err("err"));
+        assert_eq!(max_len(3, "err")("日本語"), Ok(()));
+        assert_eq!(int_range(1..=10, "err")("10"), Ok(()));
+        assert_eq!(int_range(1..=10, "err")("11"), Err("err"));
+        assert_eq!(int_range(1..=10, "err")("foo"), Err("err"));
+    }
+
+    #[test]
+    fn validate_email_and_url() {
+        assert!(is_email("user@example.com"));
+        assert!(!is_email("user@example"));
+        assert!(!is_email("@example.com"));
+        assert!(!is_email("user@@example.com"));
+        assert!(!is_email("user name@example.com"));
+
+        assert!(is_url("https://example.com/path?q=1"));
+        assert!(is_url("ftp://files.example.com"));
+        assert!(!is_url("example.com"));
+        assert!(!is_url("https://"));
+        assert!(!is_url("1http://example.com"));
+    }
+
+    #[test]
+    fn validate_number_range() {
+        let validator = range(0..100, "err");
+
+        assert_eq!(validator("5", Ok(5)), Ok(()));
+        assert_eq!(validator("100", Ok(100)), Err("err"));
+        assert_eq!(validator("", "".parse::<i32>()), Err("err"));
+    }
+
+    #[test]
+    fn chain_validators() {
+        let validator = non_empty("required").and(min_len(4, "too short"));
+
+        assert_eq!(validator(""), Err("required"));
+        assert_eq!(validator("foo"), Err("too short"));
+        assert_eq!(validator("foobar"), Ok(()));
+    }
+}